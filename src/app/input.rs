@@ -1,155 +1,148 @@
 //! Input handling
 //!
-//! This module handles keyboard event mapping and event polling.
+//! This module maps keyboard events to messages through the active
+//! [`Keymap`](super::Keymap), maps mouse events by what they hit, and polls
+//! for events.
 
 use std::time::Duration;
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 
-use super::{App, Msg, ViewMode};
+use super::keymap::{Action, KeyChord, Scope};
+use super::{App, EditorCommand, MouseTarget, Msg, NoteScroll, ViewMode};
+
+/// Lines the note viewer moves per scroll-wheel step
+const SCROLL_LINES: i32 = 3;
 
 /// Map keyboard event to message
+///
+/// Global bindings win, then the view's own (see [`Keymap`](super::Keymap)); the
+/// text-entry modals, the note search prompt and the inline editor handle
+/// their keys directly.
 pub fn handle_key_event(app: &App, key: KeyEvent) -> Option<Msg> {
-    // Helper to get lowercase char from key code (for case-insensitive matching)
-    let lowercase_char = match key.code {
-        KeyCode::Char(c) => Some(c.to_ascii_lowercase()),
-        _ => None,
-    };
+    // The inline editor takes every key, so typing never triggers a command
+    if app.view_mode == ViewMode::NoteEditor {
+        return handle_note_editor_key(key);
+    }
 
-    // Global shortcuts (Ctrl+key)
-    if key.modifiers.contains(KeyModifiers::CONTROL) {
-        match lowercase_char {
-            Some('c') | Some('q') => return Some(Msg::Quit),
-            Some('r') => return Some(Msg::RefreshData),
-            _ => {}
-        }
+    let chord = KeyChord::from(key);
+    if let Some(action) = app.keymap.action(Scope::Global, chord) {
+        return action_msg(app, action);
     }
 
     match app.view_mode {
-        ViewMode::Dashboard => handle_dashboard_key(key, lowercase_char),
-        ViewMode::ReportDetail => handle_report_detail_key(app, key, lowercase_char),
-        ViewMode::NoteViewer => handle_note_viewer_key(key, lowercase_char),
-        ViewMode::DeleteConfirmModal => handle_delete_confirm_key(key, lowercase_char),
         ViewMode::NewReportModal => handle_new_report_modal_key(app, key),
         ViewMode::EntryInputModal => handle_entry_input_modal_key(key),
-        ViewMode::Help => handle_help_key(key, lowercase_char),
-    }
-}
-
-/// Handle keys in Dashboard view
-fn handle_dashboard_key(key: KeyEvent, _lowercase_char: Option<char>) -> Option<Msg> {
-    match key.code {
-        // Arrow keys (Left shares h's boundary-ascend behavior in halls)
-        KeyCode::Left => Some(Msg::SelectPrevOrAscend),
-        KeyCode::Right => Some(Msg::SelectNext),
-        KeyCode::Down => Some(Msg::SelectNext),
-        KeyCode::Up => Some(Msg::SelectPrev),
-        KeyCode::Enter => Some(Msg::ViewReport),
-        // Esc walks up one hall level; hard no-op at root (q is the only quit)
-        KeyCode::Esc => Some(Msg::ExitHall),
-        // Character keys (case-insensitive, except g/G)
-        KeyCode::Char('g') => Some(Msg::SelectFirst),
-        KeyCode::Char('G') => Some(Msg::SelectLast),
-        KeyCode::Char(c) => match c.to_ascii_lowercase() {
-            'q' => Some(Msg::Quit),
-            'h' => Some(Msg::SelectPrevOrAscend),
-            'l' => Some(Msg::SelectNext),
-            'j' => Some(Msg::SelectNext),
-            'k' => Some(Msg::SelectPrev),
-            // The conscious Space rebind: container verb (Enter stays "the person")
-            ' ' => Some(Msg::EnterHall),
-            'n' => Some(Msg::ShowNewReport),
-            '?' => Some(Msg::ShowHelp),
-            'r' => Some(Msg::RefreshData),
-            _ => None,
-        },
-        _ => None,
+        ViewMode::CommandPalette => handle_command_palette_key(key),
+        ViewMode::NoteViewer if app.note_search.as_ref().is_some_and(|s| s.typing) => {
+            handle_note_search_key(key)
+        }
+        view => {
+            let action = app.keymap.action(Scope::for_view(view)?, chord)?;
+            action_msg(app, action)
+        }
     }
 }
 
-/// Handle keys in ReportDetail view
-fn handle_report_detail_key(
+/// Map mouse event to message
+///
+/// `target` is what the pointer is over. Clicking a card or meeting selects
+/// it and clicking it again opens it; the wheel moves through the meeting
+/// list and scrolls notes.
+pub fn handle_mouse_event(
     app: &App,
-    key: KeyEvent,
-    _lowercase_char: Option<char>,
+    mouse: MouseEvent,
+    target: Option<MouseTarget>,
 ) -> Option<Msg> {
-    match key.code {
-        // Non-char keys
-        KeyCode::Esc | KeyCode::Backspace => Some(Msg::Back),
-        KeyCode::Left => Some(Msg::Back),
-        KeyCode::Down => Some(Msg::SelectNext),
-        KeyCode::Up => Some(Msg::SelectPrev),
-        KeyCode::Right | KeyCode::Enter => {
-            if app.selected_index < app.selected_meeting_count() {
-                Some(Msg::ViewMeeting(app.selected_index))
-            } else {
-                None
-            }
-        }
-        KeyCode::Delete => Some(Msg::ShowDeleteConfirm),
-        // Character keys (case-insensitive, except g/G)
-        KeyCode::Char('g') => Some(Msg::SelectFirst),
-        KeyCode::Char('G') => Some(Msg::SelectLast),
-        KeyCode::Char(c) => match c.to_ascii_lowercase() {
-            'q' => Some(Msg::Quit),
-            'h' => Some(Msg::Back),
-            'j' => Some(Msg::SelectNext),
-            'k' => Some(Msg::SelectPrev),
-            'l' => {
-                if app.selected_index < app.selected_meeting_count() {
-                    Some(Msg::ViewMeeting(app.selected_index))
-                } else {
-                    None
-                }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => match target? {
+            MouseTarget::Report(index) | MouseTarget::Meeting(index)
+                if index == app.selected_index =>
+            {
+                action_msg(app, Action::Open)
             }
-            'e' => {
-                if app.selected_index < app.selected_meeting_count() {
-                    Some(Msg::EditMeetingFromList(app.selected_index))
-                } else {
-                    None
-                }
-            }
-            'n' => Some(Msg::NewMeeting),
-            'm' => Some(Msg::ShowEntryInput),
-            '?' => Some(Msg::ShowHelp),
+            MouseTarget::Report(index) | MouseTarget::Meeting(index) => Some(Msg::Select(index)),
+            MouseTarget::NewReportField(field, option) => Some(Msg::ModalSelect(field, option)),
+            MouseTarget::Column(sort) => Some(Msg::SortBy(sort)),
+        },
+        MouseEventKind::ScrollDown => match app.view_mode {
+            ViewMode::ReportDetail => Some(Msg::Select(app.selected_index + 1)),
+            ViewMode::NoteViewer => Some(Msg::ScrollNote(NoteScroll::Lines(SCROLL_LINES))),
             _ => None,
         },
-        _ => None,
-    }
-}
-
-/// Handle keys in NoteViewer view
-fn handle_note_viewer_key(key: KeyEvent, _lowercase_char: Option<char>) -> Option<Msg> {
-    match key.code {
-        KeyCode::Esc | KeyCode::Backspace => Some(Msg::Back),
-        KeyCode::Delete => Some(Msg::ShowDeleteConfirm),
-        KeyCode::F(1) => Some(Msg::UpdateMood(1)),
-        KeyCode::F(2) => Some(Msg::UpdateMood(2)),
-        KeyCode::F(3) => Some(Msg::UpdateMood(3)),
-        KeyCode::F(4) => Some(Msg::UpdateMood(4)),
-        KeyCode::F(5) => Some(Msg::UpdateMood(5)),
-        KeyCode::Char(c) => match c.to_ascii_lowercase() {
-            'q' => Some(Msg::Quit),
-            'e' => Some(Msg::EditMeeting),
+        MouseEventKind::ScrollUp => match app.view_mode {
+            ViewMode::ReportDetail => Some(Msg::Select(app.selected_index.checked_sub(1)?)),
+            ViewMode::NoteViewer => Some(Msg::ScrollNote(NoteScroll::Lines(-SCROLL_LINES))),
             _ => None,
         },
         _ => None,
     }
 }
 
-/// Handle keys in DeleteConfirmModal view
-fn handle_delete_confirm_key(key: KeyEvent, _lowercase_char: Option<char>) -> Option<Msg> {
-    match key.code {
-        KeyCode::Enter => Some(Msg::ConfirmDelete),
-        KeyCode::Esc => Some(Msg::CancelModal),
-        KeyCode::Char(c) => match c.to_ascii_lowercase() {
-            'y' => Some(Msg::ConfirmDelete),
-            'n' => Some(Msg::CancelModal),
-            _ => None,
-        },
-        _ => None,
-    }
+/// What an action means in the current view
+pub(super) fn action_msg(app: &App, action: Action) -> Option<Msg> {
+    // Entries are only openable when the selection is on one
+    let selected_meeting =
+        || (app.selected_index < app.selected_meeting_count()).then_some(app.selected_index);
+
+    let in_note = app.view_mode == ViewMode::NoteViewer;
+
+    Some(match action {
+        Action::Quit => Msg::Quit,
+        Action::Refresh => Msg::RefreshData,
+        Action::Back => Msg::Back,
+        Action::Help => Msg::ShowHelp,
+        Action::CloseHelp => Msg::HideHelp,
+        Action::Next if in_note => Msg::ScrollNote(NoteScroll::Lines(1)),
+        Action::Next => Msg::SelectNext,
+        Action::Prev if in_note => Msg::ScrollNote(NoteScroll::Lines(-1)),
+        Action::Prev => Msg::SelectPrev,
+        Action::PrevOrAscend => Msg::SelectPrevOrAscend,
+        Action::First if in_note => Msg::ScrollNote(NoteScroll::Top),
+        Action::First => Msg::SelectFirst,
+        Action::Last if in_note => Msg::ScrollNote(NoteScroll::Bottom),
+        Action::Last => Msg::SelectLast,
+        Action::PageDown => Msg::ScrollNote(NoteScroll::Pages(1)),
+        Action::PageUp => Msg::ScrollNote(NoteScroll::Pages(-1)),
+        Action::Open if app.view_mode == ViewMode::ReportDetail => {
+            Msg::ViewMeeting(selected_meeting()?)
+        }
+        Action::Open => Msg::ViewReport,
+        Action::EnterHall => Msg::EnterHall,
+        Action::ExitHall => Msg::ExitHall,
+        Action::NewReport => Msg::ShowNewReport,
+        Action::MoodHeatmap => Msg::ShowMoodHeatmap,
+        Action::Sync => Msg::SyncWorkspace,
+        Action::Warnings => Msg::ShowWarnings,
+        Action::CycleFilter => Msg::CycleFilter,
+        Action::ClearFilter => Msg::ClearFilter,
+        Action::CycleSort => Msg::CycleSort,
+        Action::ToggleLayout => Msg::ToggleLayout,
+        Action::ToggleSquad => Msg::ToggleSquad,
+        Action::NewMeeting => Msg::NewMeeting,
+        Action::RecordMood => Msg::ShowEntryInput,
+        Action::MoodAnalytics => Msg::ShowMoodAnalytics,
+        Action::MeetingPrep => Msg::ShowMeetingPrep,
+        Action::History => Msg::ShowHistory,
+        Action::Edit if app.view_mode == ViewMode::ReportDetail => {
+            Msg::EditMeetingFromList(selected_meeting()?)
+        }
+        Action::Edit => Msg::EditMeeting,
+        Action::Delete => Msg::ShowDeleteConfirm,
+        Action::Mood(mood) => Msg::UpdateMood(mood),
+        Action::CycleWindow => Msg::CycleMoodWindow,
+        Action::Confirm if app.view_mode == ViewMode::MigrateConfirmModal => Msg::ConfirmMigrate,
+        Action::Confirm => Msg::ConfirmDelete,
+        Action::Cancel => Msg::CancelModal,
+        Action::CommandPalette => Msg::ShowCommandPalette,
+        Action::FindPerson => Msg::ShowPersonFinder,
+        Action::SearchNote => Msg::StartNoteSearch,
+        Action::NextMatch => Msg::NextMatch,
+        Action::PrevMatch => Msg::PrevMatch,
+    })
 }
 
 /// Handle keys in NewReportModal view
@@ -196,14 +189,58 @@ fn handle_entry_input_modal_key(key: KeyEvent) -> Option<Msg> {
     }
 }
 
-/// Handle keys in Help view
-fn handle_help_key(key: KeyEvent, _lowercase_char: Option<char>) -> Option<Msg> {
+/// Handle keys in the inline note editor
+fn handle_note_editor_key(key: KeyEvent) -> Option<Msg> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let command = match key.code {
+        KeyCode::Esc => return Some(Msg::CloseEditor),
+        KeyCode::F(n @ 1..=5) => return Some(Msg::UpdateMood(n)),
+        KeyCode::Char('s') if ctrl => return Some(Msg::SaveNote),
+        KeyCode::Char('z') if ctrl => EditorCommand::Undo,
+        KeyCode::Char('y') if ctrl => EditorCommand::Redo,
+        KeyCode::Char(_) if ctrl => return None,
+        KeyCode::Char(c) => EditorCommand::Insert(c),
+        KeyCode::Enter => EditorCommand::Newline,
+        KeyCode::Tab => EditorCommand::Tab,
+        KeyCode::Backspace => EditorCommand::Backspace,
+        KeyCode::Delete => EditorCommand::Delete,
+        KeyCode::Left if ctrl => EditorCommand::WordLeft,
+        KeyCode::Right if ctrl => EditorCommand::WordRight,
+        KeyCode::Left => EditorCommand::Left,
+        KeyCode::Right => EditorCommand::Right,
+        KeyCode::Up => EditorCommand::Up,
+        KeyCode::Down => EditorCommand::Down,
+        KeyCode::Home if ctrl => EditorCommand::Top,
+        KeyCode::End if ctrl => EditorCommand::Bottom,
+        KeyCode::Home => EditorCommand::Home,
+        KeyCode::End => EditorCommand::End,
+        KeyCode::PageUp => EditorCommand::PageUp,
+        KeyCode::PageDown => EditorCommand::PageDown,
+        _ => return None,
+    };
+    Some(Msg::EditorInput(command))
+}
+
+/// Handle keys in the command palette: typing filters
+fn handle_command_palette_key(key: KeyEvent) -> Option<Msg> {
     match key.code {
-        KeyCode::Esc => Some(Msg::HideHelp),
-        KeyCode::Char(c) => match c.to_ascii_lowercase() {
-            'q' | '?' => Some(Msg::HideHelp),
-            _ => None,
-        },
+        KeyCode::Esc => Some(Msg::CancelModal),
+        KeyCode::Enter => Some(Msg::Enter),
+        KeyCode::Up | KeyCode::BackTab => Some(Msg::SelectPrev),
+        KeyCode::Down | KeyCode::Tab => Some(Msg::SelectNext),
+        KeyCode::Backspace => Some(Msg::Backspace),
+        KeyCode::Char(c) => Some(Msg::Input(c)),
+        _ => None,
+    }
+}
+
+/// Handle keys while a search within the note is being typed
+fn handle_note_search_key(key: KeyEvent) -> Option<Msg> {
+    match key.code {
+        KeyCode::Esc => Some(Msg::CancelModal),
+        KeyCode::Enter => Some(Msg::Enter),
+        KeyCode::Backspace => Some(Msg::Backspace),
+        KeyCode::Char(c) => Some(Msg::Input(c)),
         _ => None,
     }
 }
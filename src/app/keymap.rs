@@ -0,0 +1,866 @@
+//! Configurable keybindings
+//!
+//! Every bindable key lives in one table of defaults per [`Scope`], which the
+//! user can override from `~/.config/vibe-manager/keys.yaml`. Key handling,
+//! the help modal and the status bar hints all read the same [`Keymap`], so
+//! they can't drift apart.
+//!
+//! Text-entry modals (new report, mood entry) keep fixed keys: there every
+//! printable character is input.
+//!
+//! ```yaml
+//! dashboard:
+//!   sync: ctrl+s          # replaces `s`
+//!   mood_heatmap: [m, H]
+//!   warnings: []          # unbound
+//! global:
+//!   quit: ctrl+c
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+
+use super::ViewMode;
+use crate::utils::user_config_path;
+
+/// Where a binding applies: everywhere, or in one view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Scope {
+    /// Checked before the view's own bindings, in every view
+    Global,
+    Dashboard,
+    ReportDetail,
+    NoteViewer,
+    MoodAnalytics,
+    MeetingPrep,
+    MoodHeatmap,
+    History,
+    Warnings,
+    DeleteConfirm,
+    MigrateConfirm,
+    Help,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 12] = [
+        Scope::Global,
+        Scope::Dashboard,
+        Scope::ReportDetail,
+        Scope::NoteViewer,
+        Scope::MoodAnalytics,
+        Scope::MeetingPrep,
+        Scope::MoodHeatmap,
+        Scope::History,
+        Scope::Warnings,
+        Scope::DeleteConfirm,
+        Scope::MigrateConfirm,
+        Scope::Help,
+    ];
+
+    /// Section name in `keys.yaml`
+    pub fn name(self) -> &'static str {
+        match self {
+            Scope::Global => "global",
+            Scope::Dashboard => "dashboard",
+            Scope::ReportDetail => "report_detail",
+            Scope::NoteViewer => "note_viewer",
+            Scope::MoodAnalytics => "mood_analytics",
+            Scope::MeetingPrep => "meeting_prep",
+            Scope::MoodHeatmap => "mood_heatmap",
+            Scope::History => "history",
+            Scope::Warnings => "warnings",
+            Scope::DeleteConfirm => "delete_confirm",
+            Scope::MigrateConfirm => "migrate_confirm",
+            Scope::Help => "help",
+        }
+    }
+
+    /// The scope a view's keys are looked up in (`None` for text-entry modals
+    /// and the inline editor)
+    pub fn for_view(view: ViewMode) -> Option<Scope> {
+        match view {
+            ViewMode::Dashboard => Some(Scope::Dashboard),
+            ViewMode::ReportDetail => Some(Scope::ReportDetail),
+            ViewMode::NoteViewer => Some(Scope::NoteViewer),
+            ViewMode::MoodAnalytics => Some(Scope::MoodAnalytics),
+            ViewMode::MeetingPrep => Some(Scope::MeetingPrep),
+            ViewMode::MoodHeatmap => Some(Scope::MoodHeatmap),
+            ViewMode::History => Some(Scope::History),
+            ViewMode::Warnings => Some(Scope::Warnings),
+            ViewMode::DeleteConfirmModal => Some(Scope::DeleteConfirm),
+            ViewMode::MigrateConfirmModal => Some(Scope::MigrateConfirm),
+            ViewMode::Help => Some(Scope::Help),
+            ViewMode::NewReportModal
+            | ViewMode::EntryInputModal
+            | ViewMode::CommandPalette
+            | ViewMode::NoteEditor => None,
+        }
+    }
+}
+
+/// Something a key can do; turned into a `Msg` by `input::action_msg`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Refresh,
+    Back,
+    Help,
+    CloseHelp,
+    Next,
+    Prev,
+    PrevOrAscend,
+    First,
+    Last,
+    PageDown,
+    PageUp,
+    /// The selected report (dashboard) or entry (report detail)
+    Open,
+    EnterHall,
+    ExitHall,
+    NewReport,
+    MoodHeatmap,
+    Sync,
+    Warnings,
+    CycleFilter,
+    ClearFilter,
+    CycleSort,
+    ToggleLayout,
+    /// Show or hide a manager's squad line in the table layout
+    ToggleSquad,
+    NewMeeting,
+    RecordMood,
+    MoodAnalytics,
+    MeetingPrep,
+    History,
+    Edit,
+    Delete,
+    /// Set the open note's mood (1-5)
+    Mood(u8),
+    CycleWindow,
+    Confirm,
+    Cancel,
+    CommandPalette,
+    /// Jump to anyone in the org tree by name, title or team
+    FindPerson,
+    /// Search within the open note
+    SearchNote,
+    NextMatch,
+    PrevMatch,
+}
+
+const ACTION_NAMES: &[(Action, &str)] = &[
+    (Action::Quit, "quit"),
+    (Action::Refresh, "refresh"),
+    (Action::Back, "back"),
+    (Action::Help, "help"),
+    (Action::CloseHelp, "close"),
+    (Action::Next, "next"),
+    (Action::Prev, "prev"),
+    (Action::PrevOrAscend, "prev_or_ascend"),
+    (Action::First, "first"),
+    (Action::Last, "last"),
+    (Action::PageDown, "page_down"),
+    (Action::PageUp, "page_up"),
+    (Action::Open, "open"),
+    (Action::EnterHall, "enter_hall"),
+    (Action::ExitHall, "exit_hall"),
+    (Action::NewReport, "new_report"),
+    (Action::MoodHeatmap, "mood_heatmap"),
+    (Action::Sync, "sync"),
+    (Action::Warnings, "warnings"),
+    (Action::CycleFilter, "cycle_filter"),
+    (Action::ClearFilter, "clear_filter"),
+    (Action::CycleSort, "cycle_sort"),
+    (Action::ToggleLayout, "toggle_layout"),
+    (Action::ToggleSquad, "toggle_squad"),
+    (Action::NewMeeting, "new_meeting"),
+    (Action::RecordMood, "record_mood"),
+    (Action::MoodAnalytics, "mood_analytics"),
+    (Action::MeetingPrep, "meeting_prep"),
+    (Action::History, "history"),
+    (Action::Edit, "edit"),
+    (Action::Delete, "delete"),
+    (Action::Mood(1), "mood_1"),
+    (Action::Mood(2), "mood_2"),
+    (Action::Mood(3), "mood_3"),
+    (Action::Mood(4), "mood_4"),
+    (Action::Mood(5), "mood_5"),
+    (Action::CycleWindow, "cycle_window"),
+    (Action::Confirm, "confirm"),
+    (Action::Cancel, "cancel"),
+    (Action::CommandPalette, "command_palette"),
+    (Action::FindPerson, "find_person"),
+    (Action::SearchNote, "search"),
+    (Action::NextMatch, "next_match"),
+    (Action::PrevMatch, "prev_match"),
+];
+
+impl Action {
+    /// Key in `keys.yaml`
+    pub fn name(self) -> &'static str {
+        ACTION_NAMES
+            .iter()
+            .find(|(action, _)| *action == self)
+            .map_or("?", |(_, name)| name)
+    }
+
+    fn from_name(name: &str) -> Option<Action> {
+        ACTION_NAMES
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(action, _)| *action)
+    }
+
+    /// Help modal text
+    pub fn description(self, scope: Scope) -> &'static str {
+        match (self, scope) {
+            (Action::Quit, _) => "Quit",
+            (Action::Refresh, _) => "Refresh data",
+            (Action::Back, Scope::ReportDetail) => "Back to party view",
+            (Action::Back, _) => "Back",
+            (Action::Help, _) => "Help",
+            (Action::CloseHelp, _) => "Close help",
+            (Action::Next, Scope::Dashboard) => "Next member",
+            (Action::Next, Scope::NoteViewer) => "Scroll down",
+            (Action::Next, _) => "Next",
+            (Action::Prev, Scope::Dashboard) => "Previous member",
+            (Action::Prev, Scope::NoteViewer) => "Scroll up",
+            (Action::Prev, _) => "Previous",
+            (Action::PrevOrAscend, _) => "Previous, or up a hall at the edge",
+            (Action::First, Scope::NoteViewer) => "Jump to top",
+            (Action::First, _) => "Jump to first",
+            (Action::Last, Scope::NoteViewer) => "Jump to bottom",
+            (Action::Last, _) => "Jump to last",
+            (Action::PageDown, _) => "Page down",
+            (Action::PageUp, _) => "Page up",
+            (Action::Open, Scope::Dashboard) => "View member details",
+            (Action::Open, _) => "View entry notes",
+            (Action::EnterHall, _) => "Enter a manager's hall",
+            (Action::ExitHall, _) => "Up one hall",
+            (Action::NewReport, _) => "Recruit new member",
+            (Action::MoodHeatmap, _) => "Team mood heatmap",
+            (Action::Sync, _) => "Git sync (pull & push)",
+            (Action::Warnings, _) => "Workspace problems",
+            (Action::CycleFilter, _) => "Next filter",
+            (Action::ClearFilter, _) => "Clear filter",
+            (Action::CycleSort, _) => "Next sort order",
+            (Action::ToggleLayout, _) => "Cards or table",
+            (Action::ToggleSquad, _) => "Show or hide squad (table)",
+            (Action::NewMeeting, _) => "New 1-on-1 meeting",
+            (Action::RecordMood, _) => "Record mood observation",
+            (Action::MoodAnalytics, _) => "Mood analytics",
+            (Action::MeetingPrep, _) => "Prep for the next 1-on-1",
+            (Action::History, Scope::ReportDetail) => "Profile history",
+            (Action::History, _) => "Note history",
+            (Action::Edit, _) => "Edit note",
+            (Action::Delete, _) => "Delete entry",
+            (Action::Mood(_), _) => "Set mood",
+            (Action::CycleWindow, _) => "Cycle window (30/90/365 days)",
+            (Action::Confirm, _) => "Confirm",
+            (Action::Cancel, _) => "Cancel",
+            (Action::CommandPalette, _) => "Command palette",
+            (Action::FindPerson, _) => "Find someone",
+            (Action::SearchNote, _) => "Search the note",
+            (Action::NextMatch, _) => "Next match",
+            (Action::PrevMatch, _) => "Previous match",
+        }
+    }
+}
+
+/// Default bindings, in help order; each entry's keys are space-separated
+const DEFAULTS: &[(Scope, &[(&str, Action)])] = &[
+    (
+        Scope::Global,
+        &[
+            ("ctrl+c ctrl+q", Action::Quit),
+            ("ctrl+r", Action::Refresh),
+            ("ctrl+p", Action::CommandPalette),
+        ],
+    ),
+    (
+        Scope::Dashboard,
+        &[
+            ("h left", Action::PrevOrAscend),
+            ("l right j down", Action::Next),
+            ("k up", Action::Prev),
+            ("g", Action::First),
+            ("G", Action::Last),
+            ("enter", Action::Open),
+            // The conscious Space rebind: container verb (Enter stays "the person")
+            ("space", Action::EnterHall),
+            // Esc walks up one hall level; hard no-op at root (q is the only quit)
+            ("esc", Action::ExitHall),
+            ("n", Action::NewReport),
+            ("m", Action::MoodHeatmap),
+            ("s", Action::Sync),
+            ("w", Action::Warnings),
+            ("f", Action::CycleFilter),
+            ("F", Action::ClearFilter),
+            ("o", Action::CycleSort),
+            ("t", Action::ToggleLayout),
+            ("x", Action::ToggleSquad),
+            ("r", Action::Refresh),
+            (":", Action::CommandPalette),
+            ("/", Action::FindPerson),
+            ("?", Action::Help),
+            ("q", Action::Quit),
+        ],
+    ),
+    (
+        Scope::ReportDetail,
+        &[
+            ("j down", Action::Next),
+            ("k up", Action::Prev),
+            ("g", Action::First),
+            ("G", Action::Last),
+            ("l right enter", Action::Open),
+            ("e", Action::Edit),
+            ("n", Action::NewMeeting),
+            ("m", Action::RecordMood),
+            ("a", Action::MoodAnalytics),
+            ("p", Action::MeetingPrep),
+            ("v", Action::History),
+            ("delete", Action::Delete),
+            (":", Action::CommandPalette),
+            ("/", Action::FindPerson),
+            ("?", Action::Help),
+            ("esc backspace h left", Action::Back),
+            ("q", Action::Quit),
+        ],
+    ),
+    (
+        Scope::NoteViewer,
+        &[
+            ("e", Action::Edit),
+            ("f1", Action::Mood(1)),
+            ("f2", Action::Mood(2)),
+            ("f3", Action::Mood(3)),
+            ("f4", Action::Mood(4)),
+            ("f5", Action::Mood(5)),
+            ("j down", Action::Next),
+            ("k up", Action::Prev),
+            ("pagedown space", Action::PageDown),
+            ("pageup", Action::PageUp),
+            ("g home", Action::First),
+            ("G end", Action::Last),
+            ("/", Action::SearchNote),
+            ("n", Action::NextMatch),
+            ("N", Action::PrevMatch),
+            ("v", Action::History),
+            ("delete", Action::Delete),
+            (":", Action::CommandPalette),
+            ("esc backspace", Action::Back),
+            ("q", Action::Quit),
+        ],
+    ),
+    (
+        Scope::MoodAnalytics,
+        &[
+            ("w tab", Action::CycleWindow),
+            (":", Action::CommandPalette),
+            ("/", Action::FindPerson),
+            ("esc backspace h left", Action::Back),
+            ("q", Action::Quit),
+        ],
+    ),
+    (
+        Scope::MeetingPrep,
+        &[
+            (":", Action::CommandPalette),
+            ("/", Action::FindPerson),
+            ("esc backspace h left p", Action::Back),
+            ("q", Action::Quit),
+        ],
+    ),
+    (
+        Scope::MoodHeatmap,
+        &[
+            (":", Action::CommandPalette),
+            ("/", Action::FindPerson),
+            ("esc backspace m", Action::Back),
+            ("q", Action::Quit),
+        ],
+    ),
+    (
+        Scope::History,
+        &[
+            ("j down", Action::Next),
+            ("k up", Action::Prev),
+            ("g", Action::First),
+            ("G", Action::Last),
+            (":", Action::CommandPalette),
+            ("/", Action::FindPerson),
+            ("esc backspace h v left", Action::Back),
+            ("q", Action::Quit),
+        ],
+    ),
+    (
+        Scope::Warnings,
+        &[
+            ("j down", Action::Next),
+            ("k up", Action::Prev),
+            ("esc backspace w q", Action::Back),
+        ],
+    ),
+    (
+        Scope::DeleteConfirm,
+        &[("y enter", Action::Confirm), ("n esc", Action::Cancel)],
+    ),
+    (
+        Scope::MigrateConfirm,
+        &[("y enter", Action::Confirm), ("n esc", Action::Cancel)],
+    ),
+    (Scope::Help, &[("? q esc", Action::CloseHelp)]),
+];
+
+/// A key plus modifiers, e.g. `ctrl+r`, `G`, `f1`, `space`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Shift is part of a character (`G`, `?`), so it's dropped for them;
+    /// terminals disagree on whether they report it
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = match code {
+            KeyCode::Char(_) => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(key: KeyEvent) -> Self {
+        KeyChord::new(key.code, key.modifiers)
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("`{}` is not a key", s);
+        // A lone "+" or a trailing "++" names the plus key itself
+        let (mods, key) = match s.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest, "+"),
+            _ => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in mods.split('+').filter(|m| !m.is_empty()) {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "backspace" | "bksp" => KeyCode::Backspace,
+                "tab" => KeyCode::Tab,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                f => match f.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                    Some(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(invalid()),
+                },
+            },
+        };
+        Ok(KeyChord::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if self.modifiers.contains(KeyModifiers::CONTROL) => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Backspace => write!(f, "Bksp"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Insert => write!(f, "Ins"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// One key bound to one action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Binding {
+    pub chord: KeyChord,
+    pub action: Action,
+}
+
+/// Active keybindings for every scope
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    scopes: BTreeMap<Scope, Vec<Binding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let scopes = DEFAULTS
+            .iter()
+            .map(|(scope, entries)| {
+                let bindings = entries
+                    .iter()
+                    .flat_map(|(keys, action)| {
+                        keys.split_whitespace().map(|key| Binding {
+                            chord: key.parse().expect("default keys parse"),
+                            action: *action,
+                        })
+                    })
+                    .collect();
+                (*scope, bindings)
+            })
+            .collect();
+        Keymap { scopes }
+    }
+}
+
+/// One action's keys in `keys.yaml`: a single key or a list
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Keys {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Keymap {
+    /// The defaults with a `keys.yaml` document applied
+    ///
+    /// Each `scope: { action: keys }` entry replaces that action's keys in
+    /// that scope. Unknown scopes or actions, actions a scope doesn't offer,
+    /// and keys bound twice are errors.
+    pub fn from_yaml(yaml: &str) -> Result<Keymap, String> {
+        let overrides: BTreeMap<String, BTreeMap<String, Keys>> = if yaml.trim().is_empty() {
+            BTreeMap::new()
+        } else {
+            serde_yaml::from_str(yaml).map_err(|e| e.to_string())?
+        };
+
+        let mut keymap = Keymap::default();
+        for (scope_name, actions) in overrides {
+            let scope = Scope::ALL
+                .into_iter()
+                .find(|s| s.name() == scope_name)
+                .ok_or_else(|| format!("unknown section `{}`", scope_name))?;
+            for (action_name, keys) in actions {
+                let action = Action::from_name(&action_name)
+                    .filter(|action| keymap.offers(scope, *action))
+                    .ok_or_else(|| {
+                        format!("`{}` is not an action in {}", action_name, scope.name())
+                    })?;
+                let keys = match keys {
+                    Keys::One(key) => vec![key],
+                    Keys::Many(keys) => keys,
+                };
+                let chords = keys
+                    .iter()
+                    .map(|key| key.parse())
+                    .collect::<Result<Vec<KeyChord>, _>>()?;
+                keymap.rebind(scope, action, &chords);
+            }
+        }
+        keymap.check_conflicts()?;
+        Ok(keymap)
+    }
+
+    /// Whether `action` means anything in `scope` (it has a default there)
+    fn offers(&self, scope: Scope, action: Action) -> bool {
+        Keymap::actions(scope).contains(&action)
+    }
+
+    /// Every action `scope` offers, bound or not, in help order
+    pub fn actions(scope: Scope) -> Vec<Action> {
+        DEFAULTS
+            .iter()
+            .filter(|(s, _)| *s == scope)
+            .flat_map(|(_, entries)| entries.iter().map(|(_, action)| *action))
+            .collect()
+    }
+
+    /// Replace an action's keys, keeping its place in the help order
+    fn rebind(&mut self, scope: Scope, action: Action, chords: &[KeyChord]) {
+        let bindings = self.scopes.entry(scope).or_default();
+        let at = bindings
+            .iter()
+            .position(|b| b.action == action)
+            .unwrap_or(bindings.len());
+        bindings.retain(|b| b.action != action);
+        let new = chords.iter().map(|&chord| Binding { chord, action });
+        bindings.splice(at.min(bindings.len())..at.min(bindings.len()), new);
+    }
+
+    /// A key bound to two actions in one scope, or shadowed by a global key
+    fn check_conflicts(&self) -> Result<(), String> {
+        let global = self.bindings(Scope::Global);
+        // Global keys are looked up before text fields see a key, so a plain
+        // `r` there would make the letter impossible to type
+        if let Some(binding) = global.iter().find(|b| {
+            !matches!(b.chord.code, KeyCode::F(_))
+                && !b
+                    .chord
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        }) {
+            return Err(format!(
+                "global {} needs ctrl+, alt+ or an f-key, since `{}` is typed into text fields",
+                binding.action.name(),
+                binding.chord
+            ));
+        }
+        for (scope, bindings) in &self.scopes {
+            for (i, binding) in bindings.iter().enumerate() {
+                if let Some(other) = bindings[..i]
+                    .iter()
+                    .find(|b| b.chord == binding.chord && b.action != binding.action)
+                {
+                    return Err(format!(
+                        "`{}` is bound to both {} and {} in {}",
+                        binding.chord,
+                        other.action.name(),
+                        binding.action.name(),
+                        scope.name()
+                    ));
+                }
+                if *scope != Scope::Global {
+                    if let Some(other) = global.iter().find(|b| b.chord == binding.chord) {
+                        return Err(format!(
+                            "`{}` for {} in {} is taken by global {}",
+                            binding.chord,
+                            binding.action.name(),
+                            scope.name(),
+                            other.action.name()
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Bindings of a scope, in help order
+    pub fn bindings(&self, scope: Scope) -> &[Binding] {
+        self.scopes.get(&scope).map_or(&[], Vec::as_slice)
+    }
+
+    /// What a key does in a scope
+    ///
+    /// Letters are case-insensitive unless the capital has its own binding
+    /// (`g`/`G`), so Caps Lock doesn't disable the keyboard.
+    pub fn action(&self, scope: Scope, chord: KeyChord) -> Option<Action> {
+        let find = |chord: KeyChord| {
+            self.bindings(scope)
+                .iter()
+                .find(|b| b.chord == chord)
+                .map(|b| b.action)
+        };
+        find(chord).or_else(|| match chord.code {
+            KeyCode::Char(c) if c.is_ascii_uppercase() => find(KeyChord {
+                code: KeyCode::Char(c.to_ascii_lowercase()),
+                ..chord
+            }),
+            _ => None,
+        })
+    }
+
+    /// Keys bound to an action, e.g. `["l", "→", "Enter"]`
+    pub fn keys(&self, scope: Scope, action: Action) -> Vec<String> {
+        self.bindings(scope)
+            .iter()
+            .filter(|b| b.action == action)
+            .map(|b| b.chord.to_string())
+            .collect()
+    }
+
+    /// The first key of each action joined by `/` (e.g. `h/l`), for hints;
+    /// `None` if any of them is unbound
+    pub fn hint(&self, scope: Scope, actions: &[Action]) -> Option<String> {
+        let keys = actions
+            .iter()
+            .map(|action| self.keys(scope, *action).into_iter().next())
+            .collect::<Option<Vec<_>>>()?;
+        Some(keys.join("/"))
+    }
+
+    /// Help modal rows for a scope: each action once, with all its keys
+    ///
+    /// Consecutive mood keys collapse into one `F1-F5` row.
+    pub fn help_rows(&self, scope: Scope) -> Vec<(String, &'static str)> {
+        let mut rows: Vec<(String, &'static str)> = Vec::new();
+        let mut seen: Vec<Action> = Vec::new();
+        let mut moods: Vec<String> = Vec::new();
+        for binding in self.bindings(scope) {
+            if seen.contains(&binding.action) {
+                continue;
+            }
+            seen.push(binding.action);
+            let keys = self.keys(scope, binding.action).join("/");
+            match binding.action {
+                Action::Mood(_) => moods.push(keys),
+                action => rows.push((keys, action.description(scope))),
+            }
+            if moods.len() == 5 {
+                let keys = format!("{}-{}", moods[0], moods[4]);
+                rows.push((keys, "Set mood (1-5)"));
+                moods.clear();
+            }
+        }
+        rows.extend(
+            moods
+                .into_iter()
+                .map(|keys| (keys, Action::Mood(0).description(scope))),
+        );
+        rows
+    }
+}
+
+/// `keys.yaml` in the user's config directory
+///
+/// A missing file means the defaults; a file with unknown names or
+/// conflicting keys is refused with the reason.
+pub fn load_user_keymap() -> Result<Keymap> {
+    let Some(path) = user_config_path("keys.yaml").filter(|path| path.exists()) else {
+        return Ok(Keymap::default());
+    };
+    let yaml = fs::read_to_string(&path).with_context(|| format!("Failed to read {:?}", path))?;
+    Keymap::from_yaml(&yaml)
+        .map_err(anyhow::Error::msg)
+        .with_context(|| format!("Invalid keybindings in {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(s: &str) -> KeyChord {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_chords() {
+        assert_eq!(
+            chord("ctrl+r"),
+            KeyChord::new(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(chord("shift+g"), chord("G"));
+        assert_eq!(chord("space").code, KeyCode::Char(' '));
+        assert_eq!(chord("F5").code, KeyCode::F(5));
+        assert_eq!(chord("+").code, KeyCode::Char('+'));
+        assert_eq!(chord("alt++").modifiers, KeyModifiers::ALT);
+        assert!("f13".parse::<KeyChord>().is_err());
+        assert!("hyper+x".parse::<KeyChord>().is_err());
+        assert_eq!(chord("ctrl+r").to_string(), "Ctrl+R");
+        assert_eq!(chord("left").to_string(), "←");
+    }
+
+    #[test]
+    fn test_defaults_have_no_conflicts() {
+        assert_eq!(Keymap::default().check_conflicts(), Ok(()));
+        assert_eq!(Keymap::from_yaml(""), Ok(Keymap::default()));
+    }
+
+    #[test]
+    fn test_lookup_is_case_insensitive_unless_capital_is_bound() {
+        let keymap = Keymap::default();
+        let shifted = KeyChord::from(KeyEvent::new(KeyCode::Char('Q'), KeyModifiers::SHIFT));
+        assert_eq!(keymap.action(Scope::Dashboard, shifted), Some(Action::Quit));
+        assert_eq!(
+            keymap.action(Scope::Dashboard, chord("G")),
+            Some(Action::Last)
+        );
+        assert_eq!(keymap.action(Scope::Dashboard, chord("ctrl+n")), None);
+    }
+
+    #[test]
+    fn test_overrides_replace_an_actions_keys() {
+        let keymap = Keymap::from_yaml("dashboard:\n  sync: ctrl+s\n  warnings: []\n").unwrap();
+        assert_eq!(keymap.action(Scope::Dashboard, chord("s")), None);
+        assert_eq!(
+            keymap.action(Scope::Dashboard, chord("ctrl+s")),
+            Some(Action::Sync)
+        );
+        assert!(keymap.keys(Scope::Dashboard, Action::Warnings).is_empty());
+        assert_eq!(keymap.hint(Scope::Dashboard, &[Action::Warnings]), None);
+        // Untouched scopes keep their defaults
+        assert_eq!(
+            keymap.action(Scope::ReportDetail, chord("v")),
+            Some(Action::History)
+        );
+    }
+
+    #[test]
+    fn test_conflicts_and_unknown_names_are_refused() {
+        let err = Keymap::from_yaml("dashboard:\n  sync: w\n").unwrap_err();
+        assert!(err.contains("`w` is bound to both"), "{}", err);
+        let err = Keymap::from_yaml("dashboard:\n  sync: ctrl+r\n").unwrap_err();
+        assert!(err.contains("taken by global refresh"), "{}", err);
+        let err = Keymap::from_yaml("global:\n  refresh: r\n").unwrap_err();
+        assert!(err.contains("global refresh needs ctrl+"), "{}", err);
+        assert!(Keymap::from_yaml("global:\n  refresh: enter\n").is_err());
+        let keymap = Keymap::from_yaml("global:\n  refresh: [alt+r, f12]\n");
+        assert!(keymap.is_ok(), "{:?}", keymap.err());
+        assert!(Keymap::from_yaml("dashbord:\n  sync: x\n").is_err());
+        assert!(Keymap::from_yaml("note_viewer:\n  sync: x\n").is_err());
+        assert!(Keymap::from_yaml("dashboard:\n  sync: hyper+x\n").is_err());
+    }
+
+    #[test]
+    fn test_help_rows_follow_bindings() {
+        let keymap = Keymap::from_yaml("note_viewer:\n  edit: [e, i]\n").unwrap();
+        let rows = keymap.help_rows(Scope::NoteViewer);
+        assert_eq!(rows[0], ("e/i".to_string(), "Edit note"));
+        assert_eq!(rows[1], ("F1-F5".to_string(), "Set mood (1-5)"));
+    }
+}
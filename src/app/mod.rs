@@ -9,19 +9,31 @@
 //! `Effect` values that the runtime (main.rs) executes.
 
 mod input;
+pub mod keymap;
+mod note_editor;
+mod palette;
 mod state;
 mod update;
 
+use std::collections::HashSet;
+use std::path::PathBuf;
 use std::time::Instant;
 
-use ratatui::{backend::CrosstermBackend, Terminal};
+use ratatui::{backend::CrosstermBackend, layout::Size, Terminal};
 
-use crate::components::modal::NewReportState;
-use crate::model::{Context, JournalEntry, Report, ReportSummary, Workspace, WorkspaceSummary};
-use crate::storage::WorkspaceRepository;
+use crate::components::modal::{NewReportField, NewReportState};
+use crate::model::{
+    Context, JournalEntry, MoodWindow, Report, ReportSummary, RosterFilter, RosterSort, RosterView,
+    ThemeSettings, Workspace, WorkspaceSummary,
+};
+use crate::storage::migrate::MigrationStep;
+use crate::storage::{FileRevision, GitRepo, Issue, SyncOutcome, WorkspaceRepository};
 
 // Re-export public API
-pub use input::{handle_key_event, poll_event};
+pub use input::{handle_key_event, handle_mouse_event, poll_event};
+pub use keymap::Keymap;
+pub use note_editor::{is_content_empty, EditorCommand, NoteEditorState};
+pub use palette::{Command, CommandPaletteState, PaletteEntry, PaletteKind, EXPORT_FILE};
 
 /// Status message display duration
 pub(crate) const STATUS_MESSAGE_DURATION: std::time::Duration = std::time::Duration::from_secs(3);
@@ -34,8 +46,11 @@ pub(crate) const STATUS_MESSAGE_DURATION: std::time::Duration = std::time::Durat
 pub enum Effect {
     /// No side effect needed
     None,
-    /// Spawn external editor for current meeting
+    /// Spawn external editor for current meeting (`editor: external`)
     SpawnEditor { is_new: bool },
+    /// Pull and push the workspace's git repository in the background,
+    /// answering with `Msg::WorkspaceSynced`
+    SyncWorkspace,
 }
 
 /// Application view modes
@@ -47,14 +62,45 @@ pub enum ViewMode {
     ReportDetail,
     /// Viewing meeting notes
     NoteViewer,
+    /// Editing a meeting note in the built-in editor
+    NoteEditor,
     /// Modal for creating a new report
     NewReportModal,
     /// Modal for confirming deletion
     DeleteConfirmModal,
+    /// Modal listing the pending format migrations before they run
+    MigrateConfirmModal,
     /// Modal for quick entry input (mood observation)
     EntryInputModal,
     /// Help overlay
     Help,
+    /// Windowed mood analytics for the selected report
+    MoodAnalytics,
+    /// Everything to have in front of you before the selected report's
+    /// next 1-on-1
+    MeetingPrep,
+    /// Reports × weeks mood heatmap for the current roster
+    MoodHeatmap,
+    /// Past versions of a note or profile, from git
+    History,
+    /// Problems found in the workspace's files
+    Warnings,
+    /// Fuzzy-filtered list of commands, over the view it was opened from
+    CommandPalette,
+}
+
+/// What's under the mouse pointer, by hit-testing the layout the view
+/// renders (see `views::hit_test`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseTarget {
+    /// A dashboard card, by summary index
+    Report(usize),
+    /// A row of the meeting list, by display index (newest first)
+    Meeting(usize),
+    /// A new report modal field, and the option clicked within it
+    NewReportField(NewReportField, Option<usize>),
+    /// A dashboard table column heading, by the order it sorts in
+    Column(RosterSort),
 }
 
 /// Messages that can be sent to update application state (TEA pattern)
@@ -69,6 +115,10 @@ pub enum Msg {
     ShowHelp,
     /// Hide help overlay
     HideHelp,
+    /// Open the command palette over the current view
+    ShowCommandPalette,
+    /// Open the person finder over the current view
+    ShowPersonFinder,
 
     // Dashboard actions
     /// Select next item in list
@@ -82,30 +132,81 @@ pub enum Msg {
     SelectFirst,
     /// Jump to last item
     SelectLast,
+    /// Select an item by index (clicked)
+    Select(usize),
     /// View the selected report
     ViewReport,
     /// Enter the selected manager's hall (no-op on IC cards)
     EnterHall,
     /// Walk up one hall level (hard no-op at the root dashboard)
     ExitHall,
+    /// Show the mood heatmap for the current roster
+    ShowMoodHeatmap,
+    /// Show problems found in the workspace's files
+    ShowWarnings,
+    /// Show the next filter's reports
+    CycleFilter,
+    /// Show everyone again
+    ClearFilter,
+    /// Order the cards the next way
+    CycleSort,
+    /// Order by a table column; the column already sorted by goes back to
+    /// urgency
+    SortBy(RosterSort),
+    /// Switch the dashboard between cards and a table
+    ToggleLayout,
+    /// Show or hide the selected manager's squad line in the table
+    ToggleSquad,
+    /// Pull and push the workspace's git remote
+    SyncWorkspace,
+    /// A background git sync finished
+    WorkspaceSynced(Result<SyncOutcome, String>),
 
     // Report detail actions
     /// View a specific meeting by display index
     ViewMeeting(usize),
     /// Create a new meeting
     NewMeeting,
+    /// Open mood analytics for the current report
+    ShowMoodAnalytics,
+    /// Cycle the mood analytics window (30 → 90 → 365 days)
+    CycleMoodWindow,
+    /// Open meeting prep for the current report
+    ShowMeetingPrep,
+    /// Show git history of the current note (or the profile, from report detail)
+    ShowHistory,
 
     // Note viewer actions
-    /// Edit the current meeting in external editor
+    /// Edit the current meeting (in `$EDITOR` or inline, per the workspace)
     EditMeeting,
     /// Edit meeting directly from list (index is display index, newest first)
     EditMeetingFromList(usize),
     /// Update mood rating (1-5)
     UpdateMood(u8),
+    /// Scroll the note
+    ScrollNote(NoteScroll),
+    /// Start typing a search within the note
+    StartNoteSearch,
+    /// Jump to the search's next match, wrapping round at the end
+    NextMatch,
+    /// Jump to the search's previous match, wrapping round at the start
+    PrevMatch,
     /// Show delete confirmation modal
     ShowDeleteConfirm,
+
+    // Inline editor actions
+    /// Edit the note being written inline
+    EditorInput(EditorCommand),
+    /// Text pasted into the terminal
+    Paste(String),
+    /// Save the inline editor's note and go back to viewing it
+    SaveNote,
+    /// Leave the inline editor (twice to throw away unsaved changes)
+    CloseEditor,
     /// Confirm and execute deletion
     ConfirmDelete,
+    /// Back up and run the migrations shown in the confirmation modal
+    ConfirmMigrate,
 
     // Modal actions
     /// Show new report modal
@@ -122,6 +223,8 @@ pub enum Msg {
     ModalNextField,
     /// Move to previous field in modal
     ModalPrevField,
+    /// Focus a modal field, picking one of its options if given (clicked)
+    ModalSelect(NewReportField, Option<usize>),
 
     // Entry input modal actions (mood observation)
     /// Show entry input modal
@@ -136,6 +239,12 @@ pub enum Msg {
     // Data refresh
     /// Reload all data from disk
     RefreshData,
+    /// A background calendar sync finished; reload and show its outcome
+    CalendarSynced(String),
+    /// Files under the workspace changed on disk; reload what they affect
+    FilesChanged(Vec<PathBuf>),
+    /// The terminal is now this size
+    Resize(Size),
 
     // Input handling (for modals)
     /// Character input
@@ -160,6 +269,63 @@ pub struct HallFrame {
     pub selected_index: usize,
 }
 
+/// Someone in the org tree, wherever they sit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrgMember {
+    pub slug: String,
+    pub name: String,
+    pub title: Option<String>,
+    /// The team they lead, for managers
+    pub team_name: Option<String>,
+    /// Managers whose halls lead to them, outermost first (empty at the root)
+    pub halls: Vec<OrgHall>,
+}
+
+/// A hall on the way to an [`OrgMember`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrgHall {
+    pub slug: String,
+    pub name: String,
+    pub team_name: Option<String>,
+}
+
+/// How far to scroll the note viewer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteScroll {
+    /// Down (or up, when negative) this many lines
+    Lines(i32),
+    /// Down (or up) this many screens
+    Pages(i32),
+    Top,
+    Bottom,
+}
+
+/// A search within the open note
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoteSearch {
+    pub query: String,
+    /// Whether the query is still being typed
+    pub typing: bool,
+    /// Rendered line of the match last jumped to
+    pub current: Option<usize>,
+}
+
+/// File history view state
+#[derive(Debug, Clone)]
+pub struct HistoryState {
+    /// What the history is of, e.g. "Alex Chen · 2026-01-20T143000.md"
+    pub title: String,
+    /// Commits that touched the file, newest first
+    pub revisions: Vec<FileRevision>,
+    /// File contents at the selected revision
+    pub content: String,
+    /// Revision `content` was loaded for
+    pub(crate) shown: Option<usize>,
+    pub(crate) git: GitRepo,
+    /// View and list selection to restore on Back
+    pub(crate) return_to: (ViewMode, usize),
+}
+
 /// Main application state
 pub struct App {
     /// The workspace repository
@@ -174,8 +340,17 @@ pub struct App {
     pub entries_by_report: Vec<Vec<JournalEntry>>,
     /// Computed summaries for each report
     pub summaries: Vec<ReportSummary>,
+    /// Roster reports the filter hides, kept so the counts and filter
+    /// choices still cover everyone
+    pub(crate) hidden_reports: Vec<(Report, ReportSummary)>,
     /// Aggregate workspace summary
     pub workspace_summary: WorkspaceSummary,
+    /// Dashboard filter and sort (saved in the workspace's `.vibe-view`)
+    pub roster_view: RosterView,
+    /// Filters `f` cycles through, from the levels and tags on the roster
+    pub filter_choices: Vec<RosterFilter>,
+    /// Managers whose squad line the dashboard table shows, by slug
+    pub expanded_squads: HashSet<String>,
 
     // UI state
     /// Current view mode
@@ -192,6 +367,14 @@ pub struct App {
     pub editor_content: String,
     /// Current mood rating
     pub editor_mood: Option<u8>,
+    /// Lines the note is scrolled down by
+    pub note_scroll: u16,
+    /// The note being edited inline, while the editor is open
+    pub note_editor: Option<NoteEditorState>,
+    /// Search within the note being viewed
+    pub note_search: Option<NoteSearch>,
+    /// Terminal size, which the note viewer wraps and pages by
+    pub screen: Size,
 
     // New report modal state
     /// State for the new report modal form
@@ -205,6 +388,34 @@ pub struct App {
     /// Notes for the entry being created
     pub pending_entry_notes: String,
 
+    // Mood analytics state
+    /// Window the mood analytics view covers
+    pub mood_window: MoodWindow,
+
+    // Git state
+    /// File history being viewed
+    pub history: Option<HistoryState>,
+    /// Files the last sync couldn't merge (cleared by the next clean sync)
+    pub sync_conflicts: Vec<String>,
+    /// Whether a background sync is running
+    pub syncing: bool,
+
+    // Workspace checks
+    /// Problems in the workspace's files (unparseable profiles, bad moods…)
+    pub warnings: Vec<Issue>,
+    /// Dashboard selection to restore when the warnings modal closes
+    pub(crate) warnings_return_index: usize,
+
+    /// Command palette, while open
+    pub palette: Option<CommandPaletteState>,
+    /// Migrations the confirmation modal is asking about
+    pub pending_migration: Vec<MigrationStep>,
+
+    /// The user's own theme, layered over the workspace's `theme:` settings
+    pub user_theme: Option<ThemeSettings>,
+    /// Active keybindings
+    pub keymap: Keymap,
+
     // App state
     /// Flag to signal the app should quit
     pub should_quit: bool,
@@ -0,0 +1,521 @@
+//! Inline note editor
+//!
+//! A small multi-line text buffer for editing a note's body without leaving
+//! the TUI (`editor: inline` in the workspace settings). Lines and cursor
+//! columns count chars, not bytes. Typing runs, deletions and pastes each
+//! undo as one step.
+
+use crate::model::JournalEntry;
+
+/// Undo steps kept per editing session
+const UNDO_LIMIT: usize = 200;
+
+/// Lines PageUp and PageDown move by
+const PAGE_LINES: usize = 10;
+
+/// What a key does in the inline editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorCommand {
+    Insert(char),
+    Newline,
+    /// Indent by two spaces (a nested list level)
+    Tab,
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Up,
+    Down,
+    WordLeft,
+    WordRight,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    Undo,
+    Redo,
+}
+
+/// Kind of the last edit, so a run of the same kind undoes in one step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Typing,
+    Deleting,
+    Other,
+}
+
+/// Buffer contents and cursor, as saved for undo
+#[derive(Debug, Clone)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: (usize, usize),
+}
+
+/// The note being edited inline
+#[derive(Debug, Clone)]
+pub struct NoteEditorState {
+    lines: Vec<String>,
+    /// Line and char column of the cursor
+    cursor: (usize, usize),
+    /// Column Up and Down aim for, kept across shorter lines
+    goal_column: Option<usize>,
+    original: String,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<EditKind>,
+    /// Whether the note was just created (closing it empty deletes it)
+    pub is_new: bool,
+    /// Whether Esc was pressed once with unsaved changes
+    pub confirm_discard: bool,
+    /// The note, once its file or report went away outside the app; the
+    /// text stays open so it isn't lost
+    pub detached: Option<JournalEntry>,
+}
+
+impl NoteEditorState {
+    /// Start editing `text` with the cursor at the end
+    pub fn new(text: &str, is_new: bool) -> Self {
+        let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        let last = lines.len() - 1;
+        let cursor = (last, lines[last].chars().count());
+        Self {
+            lines,
+            cursor,
+            goal_column: None,
+            original: text.to_string(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: None,
+            is_new,
+            confirm_discard: false,
+            detached: None,
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Cursor line and char column
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// The note as it now reads, ending in a newline like the note files
+    pub fn text(&self) -> String {
+        let mut text = self.lines.join("\n");
+        text.push('\n');
+        text
+    }
+
+    /// Whether the text differs from what editing started with
+    pub fn is_modified(&self) -> bool {
+        self.text().trim_end() != self.original.trim_end()
+    }
+
+    pub fn apply(&mut self, command: EditorCommand) {
+        self.confirm_discard = false;
+        match command {
+            EditorCommand::Insert(c) => {
+                // A typing run ends at a word boundary, so undo goes word by word
+                let kind = if c.is_whitespace() {
+                    EditKind::Other
+                } else {
+                    EditKind::Typing
+                };
+                self.checkpoint(kind);
+                self.insert_char(c);
+            }
+            EditorCommand::Newline => {
+                self.checkpoint(EditKind::Other);
+                let (row, col) = self.cursor;
+                let rest = split_off_chars(&mut self.lines[row], col);
+                // Keep the indentation, so nested lists stay nested
+                let indent: String = self.lines[row].chars().take_while(|c| *c == ' ').collect();
+                let indent_len = indent.chars().count();
+                self.lines.insert(row + 1, indent + &rest);
+                self.cursor = (row + 1, indent_len);
+            }
+            EditorCommand::Tab => {
+                self.checkpoint(EditKind::Other);
+                self.insert_char(' ');
+                self.insert_char(' ');
+            }
+            EditorCommand::Backspace => {
+                let (row, col) = self.cursor;
+                if col > 0 {
+                    self.checkpoint(EditKind::Deleting);
+                    remove_char(&mut self.lines[row], col - 1);
+                    self.cursor.1 -= 1;
+                } else if row > 0 {
+                    self.checkpoint(EditKind::Deleting);
+                    let line = self.lines.remove(row);
+                    let prev_len = self.lines[row - 1].chars().count();
+                    self.lines[row - 1].push_str(&line);
+                    self.cursor = (row - 1, prev_len);
+                }
+            }
+            EditorCommand::Delete => {
+                let (row, col) = self.cursor;
+                if col < self.line_len(row) {
+                    self.checkpoint(EditKind::Deleting);
+                    remove_char(&mut self.lines[row], col);
+                } else if row + 1 < self.lines.len() {
+                    self.checkpoint(EditKind::Deleting);
+                    let next = self.lines.remove(row + 1);
+                    self.lines[row].push_str(&next);
+                }
+            }
+            EditorCommand::Undo => self.undo(),
+            EditorCommand::Redo => self.redo(),
+            movement => {
+                self.last_edit = None;
+                self.move_cursor(movement);
+                return;
+            }
+        }
+        self.goal_column = None;
+    }
+
+    /// Insert pasted text at the cursor, undone in one step
+    pub fn paste(&mut self, text: &str) {
+        self.confirm_discard = false;
+        self.checkpoint(EditKind::Other);
+        self.last_edit = None;
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let (row, col) = self.cursor;
+        let rest = split_off_chars(&mut self.lines[row], col);
+        let mut pasted = text.split('\n');
+        if let Some(first) = pasted.next() {
+            self.lines[row].push_str(first);
+        }
+        let mut at = row;
+        for line in pasted {
+            at += 1;
+            self.lines.insert(at, line.to_string());
+        }
+        self.cursor = (at, self.line_len(at));
+        self.lines[at].push_str(&rest);
+        self.goal_column = None;
+    }
+
+    fn insert_char(&mut self, c: char) {
+        let (row, col) = self.cursor;
+        let at = byte_index(&self.lines[row], col);
+        self.lines[row].insert(at, c);
+        self.cursor.1 += 1;
+    }
+
+    fn move_cursor(&mut self, movement: EditorCommand) {
+        let (row, col) = self.cursor;
+        let last_row = self.lines.len() - 1;
+        match movement {
+            EditorCommand::Left if col > 0 => self.cursor.1 -= 1,
+            EditorCommand::Left if row > 0 => self.cursor = (row - 1, self.line_len(row - 1)),
+            EditorCommand::Right if col < self.line_len(row) => self.cursor.1 += 1,
+            EditorCommand::Right if row < last_row => self.cursor = (row + 1, 0),
+            EditorCommand::Up => self.move_vertically(row.saturating_sub(1)),
+            EditorCommand::Down => self.move_vertically((row + 1).min(last_row)),
+            EditorCommand::PageUp => self.move_vertically(row.saturating_sub(PAGE_LINES)),
+            EditorCommand::PageDown => self.move_vertically((row + PAGE_LINES).min(last_row)),
+            EditorCommand::WordLeft => self.cursor = self.word_left(),
+            EditorCommand::WordRight => self.cursor = self.word_right(),
+            EditorCommand::Home => self.cursor.1 = 0,
+            EditorCommand::End => self.cursor.1 = self.line_len(row),
+            EditorCommand::Top => self.cursor = (0, 0),
+            EditorCommand::Bottom => self.cursor = (last_row, self.line_len(last_row)),
+            _ => {}
+        }
+        if !matches!(
+            movement,
+            EditorCommand::Up
+                | EditorCommand::Down
+                | EditorCommand::PageUp
+                | EditorCommand::PageDown
+        ) {
+            self.goal_column = None;
+        }
+    }
+
+    fn move_vertically(&mut self, row: usize) {
+        let goal = *self.goal_column.get_or_insert(self.cursor.1);
+        self.cursor = (row, goal.min(self.line_len(row)));
+    }
+
+    /// Start of the word before the cursor (the previous line's end at a
+    /// line start)
+    fn word_left(&self) -> (usize, usize) {
+        let (row, col) = self.cursor;
+        if col == 0 {
+            return match row {
+                0 => (0, 0),
+                _ => (row - 1, self.line_len(row - 1)),
+            };
+        }
+        let chars: Vec<char> = self.lines[row].chars().collect();
+        let mut at = col;
+        while at > 0 && !chars[at - 1].is_alphanumeric() {
+            at -= 1;
+        }
+        while at > 0 && chars[at - 1].is_alphanumeric() {
+            at -= 1;
+        }
+        (row, at)
+    }
+
+    /// End of the word after the cursor (the next line's start at a line end)
+    fn word_right(&self) -> (usize, usize) {
+        let (row, col) = self.cursor;
+        let chars: Vec<char> = self.lines[row].chars().collect();
+        if col == chars.len() {
+            return if row + 1 < self.lines.len() {
+                (row + 1, 0)
+            } else {
+                (row, col)
+            };
+        }
+        let mut at = col;
+        while at < chars.len() && !chars[at].is_alphanumeric() {
+            at += 1;
+        }
+        while at < chars.len() && chars[at].is_alphanumeric() {
+            at += 1;
+        }
+        (row, at)
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    /// Save the buffer for undo, unless this edit continues the last one
+    fn checkpoint(&mut self, kind: EditKind) {
+        if self.last_edit != Some(kind) || kind == EditKind::Other {
+            self.undo.push(self.snapshot());
+            if self.undo.len() > UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_edit = Some(kind);
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.undo.pop() {
+            self.redo.push(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(snapshot) = self.redo.pop() {
+            self.undo.push(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.lines = snapshot.lines;
+        self.cursor = snapshot.cursor;
+        self.last_edit = None;
+    }
+}
+
+/// Byte offset of char column `col` in `line`
+fn byte_index(line: &str, col: usize) -> usize {
+    line.char_indices().nth(col).map_or(line.len(), |(i, _)| i)
+}
+
+/// Cut `line` at char column `col`, returning what followed
+fn split_off_chars(line: &mut String, col: usize) -> String {
+    line.split_off(byte_index(line, col))
+}
+
+fn remove_char(line: &mut String, col: usize) {
+    line.remove(byte_index(line, col));
+}
+
+/// Check if meeting note content is effectively empty
+/// (only contains frontmatter and whitespace/template headers with no actual content)
+pub fn is_content_empty(content: &str) -> bool {
+    // Remove YAML frontmatter
+    let body = if content.starts_with("---") {
+        content.splitn(3, "---").nth(2).unwrap_or("")
+    } else {
+        content
+    };
+
+    // Check if remaining content is just whitespace and empty markdown structure
+    body.lines()
+        .filter(|line| {
+            let trimmed = line.trim();
+            // Skip empty lines, headers, and common template markers
+            !trimmed.is_empty()
+                && !trimmed.starts_with('#')
+                && !trimmed.starts_with("- [ ]")
+                && trimmed != "-"
+        })
+        .count()
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(editor: &mut NoteEditorState, text: &str) {
+        for c in text.chars() {
+            editor.apply(EditorCommand::Insert(c));
+        }
+    }
+
+    #[test]
+    fn test_typing_and_newlines_keep_indentation() {
+        let mut editor = NoteEditorState::new("# Notes\n  - café", false);
+        assert_eq!(editor.cursor(), (1, 8));
+        typed(&mut editor, " ok");
+        editor.apply(EditorCommand::Newline);
+        typed(&mut editor, "- next");
+
+        assert_eq!(editor.text(), "# Notes\n  - café ok\n  - next\n");
+        assert!(editor.is_modified());
+    }
+
+    #[test]
+    fn test_backspace_and_delete_join_lines() {
+        let mut editor = NoteEditorState::new("ab\ncd", false);
+        editor.apply(EditorCommand::Home);
+        editor.apply(EditorCommand::Backspace);
+        assert_eq!(editor.text(), "abcd\n");
+        assert_eq!(editor.cursor(), (0, 2));
+
+        editor.apply(EditorCommand::Delete);
+        assert_eq!(editor.text(), "abd\n");
+    }
+
+    #[test]
+    fn test_undo_goes_word_by_word() {
+        let mut editor = NoteEditorState::new("", false);
+        typed(&mut editor, "hello world");
+        assert_eq!(editor.text(), "hello world\n");
+
+        editor.apply(EditorCommand::Undo);
+        assert_eq!(editor.text(), "hello \n");
+        editor.apply(EditorCommand::Undo);
+        assert_eq!(editor.text(), "hello\n");
+        editor.apply(EditorCommand::Undo);
+        assert_eq!(editor.text(), "\n");
+        assert!(!editor.is_modified());
+
+        editor.apply(EditorCommand::Redo);
+        assert_eq!(editor.text(), "hello\n");
+    }
+
+    #[test]
+    fn test_paste_splits_lines_and_undoes_at_once() {
+        let mut editor = NoteEditorState::new("start end", false);
+        editor.apply(EditorCommand::Home);
+        editor.apply(EditorCommand::WordRight);
+        editor.paste(" one\r\ntwo");
+        assert_eq!(editor.text(), "start one\ntwo end\n");
+        assert_eq!(editor.cursor(), (1, 3));
+
+        editor.apply(EditorCommand::Undo);
+        assert_eq!(editor.text(), "start end\n");
+    }
+
+    #[test]
+    fn test_vertical_moves_remember_the_column() {
+        let mut editor = NoteEditorState::new("long line here\nab\nanother long one", false);
+        editor.apply(EditorCommand::Top);
+        editor.apply(EditorCommand::WordRight);
+        editor.apply(EditorCommand::WordRight);
+        assert_eq!(editor.cursor(), (0, 9));
+
+        editor.apply(EditorCommand::Down);
+        assert_eq!(editor.cursor(), (1, 2));
+        editor.apply(EditorCommand::Down);
+        assert_eq!(editor.cursor(), (2, 9));
+        editor.apply(EditorCommand::WordLeft);
+        assert_eq!(editor.cursor(), (2, 8));
+    }
+
+    #[test]
+    fn test_is_content_empty_with_only_whitespace() {
+        assert!(is_content_empty(""));
+        assert!(is_content_empty("   "));
+        assert!(is_content_empty("\n\n\n"));
+    }
+
+    #[test]
+    fn test_is_content_empty_with_frontmatter_only() {
+        let content = "---\nmood: 3\n---\n\n";
+        assert!(is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_with_headers_only() {
+        let content = "# Title\n## Section\n### Subsection\n";
+        assert!(is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_with_template_structure() {
+        let content = "---\n---\n\n# 1-on-1 - January 22, 2026\n\n## Discussion\n\n## Notes\n\n## Action Items\n- [ ] \n";
+        assert!(is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_with_empty_checkbox() {
+        let content = "- [ ] \n- [ ] \n";
+        assert!(is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_with_bare_dash() {
+        let content = "# Header\n-\n";
+        assert!(is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_false_with_actual_content() {
+        let content = "Some actual notes here";
+        assert!(!is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_false_with_content_after_frontmatter() {
+        let content = "---\nmood: 3\n---\n\nDiscussed project timeline.";
+        assert!(!is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_false_with_filled_checkbox() {
+        let content = "- [x] Completed task\n";
+        assert!(!is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_false_with_list_items() {
+        let content = "# Notes\n- First item\n- Second item\n";
+        assert!(!is_content_empty(content));
+    }
+
+    #[test]
+    fn test_is_content_empty_false_with_content_under_headers() {
+        let content = "---\n---\n\n# 1-on-1\n\n## Discussion\n\nWe talked about the roadmap.\n";
+        assert!(!is_content_empty(content));
+    }
+}
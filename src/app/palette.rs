@@ -0,0 +1,453 @@
+//! Command palette and person finder
+//!
+//! `:` or Ctrl+P lists everything the current view can do, bound to a key
+//! or not, plus everyone in the org tree and the workspace commands that
+//! otherwise need the command line. Typing filters the list fuzzily; Enter
+//! runs the selection in the view the palette was opened from.
+//!
+//! `/` opens the same picker with only people in it, matched on name,
+//! title and team, for getting to someone three halls down in one go.
+
+use std::cmp::Reverse;
+
+use anyhow::Result;
+use chrono::{Local, Utc};
+
+use super::input::action_msg;
+use super::keymap::{Action, Scope};
+use super::{App, Effect, Keymap, Msg, OrgMember, ViewMode};
+use crate::calendar;
+use crate::storage::{migrate, write_atomic};
+use crate::utils::fuzzy_score;
+
+/// File the palette's calendar export writes, in the workspace root
+pub const EXPORT_FILE: &str = "vibe-manager.ics";
+
+/// Movement keys are left out: there's nothing to discover about them
+const NOT_LISTED: &[Action] = &[
+    Action::Next,
+    Action::Prev,
+    Action::PrevOrAscend,
+    Action::First,
+    Action::Last,
+    Action::PageDown,
+    Action::PageUp,
+    Action::CommandPalette,
+];
+
+/// Which picker the palette is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteKind {
+    /// Actions, people and workspace commands (`:`)
+    Commands,
+    /// People only (`/`)
+    People,
+}
+
+/// What a palette entry does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// A keymap action, as if its key were pressed
+    Action(Action),
+    /// Open someone's details, entering the halls above them
+    JumpTo(OrgMember),
+    /// Check the workspace's files (`vibe-manager doctor`)
+    Doctor,
+    /// List the pending format upgrades and, once confirmed, run them after
+    /// a backup (`vibe-manager migrate`)
+    Migrate,
+    /// Write the schedule to [`EXPORT_FILE`] in the workspace
+    /// (`vibe-manager export-ics`)
+    ExportIcs,
+}
+
+/// One line of the palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteEntry {
+    pub label: String,
+    /// Keys that do the same, or where a person sits in the org tree
+    pub detail: String,
+    /// Matched as well as the label, but not shown: a person's title and
+    /// teams
+    pub keywords: String,
+    pub command: Command,
+}
+
+/// Command palette state
+#[derive(Debug, Clone)]
+pub struct CommandPaletteState {
+    pub kind: PaletteKind,
+    pub query: String,
+    pub entries: Vec<PaletteEntry>,
+    /// Position in [`matches`](Self::matches)
+    pub selected: usize,
+    /// View the palette was opened from, and runs its command in
+    pub return_to: ViewMode,
+}
+
+impl CommandPaletteState {
+    /// Entries matching the query, best first (in listed order on ties)
+    pub fn matches(&self) -> Vec<&PaletteEntry> {
+        let mut scored: Vec<(i32, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let score = fuzzy_score(&self.query, &entry.label)
+                    .max(fuzzy_score(&self.query, &entry.keywords))?;
+                Some((score, i))
+            })
+            .collect();
+        scored.sort_by_key(|&(score, i)| (Reverse(score), i));
+        scored.into_iter().map(|(_, i)| &self.entries[i]).collect()
+    }
+
+    /// Move the selection, wrapping at either end
+    pub fn move_selection(&mut self, forward: bool) {
+        let len = self.matches().len();
+        if len == 0 {
+            return;
+        }
+        self.selected = if forward {
+            (self.selected + 1) % len
+        } else {
+            (self.selected + len - 1) % len
+        };
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+}
+
+/// Palette entries for a view's actions, then the global ones
+fn action_entries(keymap: &Keymap, scope: Scope) -> Vec<PaletteEntry> {
+    let mut entries: Vec<PaletteEntry> = Vec::new();
+    for scope in [scope, Scope::Global] {
+        for action in Keymap::actions(scope) {
+            let command = Command::Action(action);
+            if NOT_LISTED.contains(&action) || entries.iter().any(|e| e.command == command) {
+                continue;
+            }
+            let label = match action {
+                Action::Mood(mood) => format!("Set mood to {}", mood),
+                action => action.description(scope).to_string(),
+            };
+            entries.push(PaletteEntry {
+                label,
+                detail: keymap.keys(scope, action).join("/"),
+                keywords: String::new(),
+                command,
+            });
+        }
+    }
+    entries
+}
+
+/// An entry opening `member`, matched on their title and teams too
+fn person_entry(member: OrgMember, label: String, detail: String) -> PaletteEntry {
+    let keywords = member
+        .title
+        .iter()
+        .chain(&member.team_name)
+        .chain(member.halls.last().and_then(|hall| hall.team_name.as_ref()))
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" · ");
+    PaletteEntry {
+        label,
+        detail,
+        keywords,
+        command: Command::JumpTo(member),
+    }
+}
+
+/// Where someone sits: the names of the halls above them
+fn hall_path(member: &OrgMember) -> String {
+    member
+        .halls
+        .iter()
+        .map(|hall| hall.name.as_str())
+        .collect::<Vec<_>>()
+        .join(" ▸ ")
+}
+
+impl App {
+    /// The current view's keymap scope, if a palette can open over it (not
+    /// over modals)
+    fn palette_scope(&self) -> Option<Scope> {
+        match self.view_mode {
+            ViewMode::Dashboard
+            | ViewMode::ReportDetail
+            | ViewMode::NoteViewer
+            | ViewMode::MoodAnalytics
+            | ViewMode::MeetingPrep
+            | ViewMode::MoodHeatmap
+            | ViewMode::History => Scope::for_view(self.view_mode),
+            _ => None,
+        }
+    }
+
+    fn show_palette(&mut self, kind: PaletteKind, entries: Vec<PaletteEntry>) {
+        self.palette = Some(CommandPaletteState {
+            kind,
+            query: String::new(),
+            entries,
+            selected: 0,
+            return_to: self.view_mode,
+        });
+        self.view_mode = ViewMode::CommandPalette;
+    }
+
+    /// Open the command palette over the current view
+    pub(crate) fn open_command_palette(&mut self) {
+        let Some(scope) = self.palette_scope() else {
+            return;
+        };
+
+        let mut entries = action_entries(&self.keymap, scope);
+        if self.view_mode == ViewMode::Dashboard {
+            entries.extend([
+                PaletteEntry {
+                    label: "Check workspace for problems".to_string(),
+                    detail: "doctor".to_string(),
+                    keywords: String::new(),
+                    command: Command::Doctor,
+                },
+                PaletteEntry {
+                    label: "Migrate workspace to the current format".to_string(),
+                    detail: "migrate".to_string(),
+                    keywords: String::new(),
+                    command: Command::Migrate,
+                },
+                PaletteEntry {
+                    label: format!("Export schedule to {}", EXPORT_FILE),
+                    detail: "export-ics".to_string(),
+                    keywords: String::new(),
+                    command: Command::ExportIcs,
+                },
+            ]);
+        }
+        entries.extend(self.org_directory().into_iter().map(|member| {
+            let label = format!("Go to {}", member.name);
+            let detail = hall_path(&member);
+            person_entry(member, label, detail)
+        }));
+        self.show_palette(PaletteKind::Commands, entries);
+    }
+
+    /// Open the person finder over the current view
+    pub(crate) fn open_person_finder(&mut self) {
+        if self.palette_scope().is_none() {
+            return;
+        }
+        let entries = self
+            .org_directory()
+            .into_iter()
+            .map(|member| {
+                let label = member.name.clone();
+                let detail = match (&member.title, hall_path(&member)) {
+                    (Some(title), path) if path.is_empty() => title.clone(),
+                    (Some(title), path) => format!("{} · {}", title, path),
+                    (None, path) => path,
+                };
+                person_entry(member, label, detail)
+            })
+            .collect();
+        self.show_palette(PaletteKind::People, entries);
+    }
+
+    /// Close the palette and run its selected command
+    pub(crate) fn run_palette_selection(&mut self) -> Result<Effect> {
+        let Some(palette) = self.palette.take() else {
+            return Ok(Effect::None);
+        };
+        self.view_mode = palette.return_to;
+        let Some(entry) = palette.matches().get(palette.selected).copied().cloned() else {
+            return Ok(Effect::None);
+        };
+
+        match entry.command {
+            Command::Action(action) => {
+                if let Some(msg) = action_msg(self, action) {
+                    return self.update(msg);
+                }
+            }
+            Command::JumpTo(member) => self.jump_to(&member)?,
+            Command::Doctor => {
+                self.check_workspace();
+                return self.update(Msg::ShowWarnings);
+            }
+            Command::Migrate => self.confirm_migration(),
+            Command::ExportIcs => self.export_schedule(),
+        }
+        Ok(Effect::None)
+    }
+
+    /// Show what `vibe-manager migrate` would change and ask before doing it
+    fn confirm_migration(&mut self) {
+        match migrate::pending(&self.repo) {
+            Ok(steps) if steps.is_empty() => self.set_status("Workspace is up to date"),
+            Ok(steps) => {
+                self.pending_migration = steps;
+                self.view_mode = ViewMode::MigrateConfirmModal;
+            }
+            Err(e) => self.set_status(format!("Error: {}", e)),
+        }
+    }
+
+    /// Close the migration modal without changing anything
+    pub(crate) fn cancel_migration(&mut self) {
+        self.pending_migration.clear();
+        self.view_mode = ViewMode::Dashboard;
+    }
+
+    /// `vibe-manager migrate`, backing up first, then reload
+    pub(crate) fn migrate_workspace(&mut self) -> Result<()> {
+        self.cancel_migration();
+        match migrate::migrate(&self.repo, true) {
+            Ok(report) => match report.steps.last() {
+                Some(step) => self.set_status(format!(
+                    "✓ Migrated to version {}; backup in {}",
+                    step.to,
+                    report
+                        .backup
+                        .as_ref()
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_default()
+                )),
+                None => self.set_status("Workspace is up to date"),
+            },
+            Err(e) => {
+                self.set_status(format!("Error: {}", e));
+                return Ok(());
+            }
+        }
+        self.workspace = self.repo.load()?;
+        self.load_data()?;
+        self.check_workspace();
+        Ok(())
+    }
+
+    /// `vibe-manager export-ics -o <workspace>/vibe-manager.ics`
+    fn export_schedule(&mut self) {
+        let settings = &self.workspace.config.settings;
+        let path = self.repo.path().join(EXPORT_FILE);
+        let written = calendar::build_schedule(&self.repo, settings, Local::now().date_naive())
+            .map_err(|e| e.to_string())
+            .and_then(|events| {
+                let ics = calendar::write_ics(&events, Utc::now().naive_utc());
+                write_atomic(&path, ics)
+                    .map(|()| events.len())
+                    .map_err(|e| e.to_string())
+            });
+        match written {
+            Ok(count) => {
+                self.set_status(format!("✓ Wrote {} event(s) to {}", count, path.display()))
+            }
+            Err(e) => self.set_status(format!("Error: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_entries_list_unbound_actions_once() {
+        let keymap = Keymap::from_yaml("dashboard:\n  sync: []\n").unwrap();
+        let entries = action_entries(&keymap, Scope::Dashboard);
+
+        let sync = entries
+            .iter()
+            .find(|e| e.command == Command::Action(Action::Sync))
+            .expect("unbound actions are still listed");
+        assert_eq!(sync.detail, "");
+        // Refresh is in both the dashboard and global scopes
+        let refreshes = entries
+            .iter()
+            .filter(|e| e.command == Command::Action(Action::Refresh))
+            .count();
+        assert_eq!(refreshes, 1);
+        assert!(!entries
+            .iter()
+            .any(|e| e.command == Command::Action(Action::Next)));
+    }
+
+    #[test]
+    fn test_matches_rank_and_selection_wraps() {
+        let entry = |label: &str| PaletteEntry {
+            label: label.to_string(),
+            detail: String::new(),
+            keywords: String::new(),
+            command: Command::Doctor,
+        };
+        let mut palette = CommandPaletteState {
+            kind: PaletteKind::Commands,
+            query: String::new(),
+            entries: vec![entry("Git sync (pull & push)"), entry("Go to Sam Reyes")],
+            selected: 0,
+            return_to: ViewMode::Dashboard,
+        };
+        palette.push('s');
+        palette.push('r');
+        let labels: Vec<&str> = palette.matches().iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(labels, ["Go to Sam Reyes"]);
+
+        palette.pop();
+        palette.move_selection(false);
+        assert_eq!(palette.selected, 1);
+        palette.move_selection(true);
+        assert_eq!(palette.selected, 0);
+    }
+
+    #[test]
+    fn test_people_match_on_title_and_team() {
+        use crate::app::OrgHall;
+
+        let member = |name: &str, title: &str, hall_team: Option<&str>| OrgMember {
+            slug: name.to_lowercase(),
+            name: name.to_string(),
+            title: Some(title.to_string()),
+            team_name: None,
+            halls: vec![OrgHall {
+                slug: "chris".to_string(),
+                name: "Chris".to_string(),
+                team_name: hall_team.map(str::to_string),
+            }],
+        };
+        let mut palette = CommandPaletteState {
+            kind: PaletteKind::People,
+            query: String::new(),
+            entries: vec![
+                person_entry(
+                    member("Ana", "Software Engineer", Some("Platform Team")),
+                    "Ana".to_string(),
+                    String::new(),
+                ),
+                person_entry(
+                    member("Taylor", "Team Lead", None),
+                    "Taylor".to_string(),
+                    String::new(),
+                ),
+            ],
+            selected: 0,
+            return_to: ViewMode::Dashboard,
+        };
+        let names = |palette: &CommandPaletteState| -> Vec<String> {
+            palette.matches().iter().map(|e| e.label.clone()).collect()
+        };
+
+        palette.query = "platform".to_string();
+        assert_eq!(names(&palette), ["Ana"]);
+        palette.query = "lead".to_string();
+        assert_eq!(names(&palette), ["Taylor"]);
+    }
+}
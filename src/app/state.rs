@@ -2,23 +2,51 @@
 //!
 //! This module handles loading, initializing, and managing application state.
 
-use std::path::PathBuf;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use anyhow::{anyhow, Result};
+use ratatui::layout::{Position, Rect, Size};
+use ratatui::text::Line;
 
-use super::{App, HallFrame, ViewMode, STATUS_MESSAGE_DURATION};
+use super::note_editor::{is_content_empty, NoteEditorState};
+use super::{
+    App, Effect, HallFrame, HistoryState, Keymap, Msg, NoteScroll, NoteSearch, OrgHall, OrgMember,
+    ViewMode, STATUS_MESSAGE_DURATION,
+};
+use crate::components::markdown::{find_matches, render_markdown};
 use crate::components::modal::NewReportState;
 use crate::model::{
-    compute_report_summary, compute_report_summary_with_frequency, compute_team_metrics,
-    compute_workspace_summary, manager_urgency_bonus, Context, JournalEntry, WorkspaceSummary,
+    compute_report_summary, compute_report_summary_with_frequency,
+    compute_team_metrics_with_settings, compute_workspace_summary, Context, EditorKind,
+    JournalEntry, MoodWindow, Report, ReportSummary, RosterFilter, RosterLayout, RosterView,
+    ThemeSettings, WorkspaceSummary,
 };
-use crate::storage::{ReportRepository, WorkspaceRepository};
+use crate::storage::{doctor, GitRepo, ReportRepository, WorkspaceRepository};
+use crate::theme::{self, Theme};
+use crate::views::note_page;
+
+/// Selection recorded across a reload, see [`App::reload_changed`]
+struct SelectionKeys {
+    report_slug: Option<String>,
+    meeting_path: Option<PathBuf>,
+    entry_path: Option<PathBuf>,
+    /// The note open in the inline editor
+    editing: Option<JournalEntry>,
+}
 
 impl App {
     /// Create new application from workspace path
     pub fn new(workspace_path: PathBuf) -> Result<Self> {
-        let repo = WorkspaceRepository::open(&workspace_path)?;
+        Self::from_repo(WorkspaceRepository::open(&workspace_path)?)
+    }
+
+    /// Create new application from an opened (and, if encrypted, unlocked) workspace
+    ///
+    /// The roster is unfiltered; see [`with_roster_view`](Self::with_roster_view)
+    /// for the saved dashboard view.
+    pub fn from_repo(repo: WorkspaceRepository) -> Result<Self> {
         let workspace = repo.load()?;
 
         let mut app = App {
@@ -28,6 +56,7 @@ impl App {
             reports: Vec::new(),
             entries_by_report: Vec::new(),
             summaries: Vec::new(),
+            hidden_reports: Vec::new(),
             workspace_summary: WorkspaceSummary {
                 team_size: 0,
                 active_count: 0,
@@ -35,25 +64,72 @@ impl App {
                 average_mood: None,
                 total_report_count: 0,
             },
+            roster_view: RosterView::default(),
+            filter_choices: Vec::new(),
+            expanded_squads: HashSet::new(),
             view_mode: ViewMode::Dashboard,
             selected_index: 0,
             selected_report_index: None,
             selected_entry_index: None,
             editor_content: String::new(),
             editor_mood: None,
+            note_scroll: 0,
+            note_editor: None,
+            note_search: None,
+            screen: Size::new(80, 24),
             new_report_state: NewReportState::default(),
             pending_entry_mood: None,
             pending_entry_context: Context::Standup,
             pending_entry_notes: String::new(),
+            mood_window: MoodWindow::default(),
+            history: None,
+            sync_conflicts: Vec::new(),
+            syncing: false,
+            warnings: Vec::new(),
+            warnings_return_index: 0,
+            palette: None,
+            pending_migration: Vec::new(),
+            user_theme: None,
+            keymap: Keymap::default(),
             should_quit: false,
             status_message: None,
             delete_from_list: false,
         };
 
+        app.apply_theme();
         app.load_data()?;
+        app.check_workspace();
         Ok(app)
     }
 
+    /// Layer the user's theme (see [`theme::load_user_theme`]) over the workspace's
+    pub fn with_user_theme(mut self, user_theme: Option<ThemeSettings>) -> Self {
+        self.user_theme = user_theme;
+        self.apply_theme();
+        self
+    }
+
+    /// Filter and sort the dashboard as in `view` (see
+    /// [`WorkspaceRepository::load_roster_view`])
+    pub fn with_roster_view(mut self, view: RosterView) -> Result<Self> {
+        self.roster_view = view;
+        self.load_data()?;
+        Ok(self)
+    }
+
+    /// Use the user's keybindings (see [`super::keymap::load_user_keymap`])
+    pub fn with_keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Make the configured theme the one this thread renders with
+    pub(crate) fn apply_theme(&self) {
+        let mut layers = vec![&self.workspace.config.settings.theme];
+        layers.extend(self.user_theme.as_ref());
+        theme::set_theme(Theme::resolve(&layers, theme::no_color_requested()));
+    }
+
     /// Repositories of the roster at the current hall path
     ///
     /// Empty stack = workspace root; otherwise walk the stack down through
@@ -64,6 +140,9 @@ impl App {
             return Ok(self.repo.list_reports()?);
         };
         let mut repo = self.repo.report(&first.slug);
+        if !repo.path().is_dir() {
+            return Err(anyhow!("hall member not found: {}", first.slug));
+        }
         for frame in frames {
             repo = repo
                 .list_team_members()?
@@ -82,72 +161,33 @@ impl App {
         self.reports.clear();
         self.entries_by_report.clear();
         self.summaries.clear();
-
-        // 2nd-level overdue status follows the workspace skip-level cadence,
-        // not each member's own profile frequency (per-member overrides are Phase 3)
-        let second_level_days =
-            frequency_to_days(&self.workspace.config.settings.default_2nd_level_frequency);
-        let overdue_threshold = self.workspace.config.settings.overdue_threshold_days;
+        self.hidden_reports.clear();
 
         // Collect all report data
-        let mut all_data: Vec<_> = report_repos
-            .into_iter()
-            .filter_map(|report_repo| {
-                let mut report = report_repo.load().ok()?;
-
-                // Load team members for managers, computing their summaries
-                // in-loop to build TeamMetrics (members never enter the
-                // index-aligned reports/entries/summaries Vecs)
-                let mut member_summaries = Vec::new();
-                if report_repo.has_team() {
-                    for team_repo in report_repo.list_team_members().unwrap_or_default() {
-                        if let Ok(team_member) = team_repo.load() {
-                            let member_entries = team_repo.entries().list().unwrap_or_default();
-                            member_summaries.push(compute_report_summary_with_frequency(
-                                &team_member,
-                                &member_entries,
-                                second_level_days,
-                                overdue_threshold,
-                            ));
-                            report.team.push(team_member);
-                        }
-                    }
-                }
-
-                let entries = report_repo.entries().list().unwrap_or_default();
-                // Inside a hall everyone is a 2nd-level report: their overdue
-                // status follows the skip-level cadence, not their profile
-                let mut summary = if in_hall {
-                    compute_report_summary_with_frequency(
-                        &report,
-                        &entries,
-                        second_level_days,
-                        overdue_threshold,
-                    )
-                } else {
-                    compute_report_summary(&report, &entries, overdue_threshold)
-                };
-
-                if report.is_manager() {
-                    let team_metrics = compute_team_metrics(&member_summaries);
-                    summary.urgency_score += manager_urgency_bonus(&team_metrics);
-                    summary.team_metrics = Some(team_metrics);
-                }
-                Some((report, entries, summary))
-            })
+        let all_data: Vec<_> = report_repos
+            .iter()
+            .filter_map(|report_repo| self.load_roster_report(report_repo, in_hall))
             .collect();
 
-        // Sort by urgency score (highest first = needs most attention)
-        all_data.sort_by_key(|d| std::cmp::Reverse(d.2.urgency_score));
+        let scoring = self.workspace.config.settings.mood_scoring;
+        let RosterView { filter, sort, .. } = &self.roster_view;
+        let (mut shown, hidden): (Vec<_>, Vec<_>) = all_data
+            .into_iter()
+            .partition(|(report, _, summary)| filter.matches(report, summary, scoring));
+        self.hidden_reports = hidden
+            .into_iter()
+            .map(|(report, _, summary)| (report, summary))
+            .collect();
+        // Urgency by default: highest first = needs most attention
+        shown.sort_by(|a, b| sort.compare((&a.0, &a.2), (&b.0, &b.2)));
 
         // Unpack into separate vectors
-        for (report, entries, summary) in all_data {
+        for (report, entries, summary) in shown {
             self.reports.push(report);
             self.entries_by_report.push(entries);
             self.summaries.push(summary);
         }
-
-        self.workspace_summary = compute_workspace_summary(&self.summaries);
+        self.refresh_roster_totals();
 
         // Reset selection if out of bounds
         if self.selected_index >= self.reports.len() && !self.reports.is_empty() {
@@ -157,6 +197,344 @@ impl App {
         Ok(())
     }
 
+    /// Load one roster report with its entries and summary
+    ///
+    /// `None` if its profile can't be read (the report is left off the roster).
+    fn load_roster_report(
+        &self,
+        report_repo: &ReportRepository,
+        in_hall: bool,
+    ) -> Option<(Report, Vec<JournalEntry>, ReportSummary)> {
+        // 2nd-level overdue status follows the workspace skip-level cadence,
+        // not each member's own profile frequency (per-member overrides are Phase 3)
+        let settings = &self.workspace.config.settings;
+        let second_level_days = settings.second_level_frequency_days();
+
+        let mut report = report_repo.load().ok()?;
+
+        // Load team members for managers, computing their summaries
+        // in-loop to build TeamMetrics (members never enter the
+        // index-aligned reports/entries/summaries Vecs)
+        let mut member_summaries = Vec::new();
+        if report_repo.has_team() {
+            for team_repo in report_repo.list_team_members().unwrap_or_default() {
+                if let Ok(team_member) = team_repo.load() {
+                    let member_entries = team_repo.entries().list().unwrap_or_default();
+                    member_summaries.push(compute_report_summary_with_frequency(
+                        &team_member,
+                        &member_entries,
+                        second_level_days,
+                        settings,
+                    ));
+                    report.team.push(team_member);
+                }
+            }
+        }
+
+        let entries = report_repo.entries().list().unwrap_or_default();
+        // Inside a hall everyone is a 2nd-level report: their overdue
+        // status follows the skip-level cadence, not their profile
+        let mut summary = if in_hall {
+            compute_report_summary_with_frequency(&report, &entries, second_level_days, settings)
+        } else {
+            compute_report_summary(&report, &entries, settings)
+        };
+
+        if report.is_manager() {
+            summary.attach_team_metrics(compute_team_metrics_with_settings(
+                &member_summaries,
+                settings,
+            ));
+        }
+        Some((report, entries, summary))
+    }
+
+    /// Reload what changed on disk, keeping the current view and selection
+    ///
+    /// A change inside a roster report's directory (notes, profile or team)
+    /// reloads just that report, in place so the list doesn't reorder under
+    /// the cursor. A report appearing or disappearing, or an edit to the
+    /// workspace config, reloads the whole roster. Changes outside the
+    /// roster being shown are ignored. A config that no longer loads keeps
+    /// the previous settings, and a hall that is gone drops back to the root
+    /// roster, each with a status message.
+    pub(crate) fn reload_changed(&mut self, paths: &[PathBuf]) -> Result<()> {
+        let root = self.repo.path().to_path_buf();
+        let config_changed = paths.iter().any(|p| {
+            p.parent() == Some(root.as_path())
+                && p.file_name()
+                    .is_some_and(|n| n.to_string_lossy().starts_with(".vibe-"))
+        });
+        let roster_dir = if self.hall_stack.is_empty() {
+            Some(root.clone())
+        } else {
+            self.reports
+                .first()
+                .and_then(|r| r.path.parent())
+                .map(PathBuf::from)
+        };
+
+        let mut roster_changed = config_changed;
+        let mut changed = BTreeSet::new();
+        for path in paths {
+            match self.reports.iter().position(|r| path.starts_with(&r.path)) {
+                Some(idx) if path != &self.reports[idx].path => {
+                    changed.insert(idx);
+                }
+                // The report directory itself was added, moved or removed
+                Some(_) => roster_changed = true,
+                None => {
+                    roster_changed |= roster_dir.as_ref().is_none_or(|dir| path.starts_with(dir))
+                }
+            }
+        }
+        if !roster_changed && changed.is_empty() {
+            return Ok(());
+        }
+
+        let selection = self.selection_keys();
+        if config_changed {
+            // A config saved half-way or with a typo keeps the settings in use
+            match self.repo.load() {
+                Ok(workspace) => {
+                    self.workspace = workspace;
+                    self.apply_theme();
+                }
+                Err(e) => self.set_status(format!("Kept the previous settings: {}", e)),
+            }
+        }
+        if roster_changed {
+            if self.reload_roster()? {
+                return Ok(());
+            }
+        } else {
+            let in_hall = !self.hall_stack.is_empty();
+            for idx in changed {
+                match self.load_roster_report(&self.report_repo(idx), in_hall) {
+                    Some((report, entries, summary)) => {
+                        self.reports[idx] = report;
+                        self.entries_by_report[idx] = entries;
+                        self.summaries[idx] = summary;
+                    }
+                    // Profile gone or unreadable: let the roster drop it
+                    None => {
+                        if self.reload_roster()? {
+                            return Ok(());
+                        }
+                        break;
+                    }
+                }
+            }
+            self.refresh_roster_totals();
+        }
+        self.restore_selection(selection);
+        Ok(())
+    }
+
+    /// [`Self::load_data`], or back to the root roster if the hall being
+    /// viewed is gone (e.g. removed by a pull); returns whether it was
+    fn reload_roster(&mut self) -> Result<bool> {
+        match self.load_data() {
+            Ok(()) => Ok(false),
+            Err(_) if !self.hall_stack.is_empty() => {
+                // The failed load left the old roster, so the note is still there
+                let editing = self.editing_entry();
+                let editor = self.note_editor.take();
+                self.leave_halls()?;
+                match editor {
+                    Some(mut editor) => {
+                        editor.detached = editing;
+                        self.note_editor = Some(editor);
+                        self.view_mode = ViewMode::NoteEditor;
+                        self.set_status("The hall you were in is gone; this note can't be saved");
+                    }
+                    None => self.set_status("The hall you were in is gone; back to the top"),
+                }
+                Ok(true)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Back to the root roster with nothing open
+    fn leave_halls(&mut self) -> Result<()> {
+        self.hall_stack.clear();
+        self.history = None;
+        self.note_editor = None;
+        self.selected_report_index = None;
+        self.selected_entry_index = None;
+        self.selected_index = 0;
+        self.view_mode = ViewMode::Dashboard;
+        self.load_data()
+    }
+
+    /// Reload the roster, keeping the open report, meeting and note selected
+    /// even if the reload reorders or removes them
+    pub(crate) fn reload_keeping_selection(&mut self) -> Result<()> {
+        let selection = self.selection_keys();
+        self.load_data()?;
+        self.restore_selection(selection);
+        Ok(())
+    }
+
+    /// What is selected, by identity rather than by index
+    fn selection_keys(&self) -> SelectionKeys {
+        let report_slug = match self.view_mode {
+            ViewMode::Dashboard => self.reports.get(self.selected_index),
+            _ => self.selected_report_index.and_then(|i| self.reports.get(i)),
+        }
+        .map(|r| r.slug.clone());
+        let meeting_path = (self.view_mode == ViewMode::ReportDetail)
+            .then(|| self.meeting_display_to_entry_index(self.selected_index))
+            .flatten()
+            .and_then(|i| self.selected_entries()?.get(i))
+            .map(|e| e.path.clone());
+        let editing = self.editing_entry();
+        let entry_path = self
+            .selected_entry_index
+            .and_then(|i| self.selected_entries()?.get(i))
+            .or(editing.as_ref())
+            .map(|e| e.path.clone());
+        SelectionKeys {
+            report_slug,
+            meeting_path,
+            entry_path,
+            editing,
+        }
+    }
+
+    /// The note open in the inline editor, even if it's gone from disk
+    fn editing_entry(&self) -> Option<JournalEntry> {
+        let editor = self.note_editor.as_ref()?;
+        editor.detached.clone().or_else(|| {
+            self.selected_entry_index
+                .and_then(|i| self.selected_entries()?.get(i))
+                .cloned()
+        })
+    }
+
+    /// Point indices back at what [`Self::selection_keys`] recorded,
+    /// leaving views whose subject was deleted; the inline editor stays open
+    /// with its note detached, so the text isn't lost
+    fn restore_selection(&mut self, keys: SelectionKeys) {
+        let report_idx = keys
+            .report_slug
+            .as_ref()
+            .and_then(|slug| self.reports.iter().position(|r| &r.slug == slug));
+
+        if self.view_mode == ViewMode::Dashboard {
+            if let Some(idx) = report_idx {
+                self.selected_index = idx;
+            }
+            return;
+        }
+        if self.selected_report_index.is_none() {
+            return;
+        }
+        let Some(report_idx) = report_idx else {
+            self.selected_report_index = None;
+            self.selected_entry_index = None;
+            self.selected_index = 0;
+            if let Some(editor) = &mut self.note_editor {
+                editor.detached = keys.editing;
+                self.set_status("Report was removed outside the app; this note can't be saved");
+                return;
+            }
+            self.view_mode = ViewMode::Dashboard;
+            self.set_status("Report was removed outside the app");
+            return;
+        };
+        self.selected_report_index = Some(report_idx);
+
+        let entries = &self.entries_by_report[report_idx];
+        let find = |path: &PathBuf| entries.iter().position(|e| &e.path == path);
+        if let Some(path) = &keys.entry_path {
+            match find(path) {
+                Some(idx) => {
+                    self.selected_entry_index = Some(idx);
+                    if let Some(editor) = &mut self.note_editor {
+                        editor.detached = None;
+                    }
+                    if self.view_mode == ViewMode::NoteViewer {
+                        self.editor_content = entries[idx].content.clone();
+                        self.editor_mood = entries[idx].mood();
+                    }
+                }
+                None => {
+                    self.selected_entry_index = None;
+                    if let Some(editor) = &mut self.note_editor {
+                        editor.detached = keys.editing;
+                        self.set_status("Note was deleted outside the app; Ctrl+S writes it back");
+                    }
+                    if matches!(
+                        self.view_mode,
+                        ViewMode::NoteViewer | ViewMode::DeleteConfirmModal
+                    ) {
+                        self.view_mode = ViewMode::ReportDetail;
+                        self.set_status("Note was deleted outside the app");
+                    }
+                }
+            }
+        }
+        if self.view_mode == ViewMode::ReportDetail {
+            let meetings: Vec<&PathBuf> = self
+                .selected_meetings()
+                .into_iter()
+                .rev()
+                .map(|e| &e.path)
+                .collect();
+            self.selected_index = keys
+                .meeting_path
+                .as_ref()
+                .and_then(|path| meetings.iter().position(|p| *p == path))
+                .unwrap_or(self.selected_index)
+                .min(meetings.len().saturating_sub(1));
+        }
+    }
+
+    /// Re-run the workspace checks behind the warnings modal
+    ///
+    /// A check that fails outright (unreadable config) leaves the list
+    /// empty; loading has already reported that.
+    pub(crate) fn check_workspace(&mut self) {
+        self.warnings = doctor::diagnose(&self.repo).unwrap_or_default();
+    }
+
+    /// Re-run the checks for just what `paths` touch: the workspace config
+    /// and the directories at the workspace root they are in
+    pub(crate) fn recheck_workspace(&mut self, paths: &[PathBuf]) {
+        let root = self.repo.path().to_path_buf();
+        let config = root.join(".vibe-manager");
+        let scopes: BTreeSet<PathBuf> = paths
+            .iter()
+            .filter_map(|path| warning_scope(&root, path))
+            .collect();
+        // The full check's order: config first, then directories by name
+        let order = |scope: &PathBuf| (scope != &config, scope.clone());
+
+        for scope in scopes {
+            let found = if scope == config {
+                doctor::diagnose_config(&self.repo)
+            } else {
+                doctor::diagnose_report(&self.repo, &scope)
+            };
+            // An unreadable config is already reported by the reload
+            let Ok(found) = found else {
+                continue;
+            };
+            self.warnings
+                .retain(|issue| warning_scope(&root, &issue.path).as_ref() != Some(&scope));
+            let at = self
+                .warnings
+                .iter()
+                .position(|issue| {
+                    warning_scope(&root, &issue.path).is_some_and(|s| order(&s) > order(&scope))
+                })
+                .unwrap_or(self.warnings.len());
+            self.warnings.splice(at..at, found);
+        }
+    }
+
     /// Set a status message with automatic expiry timestamp
     pub fn set_status(&mut self, message: impl Into<String>) {
         self.status_message = Some((message.into(), Instant::now()));
@@ -189,7 +567,8 @@ impl App {
     /// through this helper for entry mutations.
     pub(crate) fn report_repo(&self, report_idx: usize) -> ReportRepository {
         let report = &self.reports[report_idx];
-        ReportRepository::new(report.path.clone(), report.manager_slug.clone())
+        self.repo
+            .report_at(report.path.clone(), report.manager_slug.clone())
     }
 
     /// Enter the selected manager's hall (no-op on ICs and empty squads)
@@ -246,6 +625,447 @@ impl App {
         Ok(())
     }
 
+    /// Everyone in the workspace tree, by name
+    ///
+    /// Reports whose profiles can't be read are left out, as on the dashboard.
+    pub(crate) fn org_directory(&self) -> Vec<OrgMember> {
+        let mut members = Vec::new();
+        if let Ok(roots) = self.repo.list_reports() {
+            collect_members(roots, &mut Vec::new(), &mut members);
+        }
+        members.sort_by(|a, b| a.name.cmp(&b.name));
+        members
+    }
+
+    /// Open someone's details from anywhere
+    ///
+    /// Enters each hall above them in turn, so the hall stack (and Esc) is
+    /// the same as if the user had walked down by hand.
+    pub(crate) fn jump_to(&mut self, member: &OrgMember) -> Result<()> {
+        let Some(problem) = self.walk_to(member)? else {
+            return Ok(());
+        };
+        if self.roster_view.filter != RosterFilter::All {
+            // The filter hides them, or a hall on the way: show everyone
+            self.roster_view.filter = RosterFilter::All;
+            self.save_roster_view();
+            if self.walk_to(member)?.is_none() {
+                self.set_status(format!("Filter cleared to show {}", member.name));
+                return Ok(());
+            }
+        }
+        self.set_status(problem);
+        Ok(())
+    }
+
+    /// [`Self::jump_to`], or why someone on the way isn't on their roster
+    fn walk_to(&mut self, member: &OrgMember) -> Result<Option<String>> {
+        self.leave_halls()?;
+
+        for hall in &member.halls {
+            let Some(idx) = self.reports.iter().position(|r| r.slug == hall.slug) else {
+                return Ok(Some(format!("{}'s hall is gone", hall.name)));
+            };
+            self.selected_index = idx;
+            let depth = self.hall_stack.len();
+            self.enter_hall()?;
+            if self.hall_stack.len() == depth {
+                return Ok(None); // enter_hall said why
+            }
+        }
+
+        match self.reports.iter().position(|r| r.slug == member.slug) {
+            Some(idx) => {
+                self.selected_index = idx;
+                self.update(Msg::ViewReport)?;
+                Ok(None)
+            }
+            None => Ok(Some(format!(
+                "{} is no longer in the workspace",
+                member.name
+            ))),
+        }
+    }
+
+    /// Show the dashboard through another filter or sort, and remember it
+    ///
+    /// The selected card stays selected if the new view still shows it.
+    pub(crate) fn set_roster_view(&mut self, view: RosterView) -> Result<()> {
+        let selected = self
+            .reports
+            .get(self.selected_index)
+            .map(|r| r.slug.clone());
+        self.roster_view = view;
+        self.save_roster_view();
+        self.load_data()?;
+        self.selected_index = selected
+            .and_then(|slug| self.reports.iter().position(|r| r.slug == slug))
+            .unwrap_or(0);
+        Ok(())
+    }
+
+    /// Show or hide the selected manager's squad line in the table layout
+    pub(crate) fn toggle_squad(&mut self) {
+        if self.roster_view.layout != RosterLayout::Table {
+            self.set_status("Squad lines are shown in the table layout");
+            return;
+        }
+        let Some(report) = self.reports.get(self.selected_index) else {
+            return;
+        };
+        if !report.is_manager() {
+            self.set_status(format!("{} has no squad", report.profile.name));
+            return;
+        }
+        if !self.expanded_squads.remove(&report.slug) {
+            self.expanded_squads.insert(report.slug.clone());
+        }
+    }
+
+    /// Edit the selected note in the workspace's editor
+    ///
+    /// The built-in editor opens right here; `$EDITOR` is the runtime's job.
+    pub(crate) fn edit_note(&mut self, is_new: bool) -> Effect {
+        if self.workspace.config.settings.editor == EditorKind::External {
+            return Effect::SpawnEditor { is_new };
+        }
+        self.note_editor = Some(NoteEditorState::new(&self.editor_content, is_new));
+        self.view_mode = ViewMode::NoteEditor;
+        Effect::None
+    }
+
+    /// Save the inline editor's text and view the note
+    ///
+    /// Like with `$EDITOR`, a note left with nothing but its template is
+    /// deleted. If saving fails the editor stays open with the text.
+    pub(crate) fn save_note_editor(&mut self) {
+        let Some(editor) = self.note_editor.take() else {
+            return;
+        };
+        if let Some(entry) = editor.detached.clone() {
+            self.write_back_note(editor, entry);
+            return;
+        }
+        let (Some(report_idx), Some(entry_idx)) =
+            (self.selected_report_index, self.selected_entry_index)
+        else {
+            self.view_mode = ViewMode::ReportDetail;
+            return;
+        };
+
+        let text = editor.text();
+        if is_content_empty(&text) {
+            self.discard_empty_note(report_idx, entry_idx, editor.is_new);
+            return;
+        }
+        if !editor.is_modified() {
+            self.set_status("No changes");
+            self.view_note();
+            return;
+        }
+
+        let previous = std::mem::replace(
+            &mut self.entries_by_report[report_idx][entry_idx].content,
+            text.clone(),
+        );
+        match self.save_entry(report_idx, entry_idx) {
+            Ok(()) => {
+                self.editor_content = text;
+                self.set_status("Note updated");
+                self.view_note();
+            }
+            Err(e) => {
+                self.entries_by_report[report_idx][entry_idx].content = previous;
+                self.set_status(format!("Error saving note: {}", e));
+                self.note_editor = Some(editor);
+            }
+        }
+    }
+
+    /// Save a note that was deleted outside the app while it was open,
+    /// at the path it had
+    fn write_back_note(&mut self, editor: NoteEditorState, mut entry: JournalEntry) {
+        let Some(report_idx) = self.selected_report_index else {
+            self.set_status("Report was removed outside the app; this note can't be saved");
+            self.note_editor = Some(editor);
+            return;
+        };
+        let text = editor.text();
+        if is_content_empty(&text) {
+            self.view_mode = ViewMode::ReportDetail;
+            self.set_status("Entry deleted");
+            return;
+        }
+
+        entry.content = text.clone();
+        if let Err(e) = self.report_repo(report_idx).entries().save(&entry) {
+            self.set_status(format!("Error saving note: {}", e));
+            self.note_editor = Some(editor);
+            return;
+        }
+        let entries = &mut self.entries_by_report[report_idx];
+        let idx = entries.partition_point(|e| e.timestamp <= entry.timestamp);
+        self.editor_mood = entry.mood();
+        entries.insert(idx, entry);
+        self.selected_entry_index = Some(idx);
+        self.recompute_summary(report_idx);
+        self.editor_content = text;
+        self.set_status("Note written back");
+        self.view_note();
+    }
+
+    /// Leave the inline editor without saving
+    ///
+    /// Unsaved changes take a second Esc; a new note closed this way is
+    /// removed again.
+    pub(crate) fn close_note_editor(&mut self) {
+        let Some(editor) = &mut self.note_editor else {
+            return;
+        };
+        if editor.is_modified() && !editor.confirm_discard {
+            editor.confirm_discard = true;
+            self.set_status("Unsaved changes: Ctrl+S to save, Esc again to discard");
+            return;
+        }
+
+        let is_new = editor.is_new;
+        let modified = editor.is_modified();
+        let detached = editor.detached.is_some();
+        self.note_editor = None;
+        if detached {
+            // Nothing on disk to keep or clean up
+            self.view_mode = match self.selected_report_index {
+                Some(_) => ViewMode::ReportDetail,
+                None => ViewMode::Dashboard,
+            };
+            self.set_status("Note discarded");
+            return;
+        }
+        match (self.selected_report_index, self.selected_entry_index) {
+            (Some(report_idx), Some(entry_idx)) if is_new => {
+                self.discard_empty_note(report_idx, entry_idx, true)
+            }
+            _ => {
+                if modified {
+                    self.set_status("Changes discarded");
+                }
+                self.view_note();
+            }
+        }
+    }
+
+    /// Remove a note edited down to its template (or never written)
+    fn discard_empty_note(&mut self, report_idx: usize, entry_idx: usize, is_new: bool) {
+        match self.delete_entry(report_idx, entry_idx) {
+            Ok(()) => {
+                self.view_mode = ViewMode::ReportDetail;
+                self.set_status(if is_new {
+                    "Entry creation canceled"
+                } else {
+                    "Entry deleted"
+                });
+            }
+            Err(e) => {
+                self.view_note();
+                self.set_status(format!("Error deleting file: {}", e));
+            }
+        }
+    }
+
+    /// Show the open note from the top
+    pub(crate) fn view_note(&mut self) {
+        self.note_scroll = 0;
+        self.note_search = None;
+        self.view_mode = ViewMode::NoteViewer;
+    }
+
+    /// The open note as the viewer draws it, and the area it's drawn in
+    fn rendered_note(&self) -> (Vec<Line<'static>>, Rect) {
+        let page = note_page(Rect::from((Position::ORIGIN, self.screen)));
+        (render_markdown(&self.editor_content, page.width), page)
+    }
+
+    /// Scroll the note, stopping with its last line at the top
+    pub(crate) fn scroll_note(&mut self, scroll: NoteScroll) {
+        let (lines, page) = self.rendered_note();
+        let last = lines.len().saturating_sub(1) as i64;
+        let height = i64::from(page.height.max(1));
+        let top = i64::from(self.note_scroll);
+        let top = match scroll {
+            NoteScroll::Lines(lines) => top + i64::from(lines),
+            NoteScroll::Pages(pages) => top + i64::from(pages) * height,
+            NoteScroll::Top => 0,
+            NoteScroll::Bottom => last + 1 - height,
+        };
+        self.note_scroll = u16::try_from(top.clamp(0, last)).unwrap_or(u16::MAX);
+    }
+
+    /// The note search, while its query is being typed
+    pub(crate) fn typed_note_search(&mut self) -> Option<&mut NoteSearch> {
+        if self.view_mode != ViewMode::NoteViewer {
+            return None;
+        }
+        self.note_search.as_mut().filter(|search| search.typing)
+    }
+
+    /// Stop typing the search and jump to its first match on screen or below
+    pub(crate) fn submit_note_search(&mut self) {
+        let Some(search) = &mut self.note_search else {
+            return;
+        };
+        search.typing = false;
+        if search.query.is_empty() {
+            self.note_search = None;
+            return;
+        }
+        let query = search.query.clone();
+        let (lines, _) = self.rendered_note();
+        if find_matches(&lines, &query).is_empty() {
+            self.note_search = None;
+            self.set_status(format!("Not found: {}", query));
+            return;
+        }
+        self.jump_to_match(true);
+    }
+
+    /// Move to the search's next (or previous) match, scrolling it into view
+    pub(crate) fn jump_to_match(&mut self, forward: bool) {
+        let Some(search) = self.note_search.as_ref().filter(|search| !search.typing) else {
+            return;
+        };
+        let (lines, page) = self.rendered_note();
+        let matches = find_matches(&lines, &search.query);
+        let top = usize::from(self.note_scroll);
+        let (found, wrapped) = match (search.current, forward) {
+            // A new search starts from the top of the screen
+            (None, _) => match matches.iter().find(|line| **line >= top) {
+                Some(line) => (Some(line), false),
+                None => (matches.first(), true),
+            },
+            (Some(current), true) => match matches.iter().find(|line| **line > current) {
+                Some(line) => (Some(line), false),
+                None => (matches.first(), true),
+            },
+            (Some(current), false) => match matches.iter().rev().find(|line| **line < current) {
+                Some(line) => (Some(line), false),
+                None => (matches.last(), true),
+            },
+        };
+        let Some(&line) = found else {
+            return;
+        };
+
+        if let Some(search) = &mut self.note_search {
+            search.current = Some(line);
+        }
+        let height = usize::from(page.height.max(1));
+        if line < top || line >= top + height {
+            // A couple of lines of context above the match
+            self.note_scroll = u16::try_from(line.saturating_sub(2)).unwrap_or(u16::MAX);
+        }
+        if wrapped && matches.len() > 1 {
+            self.set_status(if forward {
+                "Search wrapped to the top"
+            } else {
+                "Search wrapped to the bottom"
+            });
+        }
+    }
+
+    /// Write the roster view to `.vibe-view`; failing to is only worth a
+    /// status message
+    fn save_roster_view(&mut self) {
+        if let Err(e) = self.repo.save_roster_view(&self.roster_view) {
+            self.set_status(format!("Couldn't save the dashboard view: {}", e));
+        }
+    }
+
+    /// Open the git history of the current note, or of the report's profile
+    ///
+    /// Shows a status message instead when the workspace isn't in a git
+    /// repository or the file has never been committed.
+    pub(crate) fn open_history(&mut self) {
+        let Some(report_idx) = self.selected_report_index else {
+            return;
+        };
+        let report = &self.reports[report_idx];
+        let (path, title) = match (self.view_mode, self.selected_entry_index) {
+            (ViewMode::NoteViewer, Some(entry_idx)) => {
+                let entry = &self.entries_by_report[report_idx][entry_idx];
+                let file = entry
+                    .path
+                    .file_name()
+                    .map(|f| f.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                (
+                    entry.path.clone(),
+                    format!("{} · {}", report.profile.name, file),
+                )
+            }
+            (ViewMode::ReportDetail, _) => (
+                report.path.join("_profile.md"),
+                format!("{} · profile", report.profile.name),
+            ),
+            _ => return,
+        };
+
+        let Some(git) = GitRepo::discover(self.repo.path()) else {
+            self.set_status("History needs the workspace to be in a git repository");
+            return;
+        };
+        let revisions = match git.file_history(&path) {
+            Ok(revisions) if !revisions.is_empty() => revisions,
+            Ok(_) => {
+                self.set_status("No history yet: this file has never been committed");
+                return;
+            }
+            Err(e) => {
+                self.set_status(format!("Error: {}", e));
+                return;
+            }
+        };
+
+        self.history = Some(HistoryState {
+            title,
+            revisions,
+            content: String::new(),
+            shown: None,
+            git,
+            return_to: (self.view_mode, self.selected_index),
+        });
+        self.selected_index = 0;
+        self.view_mode = ViewMode::History;
+        self.refresh_history_preview();
+    }
+
+    /// Load the selected revision's contents if it isn't already shown
+    pub(crate) fn refresh_history_preview(&mut self) {
+        let selected = self.selected_index;
+        let Some(history) = self.history.as_mut() else {
+            return;
+        };
+        if history.shown == Some(selected) {
+            return;
+        }
+        let Some(revision) = history.revisions.get(selected) else {
+            return;
+        };
+        let path = history.git.root().join(&revision.path);
+        history.content = history
+            .git
+            .show(revision)
+            .and_then(|data| self.repo.decode_text(data, &path))
+            .unwrap_or_else(|e| format!("(unavailable: {})", e));
+        history.shown = Some(selected);
+    }
+
+    /// Write an entry back to disk after it was edited in memory
+    pub fn save_entry(&self, report_idx: usize, entry_idx: usize) -> Result<()> {
+        let entry = &self.entries_by_report[report_idx][entry_idx];
+        self.report_repo(report_idx).entries().save(entry)?;
+        Ok(())
+    }
+
     /// Delete an entry by report and entry index
     ///
     /// Returns Ok(()) on success, sets status message on error
@@ -274,14 +1094,30 @@ impl App {
         let mut summary = compute_report_summary(
             &self.reports[report_idx],
             &self.entries_by_report[report_idx],
-            self.workspace.config.settings.overdue_threshold_days,
+            &self.workspace.config.settings,
         );
         if let Some(team_metrics) = prev_team_metrics {
-            summary.urgency_score += manager_urgency_bonus(&team_metrics);
-            summary.team_metrics = Some(team_metrics);
+            summary.attach_team_metrics(team_metrics);
         }
         self.summaries[report_idx] = summary;
-        self.workspace_summary = compute_workspace_summary(&self.summaries);
+        self.refresh_roster_totals();
+    }
+
+    /// Recount the workspace summary and filter choices over the whole
+    /// roster, not just what the filter lets through
+    fn refresh_roster_totals(&mut self) {
+        let all_summaries: Vec<_> = self
+            .summaries
+            .iter()
+            .chain(self.hidden_reports.iter().map(|(_, summary)| summary))
+            .cloned()
+            .collect();
+        self.workspace_summary = compute_workspace_summary(&all_summaries);
+        self.filter_choices = RosterFilter::choices(
+            self.reports
+                .iter()
+                .chain(self.hidden_reports.iter().map(|(report, _)| report)),
+        );
     }
 
     /// Get entries for currently selected report
@@ -326,17 +1162,55 @@ impl App {
         match self.view_mode {
             ViewMode::Dashboard => self.reports.len(),
             ViewMode::ReportDetail => self.selected_meeting_count(),
+            ViewMode::History => self.history.as_ref().map_or(0, |h| h.revisions.len()),
+            ViewMode::Warnings => self.warnings.len(),
             _ => 0,
         }
     }
 }
 
-/// Map a frequency setting string to days (skip-level cadence defaults to monthly)
-fn frequency_to_days(frequency: &str) -> u32 {
-    match frequency {
-        "weekly" => 7,
-        "biweekly" => 14,
-        "monthly" => 30,
-        _ => 30,
+/// What [`App::recheck_workspace`] re-checks for a change at `path`: the
+/// directory at the workspace root it is in, or `.vibe-manager` for the
+/// workspace's own files
+fn warning_scope(root: &Path, path: &Path) -> Option<PathBuf> {
+    let first = path.strip_prefix(root).ok()?.components().next()?;
+    if first.as_os_str().to_string_lossy().starts_with('.') {
+        Some(root.join(".vibe-manager"))
+    } else {
+        Some(root.join(first))
+    }
+}
+
+/// Add `repos` and everyone in their teams to `out`, depth first
+fn collect_members(
+    repos: Vec<ReportRepository>,
+    halls: &mut Vec<OrgHall>,
+    out: &mut Vec<OrgMember>,
+) {
+    for repo in repos {
+        let Ok(report) = repo.load() else {
+            continue;
+        };
+        let team_name = report
+            .profile
+            .manager_info
+            .as_ref()
+            .and_then(|info| info.team_name.clone());
+        out.push(OrgMember {
+            slug: report.slug.clone(),
+            name: report.profile.name.clone(),
+            title: report.profile.title.clone(),
+            team_name: team_name.clone(),
+            halls: halls.clone(),
+        });
+        if let Ok(team) = repo.list_team_members() {
+            halls.push(OrgHall {
+                slug: report.slug,
+                name: report.profile.name,
+                team_name,
+            });
+            collect_members(team, halls, out);
+            halls.pop();
+        }
     }
 }
@@ -5,8 +5,9 @@
 
 use anyhow::Result;
 
-use super::{App, Effect, Msg, ViewMode};
-use crate::model::ManagerInfo;
+use super::{App, Effect, Msg, NoteScroll, NoteSearch, ViewMode};
+use crate::model::{ManagerInfo, RosterFilter, RosterSort, RosterView};
+use crate::storage::SyncOutcome;
 
 impl App {
     /// Process a message and update state (TEA update function)
@@ -24,6 +25,68 @@ impl App {
                 Effect::None
             }
 
+            Msg::ShowWarnings => {
+                if self.warnings.is_empty() {
+                    self.set_status("No problems found in the workspace");
+                } else {
+                    self.warnings_return_index = self.selected_index;
+                    self.selected_index = 0;
+                    self.view_mode = ViewMode::Warnings;
+                }
+                Effect::None
+            }
+
+            Msg::CycleFilter => {
+                let filter = self.roster_view.filter.next_in(&self.filter_choices);
+                self.set_roster_view(RosterView {
+                    filter,
+                    ..self.roster_view.clone()
+                })?;
+                Effect::None
+            }
+
+            Msg::ClearFilter => {
+                self.set_roster_view(RosterView {
+                    filter: RosterFilter::All,
+                    ..self.roster_view.clone()
+                })?;
+                Effect::None
+            }
+
+            Msg::CycleSort => {
+                self.set_roster_view(RosterView {
+                    sort: self.roster_view.sort.next(),
+                    ..self.roster_view.clone()
+                })?;
+                Effect::None
+            }
+
+            Msg::SortBy(sort) => {
+                let sort = if sort == self.roster_view.sort {
+                    RosterSort::Urgency
+                } else {
+                    sort
+                };
+                self.set_roster_view(RosterView {
+                    sort,
+                    ..self.roster_view.clone()
+                })?;
+                Effect::None
+            }
+
+            Msg::ToggleLayout => {
+                self.set_roster_view(RosterView {
+                    layout: self.roster_view.layout.toggle(),
+                    ..self.roster_view.clone()
+                })?;
+                Effect::None
+            }
+
+            Msg::ToggleSquad => {
+                self.toggle_squad();
+                Effect::None
+            }
+
             Msg::ShowHelp => {
                 self.view_mode = ViewMode::Help;
                 Effect::None
@@ -34,6 +97,30 @@ impl App {
                 Effect::None
             }
 
+            Msg::ShowCommandPalette => {
+                self.open_command_palette();
+                Effect::None
+            }
+
+            Msg::ShowPersonFinder => {
+                self.open_person_finder();
+                Effect::None
+            }
+
+            Msg::SelectNext if self.view_mode == ViewMode::CommandPalette => {
+                if let Some(palette) = &mut self.palette {
+                    palette.move_selection(true);
+                }
+                Effect::None
+            }
+
+            Msg::SelectPrev if self.view_mode == ViewMode::CommandPalette => {
+                if let Some(palette) = &mut self.palette {
+                    palette.move_selection(false);
+                }
+                Effect::None
+            }
+
             Msg::SelectNext => {
                 let max_len = self.current_list_len();
                 if max_len > 0 {
@@ -99,6 +186,13 @@ impl App {
                 Effect::None
             }
 
+            Msg::Select(index) => {
+                if index < self.current_list_len() {
+                    self.selected_index = index;
+                }
+                Effect::None
+            }
+
             Msg::ViewReport => {
                 if !self.reports.is_empty() {
                     self.selected_report_index = Some(self.selected_index);
@@ -115,7 +209,7 @@ impl App {
                         let entry = &self.entries_by_report[report_idx][actual_index];
                         self.editor_content = entry.content.clone();
                         self.editor_mood = entry.mood();
-                        self.view_mode = ViewMode::NoteViewer;
+                        self.view_note();
                     }
                 }
                 Effect::None
@@ -136,8 +230,8 @@ impl App {
                             self.entries_by_report[report_idx].push(meeting);
                             self.selected_entry_index =
                                 Some(self.entries_by_report[report_idx].len() - 1);
-                            self.view_mode = ViewMode::NoteViewer;
-                            return Ok(Effect::SpawnEditor { is_new: true });
+                            self.view_note();
+                            return Ok(self.edit_note(true));
                         }
                         Err(e) => {
                             self.set_status(format!("Error: {}", e));
@@ -147,7 +241,7 @@ impl App {
                 Effect::None
             }
 
-            Msg::EditMeeting => Effect::SpawnEditor { is_new: false },
+            Msg::EditMeeting => self.edit_note(false),
 
             Msg::EditMeetingFromList(display_index) => {
                 if let Some(actual_index) = self.meeting_display_to_entry_index(display_index) {
@@ -157,7 +251,7 @@ impl App {
                         self.editor_content = entry.content.clone();
                         self.editor_mood = entry.mood();
                         // Don't change view mode - go straight to editor
-                        return Ok(Effect::SpawnEditor { is_new: false });
+                        return Ok(self.edit_note(false));
                     }
                 }
                 Effect::None
@@ -181,11 +275,67 @@ impl App {
                 Effect::None
             }
 
+            Msg::ScrollNote(scroll) => {
+                if self.view_mode == ViewMode::NoteViewer {
+                    self.scroll_note(scroll);
+                }
+                Effect::None
+            }
+
+            Msg::StartNoteSearch => {
+                if self.view_mode == ViewMode::NoteViewer {
+                    self.note_search = Some(NoteSearch {
+                        typing: true,
+                        ..NoteSearch::default()
+                    });
+                }
+                Effect::None
+            }
+
+            Msg::NextMatch => {
+                self.jump_to_match(true);
+                Effect::None
+            }
+
+            Msg::PrevMatch => {
+                self.jump_to_match(false);
+                Effect::None
+            }
+
+            Msg::EditorInput(command) => {
+                if let Some(editor) = &mut self.note_editor {
+                    editor.apply(command);
+                }
+                Effect::None
+            }
+
+            Msg::Paste(text) => {
+                if let Some(editor) = &mut self.note_editor {
+                    editor.paste(&text);
+                }
+                Effect::None
+            }
+
+            Msg::SaveNote => {
+                self.save_note_editor();
+                Effect::None
+            }
+
+            Msg::CloseEditor => {
+                self.close_note_editor();
+                Effect::None
+            }
+
             Msg::ShowDeleteConfirm => {
                 self.handle_show_delete_confirm();
                 Effect::None
             }
 
+            Msg::ConfirmMigrate => {
+                self.migrate_workspace()?;
+                Effect::None
+            }
+
             Msg::ConfirmDelete => {
                 if let (Some(report_idx), Some(entry_idx)) =
                     (self.selected_report_index, self.selected_entry_index)
@@ -252,6 +402,41 @@ impl App {
                 Effect::None
             }
 
+            Msg::ModalSelect(field, option) => {
+                if self.view_mode == ViewMode::NewReportModal {
+                    self.new_report_state.select(field, option);
+                }
+                Effect::None
+            }
+
+            Msg::ShowMoodHeatmap => {
+                if self.view_mode == ViewMode::Dashboard && !self.reports.is_empty() {
+                    self.view_mode = ViewMode::MoodHeatmap;
+                }
+                Effect::None
+            }
+
+            Msg::ShowMoodAnalytics => {
+                if self.view_mode == ViewMode::ReportDetail {
+                    self.view_mode = ViewMode::MoodAnalytics;
+                }
+                Effect::None
+            }
+
+            Msg::ShowMeetingPrep => {
+                if self.view_mode == ViewMode::ReportDetail {
+                    self.view_mode = ViewMode::MeetingPrep;
+                }
+                Effect::None
+            }
+
+            Msg::CycleMoodWindow => {
+                if self.view_mode == ViewMode::MoodAnalytics {
+                    self.mood_window = self.mood_window.next();
+                }
+                Effect::None
+            }
+
             // Entry input modal actions (mood observation)
             Msg::ShowEntryInput => {
                 if self.selected_report_index.is_some() {
@@ -284,27 +469,108 @@ impl App {
 
             Msg::RefreshData => {
                 self.load_data()?;
+                self.check_workspace();
+                Effect::None
+            }
+
+            Msg::ShowHistory => {
+                self.open_history();
+                Effect::None
+            }
+
+            Msg::SyncWorkspace => {
+                if self.syncing {
+                    Effect::None
+                } else {
+                    self.syncing = true;
+                    self.set_status("Syncing workspace…");
+                    Effect::SyncWorkspace
+                }
+            }
+
+            Msg::WorkspaceSynced(result) => {
+                self.syncing = false;
+                match result {
+                    Ok(SyncOutcome::Synced { committed }) => {
+                        self.sync_conflicts.clear();
+                        self.reload_keeping_selection()?;
+                        self.set_status(if committed {
+                            "Synced: committed local changes, pulled and pushed"
+                        } else {
+                            "Synced: pulled and pushed"
+                        });
+                    }
+                    Ok(SyncOutcome::Conflicts(files)) => {
+                        self.set_status(format!(
+                            "Sync stopped: {} conflicting file(s), nothing was overwritten",
+                            files.len()
+                        ));
+                        self.sync_conflicts = files;
+                    }
+                    Err(e) => self.set_status(format!("Sync failed: {}", e)),
+                }
+                Effect::None
+            }
+
+            Msg::CalendarSynced(outcome) => {
+                self.reload_keeping_selection()?;
+                self.set_status(outcome);
+                Effect::None
+            }
+
+            Msg::Resize(size) => {
+                self.screen = size;
+                // Keep the note's scroll within its newly wrapped lines
+                if self.view_mode == ViewMode::NoteViewer {
+                    self.scroll_note(NoteScroll::Lines(0));
+                }
+                Effect::None
+            }
+
+            Msg::FilesChanged(paths) => {
+                // Whatever another program left on disk, the TUI stays up
+                if let Err(e) = self.reload_changed(&paths) {
+                    self.set_status(format!("Couldn't reload changes: {}", e));
+                }
+                self.recheck_workspace(&paths);
                 Effect::None
             }
 
             Msg::Input(c) => {
-                if self.view_mode == ViewMode::NewReportModal {
+                if let Some(search) = self.typed_note_search() {
+                    search.query.push(c);
+                } else if self.view_mode == ViewMode::NewReportModal {
                     self.new_report_state.handle_char(c);
                 } else if self.view_mode == ViewMode::EntryInputModal {
                     self.pending_entry_notes.push(c);
+                } else if let Some(palette) = &mut self.palette {
+                    palette.push(c);
                 }
                 Effect::None
             }
 
             Msg::Backspace => {
-                if self.view_mode == ViewMode::NewReportModal {
+                if let Some(search) = self.typed_note_search() {
+                    search.query.pop();
+                } else if self.view_mode == ViewMode::NewReportModal {
                     self.new_report_state.handle_backspace();
                 } else if self.view_mode == ViewMode::EntryInputModal {
                     self.pending_entry_notes.pop();
+                } else if let Some(palette) = &mut self.palette {
+                    palette.pop();
                 }
                 Effect::None
             }
 
+            Msg::Enter if self.typed_note_search().is_some() => {
+                self.submit_note_search();
+                Effect::None
+            }
+
+            Msg::Enter if self.view_mode == ViewMode::CommandPalette => {
+                return self.run_palette_selection();
+            }
+
             Msg::Enter => {
                 if self.view_mode == ViewMode::NewReportModal {
                     if self.new_report_state.is_valid() {
@@ -317,6 +583,13 @@ impl App {
             }
         };
 
+        if self.view_mode == ViewMode::History {
+            self.refresh_history_preview();
+        }
+        if let Some(e) = self.repo.take_auto_commit_failure() {
+            self.set_status(format!("Saved, but the git auto-commit failed: {}", e));
+        }
+
         Ok(effect)
     }
 
@@ -331,13 +604,30 @@ impl App {
                 self.view_mode = ViewMode::Dashboard;
                 self.selected_report_index = None;
             }
+            // Esc clears a search before it leaves the note
+            ViewMode::NoteViewer if self.note_search.take().is_some() => {}
             ViewMode::NoteViewer => {
                 self.view_mode = ViewMode::ReportDetail;
                 self.selected_entry_index = None;
             }
+            ViewMode::MoodAnalytics | ViewMode::MeetingPrep => {
+                self.view_mode = ViewMode::ReportDetail;
+            }
+            ViewMode::MoodHeatmap => {
+                self.view_mode = ViewMode::Dashboard;
+            }
+            ViewMode::History => {
+                if let Some(history) = self.history.take() {
+                    (self.view_mode, self.selected_index) = history.return_to;
+                }
+            }
             ViewMode::Help | ViewMode::NewReportModal => {
                 self.view_mode = ViewMode::Dashboard;
             }
+            ViewMode::Warnings => {
+                self.view_mode = ViewMode::Dashboard;
+                self.selected_index = self.warnings_return_index;
+            }
             ViewMode::EntryInputModal => {
                 self.view_mode = ViewMode::ReportDetail;
                 self.pending_entry_mood = None;
@@ -351,7 +641,9 @@ impl App {
                     self.view_mode = ViewMode::NoteViewer;
                 }
             }
-            ViewMode::Dashboard => {}
+            ViewMode::MigrateConfirmModal => self.cancel_migration(),
+            ViewMode::NoteEditor => self.close_note_editor(),
+            ViewMode::Dashboard | ViewMode::CommandPalette => {}
         }
     }
 
@@ -391,6 +683,13 @@ impl App {
                     self.view_mode = ViewMode::NoteViewer;
                 }
             }
+            ViewMode::CommandPalette => {
+                if let Some(palette) = self.palette.take() {
+                    self.view_mode = palette.return_to;
+                }
+            }
+            ViewMode::MigrateConfirmModal => self.cancel_migration(),
+            ViewMode::NoteViewer => self.note_search = None,
             _ => {}
         }
     }
@@ -421,6 +720,9 @@ impl App {
             level: Some(level),
             meeting_frequency,
             active: true,
+            tags: vec![],
+            email: None,
+            next_meeting: None,
             report_type,
             manager_info,
             birthday: None,
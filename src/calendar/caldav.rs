@@ -0,0 +1,455 @@
+//! CalDAV calendar sync (`caldav` build feature)
+//!
+//! Reads events from a CalDAV calendar collection with a single
+//! `calendar-query` REPORT and feeds them through the same matching as the
+//! `.ics` import: past 1-on-1s are recorded as meetings and each report's
+//! `next_meeting` follows the calendar. Every call blocks on the network, so
+//! the TUI runs [`sync_workspace`] on a background thread.
+
+use std::time::Duration;
+
+use base64::Engine;
+use chrono::{Days, Local, NaiveDateTime, TimeZone, Utc};
+
+use super::ics::{parse_ics, ParsedCalendar};
+use super::import::{apply_import, plan_import};
+use super::{CalendarError, CalendarResult};
+use crate::model::{CalDavSettings, WorkspaceSettings};
+use crate::storage::WorkspaceRepository;
+
+/// Per-request timeout, so a dead server can't hang a sync forever
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Outcome of a sync, for the status line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncSummary {
+    /// Meetings recorded from past events
+    pub recorded: usize,
+    /// Reports whose next planned 1-on-1 changed
+    pub rescheduled: usize,
+    /// Events that matched no report or several
+    pub unmatched: usize,
+}
+
+impl SyncSummary {
+    pub fn message(&self) -> String {
+        format!(
+            "Calendar synced: {} meeting(s) recorded, {} plan(s) updated, {} event(s) unmatched",
+            self.recorded, self.rescheduled, self.unmatched
+        )
+    }
+}
+
+/// Client for one CalDAV calendar collection
+pub struct CalDavClient {
+    agent: ureq::Agent,
+    url: String,
+    authorization: Option<String>,
+}
+
+impl CalDavClient {
+    /// Build a client, reading the password from `password_env` if set
+    pub fn new(settings: &CalDavSettings) -> CalendarResult<Self> {
+        let authorization = match &settings.username {
+            Some(username) => {
+                let password = match &settings.password_env {
+                    Some(var) => std::env::var(var).map_err(|_| {
+                        CalendarError::Remote(format!("environment variable {} is not set", var))
+                    })?,
+                    None => String::new(),
+                };
+                let credentials = base64::engine::general_purpose::STANDARD
+                    .encode(format!("{}:{}", username, password));
+                Some(format!("Basic {}", credentials))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            agent: ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build(),
+            url: settings.url.clone(),
+            authorization,
+        })
+    }
+
+    /// Fetch every event overlapping `[start, end)` (local times)
+    ///
+    /// Each returned calendar object is parsed on its own; objects that
+    /// aren't valid iCalendar are reported in `skipped`.
+    pub fn fetch_events(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> CalendarResult<ParsedCalendar> {
+        let body = calendar_query(start, end);
+        let mut request = self
+            .agent
+            .request("REPORT", &self.url)
+            .set("Depth", "1")
+            .set("Content-Type", "application/xml; charset=utf-8");
+        if let Some(authorization) = &self.authorization {
+            request = request.set("Authorization", authorization);
+        }
+
+        let response = request.send_string(&body).map_err(|e| match e {
+            ureq::Error::Status(code, response) => CalendarError::Remote(format!(
+                "{} {} from {}",
+                code,
+                response.status_text(),
+                self.url
+            )),
+            ureq::Error::Transport(transport) => CalendarError::Remote(transport.to_string()),
+        })?;
+        let xml = response.into_string()?;
+
+        let mut merged = ParsedCalendar::default();
+        for (idx, data) in calendar_data(&xml).into_iter().enumerate() {
+            match parse_ics(&data) {
+                Ok(parsed) => {
+                    merged.events.extend(parsed.events);
+                    merged.skipped.extend(parsed.skipped);
+                }
+                Err(e) => merged.skipped.push(format!("#{}: {}", idx + 1, e)),
+            }
+        }
+        Ok(merged)
+    }
+}
+
+/// Pull the configured CalDAV calendar into the workspace
+///
+/// Reads `window_days` either side of `now`, records past 1-on-1s and
+/// updates planned dates. Errors if no `calendar.caldav` is configured.
+pub fn sync_workspace(
+    repo: &WorkspaceRepository,
+    settings: &WorkspaceSettings,
+    now: NaiveDateTime,
+) -> CalendarResult<SyncSummary> {
+    let Some(caldav) = &settings.calendar.caldav else {
+        return Err(CalendarError::Remote(
+            "no calendar.caldav configured in .vibe-manager".to_string(),
+        ));
+    };
+
+    let window = Days::new(caldav.window_days as u64);
+    let (Some(start), Some(end)) = (now.checked_sub_days(window), now.checked_add_days(window))
+    else {
+        return Err(CalendarError::Remote(format!(
+            "calendar.caldav.window_days ({}) reaches past the calendar's range",
+            caldav.window_days
+        )));
+    };
+    let calendar = CalDavClient::new(caldav)?.fetch_events(start, end)?;
+    let plan = plan_import(repo, &calendar, &settings.calendar, now)?;
+    let recorded = apply_import(&plan)?;
+
+    Ok(SyncSummary {
+        recorded,
+        rescheduled: plan.planned.len(),
+        unmatched: plan.unmatched.len() + plan.ambiguous.len(),
+    })
+}
+
+/// `calendar-query` REPORT body for VEVENTs in a time range
+fn calendar_query(start: NaiveDateTime, end: NaiveDateTime) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop>
+    <C:calendar-data/>
+  </D:prop>
+  <C:filter>
+    <C:comp-filter name="VCALENDAR">
+      <C:comp-filter name="VEVENT">
+        <C:time-range start="{}" end="{}"/>
+      </C:comp-filter>
+    </C:comp-filter>
+  </C:filter>
+</C:calendar-query>
+"#,
+        utc_stamp(start),
+        utc_stamp(end)
+    )
+}
+
+/// Local wall-clock time as an iCalendar UTC timestamp
+fn utc_stamp(local: NaiveDateTime) -> String {
+    let utc = Local
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc).naive_utc())
+        .unwrap_or(local);
+    utc.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Text of every `calendar-data` element in a multistatus response
+///
+/// A deliberately small scanner rather than an XML parser: it only needs
+/// the element's text, under whatever namespace prefix the server picked.
+fn calendar_data(xml: &str) -> Vec<String> {
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(open) = rest.find('<') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..close];
+        rest = &rest[close + 1..];
+
+        let name = tag.split_whitespace().next().unwrap_or("");
+        let local = name.rsplit(':').next().unwrap_or(name);
+        if local != "calendar-data" || tag.starts_with('/') || tag.ends_with('/') {
+            continue;
+        }
+        let Some(end) = find_closing(rest, "calendar-data") else {
+            break;
+        };
+        let text = rest[..end].trim();
+        let text = text
+            .strip_prefix("<![CDATA[")
+            .and_then(|t| t.strip_suffix("]]>"))
+            .map(str::to_string)
+            .unwrap_or_else(|| unescape_xml(text));
+        found.push(text);
+        rest = &rest[end..];
+    }
+    found
+}
+
+/// Byte offset of the closing tag for `local_name`, any namespace prefix
+fn find_closing(xml: &str, local_name: &str) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(pos) = xml[offset..].find("</") {
+        let start = offset + pos;
+        let tag_end = xml[start..].find('>')? + start;
+        let name = &xml[start + 2..tag_end];
+        if name.trim().rsplit(':').next() == Some(local_name) {
+            return Some(start);
+        }
+        offset = tag_end;
+    }
+    None
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&#13;", "\r")
+        .replace("&#xD;", "\r")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ReportProfile;
+    use chrono::NaiveDate;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+    use tempfile::TempDir;
+
+    /// A request as seen by the stand-in server
+    struct Captured {
+        request_line: String,
+        headers: Vec<String>,
+        body: String,
+    }
+
+    /// Serve one canned response from a local stand-in CalDAV server
+    fn stand_in_server(status: &str, body: String) -> (String, mpsc::Receiver<Captured>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/calendars/me/work/",
+            listener.local_addr().unwrap()
+        );
+        let status = status.to_string();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut headers = Vec::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                headers.push(line);
+            }
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/xml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            tx.send(Captured {
+                request_line: request_line.trim_end().to_string(),
+                headers,
+                body: String::from_utf8(request_body).unwrap(),
+            })
+            .unwrap();
+        });
+        (url, rx)
+    }
+
+    fn multistatus(objects: &[&str]) -> String {
+        let responses: String = objects
+            .iter()
+            .enumerate()
+            .map(|(idx, ics)| {
+                format!(
+                    "<d:response><d:href>/calendars/me/work/{}.ics</d:href><d:propstat><d:prop>\
+                     <cal:calendar-data>{}</cal:calendar-data></d:prop>\
+                     <d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>",
+                    idx,
+                    ics.replace('&', "&amp;").replace('<', "&lt;")
+                )
+            })
+            .collect();
+        format!(
+            "<?xml version=\"1.0\"?><d:multistatus xmlns:d=\"DAV:\" \
+             xmlns:cal=\"urn:ietf:params:xml:ns:caldav\">{}</d:multistatus>",
+            responses
+        )
+    }
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    }
+
+    fn caldav_settings(url: String) -> CalDavSettings {
+        CalDavSettings {
+            url,
+            username: Some("me".to_string()),
+            password_env: Some("VIBE_TEST_CALDAV_PASSWORD".to_string()),
+            window_days: 30,
+            auto_sync: false,
+        }
+    }
+
+    #[test]
+    fn test_calendar_data_handles_prefixes_and_cdata() {
+        let xml = "<a:multistatus><a:response><C:calendar-data>BEGIN:VCALENDAR&#13;\nX &amp; Y\
+                   </C:calendar-data></a:response><calendar-data><![CDATA[BEGIN:VCALENDAR]]>\
+                   </calendar-data><C:calendar-data/></a:multistatus>";
+        assert_eq!(
+            calendar_data(xml),
+            vec!["BEGIN:VCALENDAR\r\nX & Y", "BEGIN:VCALENDAR"]
+        );
+    }
+
+    #[test]
+    fn test_fetch_events_from_stand_in_server() {
+        std::env::set_var("VIBE_TEST_CALDAV_PASSWORD", "secret");
+        let (url, requests) = stand_in_server(
+            "207 Multi-Status",
+            multistatus(&[
+                "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:a\r\nSUMMARY:Alex 1:1\r\n\
+                 DTSTART:20260105T100000\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n",
+                "not a calendar",
+            ]),
+        );
+
+        let client = CalDavClient::new(&caldav_settings(url)).unwrap();
+        let parsed = client
+            .fetch_events(at(2026, 1, 1, 0), at(2026, 2, 1, 0))
+            .unwrap();
+        assert_eq!(parsed.events.len(), 1);
+        assert_eq!(parsed.events[0].summary, "Alex 1:1");
+        assert_eq!(parsed.skipped.len(), 1);
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "REPORT /calendars/me/work/ HTTP/1.1");
+        assert!(request
+            .headers
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("depth: 1")));
+        // "me:secret"
+        assert!(request
+            .headers
+            .iter()
+            .any(|h| h == "Authorization: Basic bWU6c2VjcmV0"));
+        assert!(request.body.contains("<C:comp-filter name=\"VEVENT\">"));
+    }
+
+    #[test]
+    fn test_server_error_is_reported() {
+        let (url, _requests) = stand_in_server("401 Unauthorized", String::new());
+        let settings = CalDavSettings {
+            username: None,
+            password_env: None,
+            ..caldav_settings(url)
+        };
+        let err = CalDavClient::new(&settings)
+            .unwrap()
+            .fetch_events(at(2026, 1, 1, 0), at(2026, 2, 1, 0))
+            .unwrap_err();
+        assert!(err.to_string().contains("401"));
+    }
+
+    #[test]
+    fn test_sync_workspace_records_and_plans() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let profile: ReportProfile =
+            serde_yaml::from_str("name: Alex Chen\nemail: alex@corp.com").unwrap();
+        repo.create_report("Alex Chen", profile).unwrap();
+
+        let (url, _requests) = stand_in_server(
+            "207 Multi-Status",
+            multistatus(&[
+                "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:a\r\nSUMMARY:1:1\r\n\
+                 DTSTART:20260105T100000\r\nRRULE:FREQ=WEEKLY\r\n\
+                 ATTENDEE:mailto:alex@corp.com\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n",
+            ]),
+        );
+        let mut settings = WorkspaceSettings::default();
+        settings.calendar.caldav = Some(CalDavSettings {
+            username: None,
+            password_env: None,
+            ..caldav_settings(url)
+        });
+
+        let summary = sync_workspace(&repo, &settings, at(2026, 1, 13, 0)).unwrap();
+        assert_eq!(
+            summary,
+            SyncSummary {
+                recorded: 2,
+                rescheduled: 1,
+                unmatched: 0
+            }
+        );
+        let alex = repo.report("alex-chen").load().unwrap();
+        assert_eq!(alex.profile.next_meeting, Some(at(2026, 1, 19, 10)));
+    }
+
+    #[test]
+    fn test_sync_without_configuration_fails() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let err =
+            sync_workspace(&repo, &WorkspaceSettings::default(), at(2026, 1, 1, 0)).unwrap_err();
+        assert!(err.to_string().contains("calendar.caldav"));
+    }
+}
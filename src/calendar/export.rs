@@ -0,0 +1,360 @@
+//! Export the recommended 1-on-1 schedule as iCalendar
+//!
+//! Produces one all-day event per report for their next due 1-on-1 (last
+//! meeting + their cadence; skip-level reports use the workspace's
+//! `default_2nd_level_frequency`), plus yearly birthdays and work
+//! anniversaries. People below the 2nd level are left out: they meet their
+//! own managers, not you. UIDs are stable per report, so a calendar client
+//! subscribed to the file updates events in place when it is regenerated.
+
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime};
+
+use super::CalendarResult;
+use crate::model::WorkspaceSettings;
+use crate::storage::WorkspaceRepository;
+
+/// Product identifier written to exported calendars
+const PRODID: &str = "-//Vibe Manager//Schedule//EN";
+
+/// What a scheduled event is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleKind {
+    OneOnOne,
+    SkipLevel,
+    Birthday,
+    Anniversary,
+}
+
+impl ScheduleKind {
+    fn uid_prefix(&self) -> &'static str {
+        match self {
+            ScheduleKind::OneOnOne => "1on1",
+            ScheduleKind::SkipLevel => "skip-level",
+            ScheduleKind::Birthday => "birthday",
+            ScheduleKind::Anniversary => "anniversary",
+        }
+    }
+
+    /// Birthdays and anniversaries repeat every year
+    pub fn is_yearly(&self) -> bool {
+        matches!(self, ScheduleKind::Birthday | ScheduleKind::Anniversary)
+    }
+}
+
+/// One all-day event in the exported schedule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledEvent {
+    pub kind: ScheduleKind,
+    /// Slug of the report the event is about
+    pub slug: String,
+    pub summary: String,
+    pub description: Option<String>,
+    /// Day of the event (first occurrence for yearly events)
+    pub date: NaiveDate,
+}
+
+impl ScheduledEvent {
+    fn uid(&self) -> String {
+        format!("{}-{}@vibe-manager", self.kind.uid_prefix(), self.slug)
+    }
+}
+
+/// Build the schedule for every active direct and skip-level report
+///
+/// Meetings already past due are placed on `today` so they stay visible.
+pub fn build_schedule(
+    repo: &WorkspaceRepository,
+    settings: &WorkspaceSettings,
+    today: NaiveDate,
+) -> CalendarResult<Vec<ScheduledEvent>> {
+    let direct = repo.list_reports()?;
+    let mut skip_levels = Vec::new();
+    for report_repo in &direct {
+        skip_levels.extend(report_repo.list_team_members()?);
+    }
+    let skip_level_days = settings.second_level_frequency_days();
+
+    let mut events = Vec::new();
+    let people = direct
+        .into_iter()
+        .map(|r| (r, ScheduleKind::OneOnOne))
+        .chain(
+            skip_levels
+                .into_iter()
+                .map(|r| (r, ScheduleKind::SkipLevel)),
+        );
+    for (report_repo, kind) in people {
+        let report = report_repo.load()?;
+        if !report.profile.active {
+            continue;
+        }
+        let name = &report.profile.name;
+
+        let (frequency_days, label) = match kind {
+            ScheduleKind::SkipLevel => (skip_level_days, "Skip-level"),
+            _ => (report.meeting_frequency_days(), "1-on-1"),
+        };
+        let last_meeting = report_repo
+            .entries()
+            .list()?
+            .iter()
+            .filter(|e| e.is_meeting())
+            .map(|e| e.date())
+            .max();
+        let (date, description) = match last_meeting {
+            Some(last) => {
+                let due = last + Days::new(frequency_days as u64);
+                let note = if due < today {
+                    format!("Overdue: last met {}", last.format("%B %d, %Y"))
+                } else {
+                    format!("Last met {}", last.format("%B %d, %Y"))
+                };
+                (due.max(today), note)
+            }
+            None => (today, "No meetings recorded yet".to_string()),
+        };
+        events.push(ScheduledEvent {
+            kind,
+            slug: report.slug.clone(),
+            summary: format!("{}: {}", label, name),
+            description: Some(description),
+            date,
+        });
+
+        if let Some(birthday) = report.profile.birthday {
+            events.push(ScheduledEvent {
+                kind: ScheduleKind::Birthday,
+                slug: report.slug.clone(),
+                summary: format!("🎂 {}'s birthday", name),
+                description: None,
+                date: birthday,
+            });
+        }
+        if let Some(start) = report.profile.start_date {
+            if let Some(first) = start.checked_add_months(Months::new(12)) {
+                events.push(ScheduledEvent {
+                    kind: ScheduleKind::Anniversary,
+                    slug: report.slug.clone(),
+                    summary: format!("🎉 {}'s work anniversary", name),
+                    description: Some(format!("Started {}", start.format("%B %d, %Y"))),
+                    date: first,
+                });
+            }
+        }
+    }
+
+    events.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.summary.cmp(&b.summary)));
+    Ok(events)
+}
+
+/// Serialize events as an iCalendar document; `now` is written as `DTSTAMP` (UTC)
+pub fn write_ics(events: &[ScheduledEvent], now: NaiveDateTime) -> String {
+    let stamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "X-WR-CALNAME:Vibe Manager".to_string(),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid()));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.date.format("%Y%m%d")
+        ));
+        lines.push(format!(
+            "DTEND;VALUE=DATE:{}",
+            (event.date + Days::new(1)).format("%Y%m%d")
+        ));
+        if event.kind.is_yearly() {
+            // Leap-day events fall back to Feb 28 in common years
+            if event.date.month() == 2 && event.date.day() == 29 {
+                lines.push("RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1".to_string());
+            } else {
+                lines.push("RRULE:FREQ=YEARLY".to_string());
+            }
+        }
+        lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape(description)));
+        }
+        lines.push("TRANSP:TRANSPARENT".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line at 75 octets (RFC 5545 §3.1) and terminate it with CRLF
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::parse_ics;
+    use crate::model::ReportProfile;
+    use tempfile::TempDir;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn profile(yaml: &str) -> ReportProfile {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_build_schedule_due_dates_and_yearly_events() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let alex = repo
+            .create_report(
+                "Alex Chen",
+                profile(
+                    "name: Alex Chen\nmeeting_frequency: weekly\n\
+                     birthday: 1992-05-20\nstart_date: 2024-03-15",
+                ),
+            )
+            .unwrap();
+        alex.entries()
+            .create_meeting(Some(date(2026, 1, 8)))
+            .unwrap();
+        repo.create_report("Sam Taylor", profile("name: Sam Taylor"))
+            .unwrap();
+        repo.create_report("Former", profile("name: Former\nactive: false"))
+            .unwrap();
+
+        let today = date(2026, 1, 10);
+        let events = build_schedule(&repo, &WorkspaceSettings::default(), today).unwrap();
+        let got: Vec<_> = events
+            .iter()
+            .map(|e| (e.kind, e.slug.as_str(), e.date))
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                (ScheduleKind::Birthday, "alex-chen", date(1992, 5, 20)),
+                (ScheduleKind::Anniversary, "alex-chen", date(2025, 3, 15)),
+                // Never met: due today
+                (ScheduleKind::OneOnOne, "sam-taylor", today),
+                (ScheduleKind::OneOnOne, "alex-chen", date(2026, 1, 15)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skip_levels_use_second_level_frequency() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let manager = repo
+            .create_report(
+                "Maya Patel",
+                profile("name: Maya Patel\nreport_type: manager"),
+            )
+            .unwrap();
+        let member_dir = manager.path().join("team/devon-okafor");
+        std::fs::create_dir_all(&member_dir).unwrap();
+        std::fs::write(
+            member_dir.join("_profile.md"),
+            "---\nname: Devon Okafor\nmeeting_frequency: weekly\n---\n",
+        )
+        .unwrap();
+        let member = manager.list_team_members().unwrap().remove(0);
+        member
+            .entries()
+            .create_meeting(Some(date(2026, 1, 1)))
+            .unwrap();
+        // A 3rd-level report isn't on the schedule at all
+        let deeper_dir = member_dir.join("team/lee-kim");
+        std::fs::create_dir_all(&deeper_dir).unwrap();
+        std::fs::write(
+            deeper_dir.join("_profile.md"),
+            "---\nname: Lee Kim\nbirthday: 1990-01-05\n---\n",
+        )
+        .unwrap();
+
+        let settings = WorkspaceSettings {
+            default_2nd_level_frequency: "biweekly".to_string(),
+            ..WorkspaceSettings::default()
+        };
+        let events = build_schedule(&repo, &settings, date(2026, 1, 2)).unwrap();
+        let skip = events
+            .iter()
+            .find(|e| e.kind == ScheduleKind::SkipLevel)
+            .unwrap();
+        assert_eq!(skip.summary, "Skip-level: Devon Okafor");
+        assert_eq!(skip.date, date(2026, 1, 15));
+        assert!(events.iter().all(|e| e.slug != "lee-kim"));
+        assert_eq!(events.len(), 2);
+    }
+
+    #[test]
+    fn test_write_ics_round_trips_through_parser() {
+        let events = vec![
+            ScheduledEvent {
+                kind: ScheduleKind::OneOnOne,
+                slug: "alex-chen".to_string(),
+                summary: "1-on-1: Chen, Alex".to_string(),
+                description: Some("Last met; see notes".to_string()),
+                date: date(2026, 1, 15),
+            },
+            ScheduledEvent {
+                kind: ScheduleKind::Birthday,
+                slug: "sam-taylor".to_string(),
+                summary: "Birthday".to_string(),
+                description: None,
+                date: date(1996, 2, 29),
+            },
+        ];
+        let now = date(2026, 1, 10).and_hms_opt(9, 0, 0).unwrap();
+        let ics = write_ics(&events, now);
+
+        assert!(ics.contains("UID:1on1-alex-chen@vibe-manager\r\n"));
+        assert!(ics.contains("SUMMARY:1-on-1: Chen\\, Alex\r\n"));
+        assert!(ics.contains("DESCRIPTION:Last met\\; see notes\r\n"));
+        assert!(ics.contains("RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1\r\n"));
+
+        let parsed = parse_ics(&ics).unwrap();
+        assert!(parsed.skipped.is_empty());
+        assert_eq!(parsed.events.len(), 2);
+        assert_eq!(parsed.events[0].summary, "1-on-1: Chen, Alex");
+        assert!(parsed.events[0].all_day);
+    }
+
+    #[test]
+    fn test_fold_long_lines() {
+        let folded = fold(&format!("SUMMARY:{}", "é".repeat(60)));
+        for line in folded.split("\r\n") {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(
+            folded.replace("\r\n ", ""),
+            format!("SUMMARY:{}\r\n", "é".repeat(60))
+        );
+    }
+}
@@ -0,0 +1,587 @@
+//! Minimal iCalendar (RFC 5545) reader
+//!
+//! Handles what calendar exports actually contain for recurring 1-on-1s:
+//! `VEVENT`s with `DTSTART`, `SUMMARY`, `ATTENDEE`, `STATUS`, `RRULE`
+//! (daily/weekly/monthly/yearly with `INTERVAL`, `COUNT`, `UNTIL`, weekly `BYDAY`),
+//! `EXDATE`, and moved instances via `RECURRENCE-ID`. Time zones are not
+//! resolved: UTC times are converted to local time, `TZID` times are taken
+//! as local wall-clock time.
+
+use std::collections::HashSet;
+
+use chrono::{
+    DateTime, Datelike, Days, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday,
+};
+
+use super::{CalendarError, CalendarResult};
+
+/// Upper bound on occurrences expanded per recurring event
+const MAX_OCCURRENCES: usize = 5000;
+
+/// A calendar event attendee
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attendee {
+    /// Display name (`CN` parameter)
+    pub name: Option<String>,
+    /// Email address (from the `mailto:` value)
+    pub email: Option<String>,
+}
+
+/// How often a recurring event repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A parsed `RRULE`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDateTime>,
+    /// Weekdays for weekly rules (empty = the weekday of `DTSTART`)
+    pub by_day: Vec<Weekday>,
+}
+
+/// One `VEVENT`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    pub uid: Option<String>,
+    pub summary: String,
+    pub start: NaiveDateTime,
+    /// Whether `DTSTART` was a date without a time (all-day event)
+    pub all_day: bool,
+    pub attendees: Vec<Attendee>,
+    pub recurrence: Option<Recurrence>,
+    /// Occurrence starts removed from the series
+    pub exdates: Vec<NaiveDateTime>,
+    /// For a moved instance: the original start it replaces
+    pub recurrence_id: Option<NaiveDateTime>,
+    pub cancelled: bool,
+}
+
+impl CalendarEvent {
+    /// Start times of every occurrence up to and including `until`
+    ///
+    /// Non-recurring events yield their single start (if not after `until`).
+    pub fn occurrences(&self, until: NaiveDateTime) -> Vec<NaiveDateTime> {
+        let Some(rule) = &self.recurrence else {
+            return if self.start <= until {
+                vec![self.start]
+            } else {
+                vec![]
+            };
+        };
+
+        let limit = rule.until.map_or(until, |u| u.min(until));
+        let excluded: HashSet<NaiveDate> = self.exdates.iter().map(|d| d.date()).collect();
+        let mut out = Vec::new();
+        let mut generated = 0u32;
+
+        'periods: for period in 0u64.. {
+            // Periods since DTSTART in the rule's unit; a huge INTERVAL
+            // overflows, which ends the series like running off the calendar
+            let step = period.checked_mul(rule.interval as u64);
+            let months = |per_period: u64| {
+                step.and_then(|n| n.checked_mul(per_period))
+                    .and_then(|n| u32::try_from(n).ok())
+                    .and_then(|n| self.start.checked_add_months(Months::new(n)))
+            };
+            let candidates = match rule.frequency {
+                Frequency::Daily => {
+                    vec![step.and_then(|n| self.start.checked_add_days(Days::new(n)))]
+                }
+                Frequency::Monthly => vec![months(1)],
+                Frequency::Yearly => vec![months(12)],
+                Frequency::Weekly => {
+                    let Some(week_start) = step.and_then(|n| n.checked_mul(7)).and_then(|n| {
+                        self.start
+                            .date()
+                            .week(Weekday::Mon)
+                            .first_day()
+                            .checked_add_days(Days::new(n))
+                    }) else {
+                        break 'periods;
+                    };
+                    let days = if rule.by_day.is_empty() {
+                        vec![self.start.weekday()]
+                    } else {
+                        rule.by_day.clone()
+                    };
+                    let mut dates: Vec<_> = days
+                        .iter()
+                        .map(|d| {
+                            week_start
+                                .checked_add_days(Days::new(d.num_days_from_monday() as u64))
+                                .map(|date| date.and_time(self.start.time()))
+                        })
+                        .collect();
+                    dates.sort();
+                    dates
+                }
+            };
+
+            for candidate in candidates {
+                let Some(start) = candidate else {
+                    break 'periods;
+                };
+                if start < self.start {
+                    continue;
+                }
+                if start > limit || out.len() >= MAX_OCCURRENCES {
+                    break 'periods;
+                }
+                // Adding months clamps the 31st to a shorter month's last
+                // day; RFC 5545 has those months (and Feb 29 in other
+                // years) skipped instead
+                let by_month = matches!(rule.frequency, Frequency::Monthly | Frequency::Yearly);
+                if by_month && start.day() != self.start.day() {
+                    continue;
+                }
+                generated += 1;
+                if rule.count.is_some_and(|c| generated > c) {
+                    break 'periods;
+                }
+                if !excluded.contains(&start.date()) {
+                    out.push(start);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Events read from an iCalendar document
+#[derive(Debug, Clone, Default)]
+pub struct ParsedCalendar {
+    pub events: Vec<CalendarEvent>,
+    /// One message per event that couldn't be read (and was left out)
+    pub skipped: Vec<String>,
+}
+
+/// Parse every `VEVENT` in an iCalendar document
+///
+/// A malformed event is skipped with a message rather than failing the
+/// whole file — an export with one odd birthday shouldn't block an import.
+/// Only content that isn't iCalendar at all is an error.
+pub fn parse_ics(content: &str) -> CalendarResult<ParsedCalendar> {
+    let lines = unfold(content);
+    if !lines
+        .iter()
+        .any(|l| l.trim().eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err(CalendarError::Parse(
+            "not an iCalendar file (no BEGIN:VCALENDAR)".to_string(),
+        ));
+    }
+
+    let mut parsed = ParsedCalendar::default();
+    let mut current: Option<(EventBuilder, Option<CalendarError>)> = None;
+    // Depth of components nested inside the current event (e.g. VALARM),
+    // whose properties must not leak into the event
+    let mut nested = 0;
+
+    for (line_no, line) in lines.iter().enumerate() {
+        let Some((name, params, value)) = split_property(line) else {
+            continue;
+        };
+        match (name.as_str(), value) {
+            ("BEGIN", "VEVENT") => {
+                current = Some((EventBuilder::default(), None));
+                nested = 0;
+            }
+            ("BEGIN", _) if current.is_some() => nested += 1,
+            ("END", component) if component != "VEVENT" && nested > 0 => nested -= 1,
+            _ if nested > 0 => {}
+            ("END", "VEVENT") => {
+                let Some((builder, error)) = current.take() else {
+                    continue;
+                };
+                let label = builder.label(line_no + 1);
+                match (error, builder.build()) {
+                    (Some(error), _) => parsed.skipped.push(format!("{}: {}", label, error)),
+                    (None, None) => parsed.skipped.push(format!("{}: no DTSTART", label)),
+                    (None, Some(event)) => parsed.events.push(event),
+                }
+            }
+            _ => {
+                if let Some((builder, error)) = current.as_mut() {
+                    if let Err(e) = builder.property(&name, &params, value) {
+                        error.get_or_insert(e);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(parsed)
+}
+
+#[derive(Default)]
+struct EventBuilder {
+    uid: Option<String>,
+    summary: String,
+    start: Option<(NaiveDateTime, bool)>,
+    attendees: Vec<Attendee>,
+    recurrence: Option<Recurrence>,
+    exdates: Vec<NaiveDateTime>,
+    recurrence_id: Option<NaiveDateTime>,
+    cancelled: bool,
+}
+
+impl EventBuilder {
+    fn property(
+        &mut self,
+        name: &str,
+        params: &[(String, String)],
+        value: &str,
+    ) -> CalendarResult<()> {
+        match name {
+            "UID" => self.uid = Some(value.to_string()),
+            "SUMMARY" => self.summary = unescape(value),
+            "STATUS" => self.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            "DTSTART" => self.start = Some(parse_datetime(value)?),
+            "RECURRENCE-ID" => self.recurrence_id = Some(parse_datetime(value)?.0),
+            "EXDATE" => {
+                for part in value.split(',') {
+                    self.exdates.push(parse_datetime(part)?.0);
+                }
+            }
+            "RRULE" => self.recurrence = Some(parse_rrule(value)?),
+            "ATTENDEE" => {
+                let name = params
+                    .iter()
+                    .find(|(k, _)| k == "CN")
+                    .map(|(_, v)| v.clone());
+                let email = value
+                    .get(..7)
+                    .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+                    .map(|_| value[7..].to_string());
+                self.attendees.push(Attendee { name, email });
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// How to refer to this event in a skip message
+    fn label(&self, end_line: usize) -> String {
+        if self.summary.is_empty() {
+            format!("event ending on line {}", end_line)
+        } else {
+            format!("'{}'", self.summary)
+        }
+    }
+
+    fn build(self) -> Option<CalendarEvent> {
+        let (start, all_day) = self.start?;
+        Some(CalendarEvent {
+            uid: self.uid,
+            summary: self.summary,
+            start,
+            all_day,
+            attendees: self.attendees,
+            recurrence: self.recurrence,
+            exdates: self.exdates,
+            recurrence_id: self.recurrence_id,
+            cancelled: self.cancelled,
+        })
+    }
+}
+
+/// Join folded lines (continuations start with a space or tab)
+fn unfold(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in content.lines() {
+        let raw = raw.trim_end_matches('\r');
+        if let Some(rest) = raw.strip_prefix([' ', '\t']) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(rest);
+                continue;
+            }
+        }
+        lines.push(raw.to_string());
+    }
+    lines
+}
+
+/// Property parameters as uppercased (name, value) pairs
+type Params = Vec<(String, String)>;
+
+/// Split `NAME;PARAM=VALUE;...:value`, honoring quoted parameter values
+fn split_property(line: &str) -> Option<(String, Params, &str)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.to_ascii_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+    Some((name, params, value))
+}
+
+/// Decode `TEXT` escapes in one left-to-right pass, so `\\n` stays a
+/// backslash followed by `n` (newlines become spaces: summaries are one line)
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push(' '),
+            Some(escaped @ (',' | ';' | '\\')) => out.push(escaped),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Parse a `DATE` or `DATE-TIME` value; returns (local time, is all-day)
+fn parse_datetime(value: &str) -> CalendarResult<(NaiveDateTime, bool)> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .map_err(|_| CalendarError::Parse(format!("invalid date-time '{}'", value)))?;
+        let local = DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc).with_timezone(&Local);
+        return Ok((local.naive_local(), false));
+    }
+    if let Ok(naive) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S") {
+        return Ok((naive, false));
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .map(|date| (date.and_time(NaiveTime::MIN), true))
+        .map_err(|_| CalendarError::Parse(format!("invalid date '{}'", value)))
+}
+
+fn parse_rrule(value: &str) -> CalendarResult<Recurrence> {
+    let mut frequency = None;
+    let mut rule = Recurrence {
+        frequency: Frequency::Weekly,
+        interval: 1,
+        count: None,
+        until: None,
+        by_day: Vec::new(),
+    };
+    let invalid = || CalendarError::Parse(format!("unsupported RRULE '{}'", value));
+
+    for part in value.split(';') {
+        let Some((key, val)) = part.split_once('=') else {
+            continue;
+        };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                frequency = Some(match val.to_ascii_uppercase().as_str() {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    _ => return Err(invalid()),
+                })
+            }
+            "INTERVAL" => {
+                rule.interval = val.parse().ok().filter(|&i| i > 0).ok_or_else(invalid)?
+            }
+            "COUNT" => rule.count = Some(val.parse().map_err(|_| invalid())?),
+            "UNTIL" => rule.until = Some(parse_datetime(val)?.0),
+            "BYDAY" => {
+                rule.by_day = val
+                    .split(',')
+                    .map(|d| parse_weekday(d).ok_or_else(invalid))
+                    .collect::<CalendarResult<_>>()?;
+            }
+            _ => {}
+        }
+    }
+
+    rule.frequency = frequency.ok_or_else(invalid)?;
+    Ok(rule)
+}
+
+/// Parse a `BYDAY` entry (ordinal prefixes like `1MO` are not supported)
+fn parse_weekday(day: &str) -> Option<Weekday> {
+    match day.trim().to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    }
+
+    fn wrap(events: &str) -> String {
+        format!("BEGIN:VCALENDAR\n{}END:VCALENDAR\n", events)
+    }
+
+    const WEEKLY: &str = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:abc@example.com\r
+SUMMARY:Alex / Me 1:1\r
+DTSTART;TZID=Europe/Berlin:20260105T100000\r
+RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=4\r
+EXDATE;TZID=Europe/Berlin:20260119T100000\r
+ORGANIZER;CN=Me:mailto:me@example.com\r
+ATTENDEE;CN=\"Chen, Alex\";ROLE=REQ-PARTICIPANT:mailto:alex@exa\r
+ mple.com\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn test_parse_event_fields() {
+        let events = parse_ics(WEEKLY).unwrap().events;
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.uid.as_deref(), Some("abc@example.com"));
+        assert_eq!(event.summary, "Alex / Me 1:1");
+        assert_eq!(event.start, at(2026, 1, 5, 10));
+        assert!(!event.all_day);
+        // Organizer is not an attendee; folded line is joined; quoted CN keeps its comma
+        assert_eq!(
+            event.attendees,
+            vec![Attendee {
+                name: Some("Chen, Alex".to_string()),
+                email: Some("alex@example.com".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_weekly_occurrences_with_interval_count_and_exdate() {
+        let event = &parse_ics(WEEKLY).unwrap().events[0];
+        let occurrences = event.occurrences(at(2026, 12, 31, 0));
+        // Jan 5, (Jan 19 excluded), Feb 2, Feb 16 — COUNT includes the excluded one
+        assert_eq!(
+            occurrences,
+            vec![at(2026, 1, 5, 10), at(2026, 2, 2, 10), at(2026, 2, 16, 10)]
+        );
+        // Cut off at `until`
+        assert_eq!(event.occurrences(at(2026, 1, 31, 0)).len(), 1);
+    }
+
+    #[test]
+    fn test_weekly_byday() {
+        let ics = "BEGIN:VEVENT\nDTSTART:20260106T090000\nRRULE:FREQ=WEEKLY;BYDAY=TU,TH;UNTIL=20260115T235959\nEND:VEVENT\n";
+        let event = &parse_ics(&wrap(ics)).unwrap().events[0];
+        assert_eq!(
+            event.occurrences(at(2026, 12, 31, 0)),
+            vec![
+                at(2026, 1, 6, 9),
+                at(2026, 1, 8, 9),
+                at(2026, 1, 13, 9),
+                at(2026, 1, 15, 9)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_huge_interval_ends_the_series() {
+        for freq in ["DAILY", "WEEKLY", "MONTHLY", "YEARLY"] {
+            let ics = format!(
+                "BEGIN:VEVENT\nDTSTART:20260106T090000\nRRULE:FREQ={};INTERVAL=4294967295\nEND:VEVENT\n",
+                freq
+            );
+            let event = &parse_ics(&wrap(&ics)).unwrap().events[0];
+            assert_eq!(
+                event.occurrences(NaiveDateTime::MAX),
+                vec![at(2026, 1, 6, 9)],
+                "{}",
+                freq
+            );
+        }
+    }
+
+    #[test]
+    fn test_monthly_and_all_day() {
+        let ics = "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20260110\nRRULE:FREQ=MONTHLY;COUNT=3\nSTATUS:CANCELLED\nEND:VEVENT\n";
+        let event = &parse_ics(&wrap(ics)).unwrap().events[0];
+        assert!(event.all_day);
+        assert!(event.cancelled);
+        assert_eq!(event.occurrences(at(2026, 12, 31, 0)).len(), 3);
+        assert_eq!(
+            event.occurrences(at(2026, 12, 31, 0))[2],
+            at(2026, 3, 10, 0)
+        );
+    }
+
+    #[test]
+    fn test_monthly_skips_months_without_the_day() {
+        let ics = "BEGIN:VEVENT\nDTSTART:20260131T090000\nRRULE:FREQ=MONTHLY;COUNT=3\nEND:VEVENT\n";
+        let event = &parse_ics(&wrap(ics)).unwrap().events[0];
+        assert_eq!(
+            event.occurrences(at(2026, 12, 31, 0)),
+            vec![at(2026, 1, 31, 9), at(2026, 3, 31, 9), at(2026, 5, 31, 9)]
+        );
+
+        let ics = "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20240229\nRRULE:FREQ=YEARLY\nEND:VEVENT\n";
+        let event = &parse_ics(&wrap(ics)).unwrap().events[0];
+        assert_eq!(
+            event.occurrences(at(2029, 1, 1, 0)),
+            vec![at(2024, 2, 29, 0), at(2028, 2, 29, 0)]
+        );
+    }
+
+    #[test]
+    fn test_unescape_in_one_pass() {
+        assert_eq!(unescape("C:\\\\notes"), "C:\\notes");
+        assert_eq!(
+            unescape("Alex\\, Sam\\; 1:1\\nagenda"),
+            "Alex, Sam; 1:1 agenda"
+        );
+        assert_eq!(unescape("trailing\\"), "trailing\\");
+    }
+
+    #[test]
+    fn test_malformed_events_are_skipped() {
+        let ics = wrap(
+            "BEGIN:VEVENT\nSUMMARY:No start\nEND:VEVENT\n\
+             BEGIN:VEVENT\nSUMMARY:Bad\nDTSTART:garbage\nEND:VEVENT\n\
+             BEGIN:VEVENT\nDTSTART:20260101T090000\nRRULE:FREQ=HOURLY\nEND:VEVENT\n\
+             BEGIN:VEVENT\nSUMMARY:Fine\nDTSTART:20260101T090000\nEND:VEVENT\n",
+        );
+        let parsed = parse_ics(&ics).unwrap();
+        assert_eq!(parsed.events.len(), 1);
+        assert_eq!(parsed.events[0].summary, "Fine");
+        assert_eq!(parsed.skipped.len(), 3);
+        assert_eq!(parsed.skipped[0], "'No start': no DTSTART");
+    }
+
+    #[test]
+    fn test_not_icalendar() {
+        assert!(parse_ics("name,date\nAlex,2026-01-01\n").is_err());
+    }
+}
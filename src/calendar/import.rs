@@ -0,0 +1,473 @@
+//! Import past 1-on-1s from a calendar export
+//!
+//! Importing is two steps so the CLI can offer a dry run: [`plan_import`]
+//! matches events to reports and works out which meetings are missing from
+//! the journal, then [`apply_import`] writes them.
+//!
+//! An event is matched to reports through the workspace's `calendar.mappings`
+//! first (keyed by attendee email, attendee name, or event title), then by
+//! attendee email or name against report profiles. An event that resolves
+//! to exactly one report is treated as a 1-on-1 with them; events matching
+//! several reports (team syncs) or none are reported and skipped.
+//!
+//! Future occurrences set each report's `next_meeting`. A planned date with
+//! no matching upcoming event is left alone until it passes, so importing a
+//! partial export never wipes dates it doesn't cover.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use chrono::{Days, NaiveDate, NaiveDateTime};
+
+use super::ics::{CalendarEvent, ParsedCalendar};
+use super::CalendarResult;
+use crate::model::CalendarSettings;
+use crate::storage::{EntryRepository, ReportRepository, WorkspaceRepository};
+
+/// How far ahead to look for a report's next planned 1-on-1
+const PLANNING_HORIZON_DAYS: u64 = 366;
+
+/// A meeting the import would record
+#[derive(Debug, Clone)]
+pub struct PlannedMeeting {
+    /// Slug of the report the meeting is with
+    pub slug: String,
+    /// Report's display name
+    pub name: String,
+    pub start: NaiveDateTime,
+    /// Title of the calendar event
+    pub summary: String,
+    entries: EntryRepository,
+}
+
+/// A change to a report's next planned 1-on-1
+#[derive(Debug, Clone)]
+pub struct PlannedDate {
+    pub slug: String,
+    pub name: String,
+    /// Planned date before the import
+    pub previous: Option<NaiveDateTime>,
+    /// Planned date after the import (`None` clears a date that has passed)
+    pub next: Option<NaiveDateTime>,
+    report: ReportRepository,
+}
+
+/// What an import would do, for previewing before anything is written
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    /// Meetings to record, oldest first
+    pub meetings: Vec<PlannedMeeting>,
+    /// Reports whose next planned 1-on-1 changes
+    pub planned: Vec<PlannedDate>,
+    /// Past occurrences already covered by a journal meeting that day
+    pub already_recorded: usize,
+    /// Titles of events that matched no report
+    pub unmatched: BTreeSet<String>,
+    /// Titles of events that matched more than one report
+    pub ambiguous: BTreeSet<String>,
+    /// Events the parser couldn't read
+    pub skipped: Vec<String>,
+}
+
+/// A report the importer can match events against
+struct Target {
+    slug: String,
+    name: String,
+    email: Option<String>,
+    report: ReportRepository,
+    entries: EntryRepository,
+    meeting_dates: HashSet<NaiveDate>,
+    /// Planned date stored in the profile
+    next_meeting: Option<NaiveDateTime>,
+    /// Earliest upcoming occurrence found in the calendar
+    upcoming: Option<NaiveDateTime>,
+}
+
+/// Work out which past calendar meetings are missing from the journal
+///
+/// Occurrences before `now` become meetings to record; the earliest one
+/// after `now` becomes the report's next planned 1-on-1. Cancelled and
+/// all-day events are ignored.
+pub fn plan_import(
+    repo: &WorkspaceRepository,
+    calendar: &ParsedCalendar,
+    settings: &CalendarSettings,
+    now: NaiveDateTime,
+) -> CalendarResult<ImportPlan> {
+    let mut targets = Vec::new();
+    for report_repo in repo.list_all_reports()? {
+        let report = report_repo.load()?;
+        let entries = report_repo.entries();
+        let meeting_dates = entries
+            .list()?
+            .iter()
+            .filter(|e| e.is_meeting())
+            .map(|e| e.date())
+            .collect();
+        targets.push(Target {
+            slug: report.slug,
+            name: report.profile.name,
+            email: report.profile.email,
+            report: report_repo,
+            entries,
+            meeting_dates,
+            next_meeting: report.profile.next_meeting,
+            upcoming: None,
+        });
+    }
+
+    let mappings: HashMap<String, &str> = settings
+        .mappings
+        .iter()
+        .map(|(key, slug)| (normalize(key), slug.as_str()))
+        .collect();
+
+    // Moved instances replace the original occurrence of their series
+    let mut moved: HashMap<&str, Vec<NaiveDateTime>> = HashMap::new();
+    for event in &calendar.events {
+        if let (Some(uid), Some(original)) = (&event.uid, event.recurrence_id) {
+            moved.entry(uid.as_str()).or_default().push(original);
+        }
+    }
+
+    let mut plan = ImportPlan {
+        skipped: calendar.skipped.clone(),
+        ..ImportPlan::default()
+    };
+
+    for event in &calendar.events {
+        if event.cancelled || event.all_day {
+            continue;
+        }
+
+        let matched = match_event(event, &targets, &mappings);
+        let target_idx = match matched.as_slice() {
+            [idx] => *idx,
+            [] => {
+                plan.unmatched.insert(event.summary.clone());
+                continue;
+            }
+            _ => {
+                plan.ambiguous.insert(event.summary.clone());
+                continue;
+            }
+        };
+
+        let mut series = event.clone();
+        if event.recurrence_id.is_none() {
+            if let Some(originals) = event.uid.as_deref().and_then(|uid| moved.get(uid)) {
+                series.exdates.extend(originals);
+            }
+        }
+
+        let target = &mut targets[target_idx];
+        let horizon = now + Days::new(PLANNING_HORIZON_DAYS);
+        let (past, future): (Vec<_>, Vec<_>) = series
+            .occurrences(horizon)
+            .into_iter()
+            .partition(|s| *s < now);
+        if let Some(first) = future.first() {
+            target.upcoming = Some(target.upcoming.map_or(*first, |u| u.min(*first)));
+        }
+        for start in past {
+            // Also dedups events that land on the same day within this import
+            if !target.meeting_dates.insert(start.date()) {
+                plan.already_recorded += 1;
+                continue;
+            }
+            plan.meetings.push(PlannedMeeting {
+                slug: target.slug.clone(),
+                name: target.name.clone(),
+                start,
+                summary: event.summary.clone(),
+                entries: target.entries.clone(),
+            });
+        }
+    }
+
+    for target in targets {
+        let next = match target.upcoming {
+            Some(upcoming) => Some(upcoming),
+            None => target.next_meeting.filter(|planned| *planned >= now),
+        };
+        if next != target.next_meeting {
+            plan.planned.push(PlannedDate {
+                slug: target.slug,
+                name: target.name,
+                previous: target.next_meeting,
+                next,
+                report: target.report,
+            });
+        }
+    }
+
+    plan.meetings.sort_by_key(|m| m.start);
+    Ok(plan)
+}
+
+/// Write the planned meetings as journal entries and update planned dates;
+/// returns how many meetings were written
+pub fn apply_import(plan: &ImportPlan) -> CalendarResult<usize> {
+    for planned in &plan.planned {
+        let mut report = planned.report.load()?;
+        report.profile.next_meeting = planned.next;
+        planned.report.save(&report)?;
+    }
+
+    for meeting in &plan.meetings {
+        let content = format!(
+            "# 1-on-1 - {}\n\n_Imported from calendar: {}_\n",
+            meeting.start.date().format("%B %d, %Y"),
+            meeting.summary
+        );
+        meeting.entries.create_meeting_at(meeting.start, content)?;
+    }
+    Ok(plan.meetings.len())
+}
+
+/// Indices of the targets an event resolves to (deduplicated)
+fn match_event(
+    event: &CalendarEvent,
+    targets: &[Target],
+    mappings: &HashMap<String, &str>,
+) -> Vec<usize> {
+    let by_slug = |slug: &str| targets.iter().position(|t| t.slug == slug);
+
+    // Explicit mappings win over automatic matching
+    let mut keys = vec![normalize(&event.summary)];
+    for attendee in &event.attendees {
+        keys.extend(attendee.email.as_deref().map(normalize));
+        keys.extend(attendee.name.as_deref().map(normalize));
+    }
+    let mut matched: Vec<usize> = keys
+        .iter()
+        .filter_map(|key| mappings.get(key))
+        .filter_map(|slug| by_slug(slug))
+        .collect();
+
+    if matched.is_empty() {
+        for attendee in &event.attendees {
+            let email = attendee.email.as_deref().map(normalize);
+            let name = attendee.name.as_deref().map(normalize_name);
+            matched.extend(targets.iter().enumerate().filter_map(|(idx, target)| {
+                let email_match =
+                    email.is_some() && target.email.as_deref().map(normalize) == email;
+                let name_match = name.is_some() && Some(normalize(&target.name)) == name;
+                (email_match || name_match).then_some(idx)
+            }));
+        }
+    }
+
+    matched.sort_unstable();
+    matched.dedup();
+    matched
+}
+
+fn normalize(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+/// Normalize an attendee name, turning "Chen, Alex" into "alex chen"
+fn normalize_name(name: &str) -> String {
+    match name.split_once(',') {
+        Some((last, first)) => normalize(&format!("{} {}", first.trim(), last.trim())),
+        None => normalize(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::parse_ics;
+    use crate::model::ReportProfile;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    }
+
+    fn profile(name: &str, email: Option<&str>) -> ReportProfile {
+        serde_yaml::from_str::<ReportProfile>(&format!("name: {}", name))
+            .map(|mut p| {
+                p.email = email.map(String::from);
+                p
+            })
+            .unwrap()
+    }
+
+    fn workspace() -> (TempDir, WorkspaceRepository) {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        repo.create_report("Alex Chen", profile("Alex Chen", Some("alex@corp.com")))
+            .unwrap();
+        repo.create_report("Sam Taylor", profile("Sam Taylor", None))
+            .unwrap();
+        (temp, repo)
+    }
+
+    fn event(summary: &str, start: &str, extra: &str) -> String {
+        format!(
+            "BEGIN:VEVENT\nUID:{}\nSUMMARY:{}\nDTSTART:{}\n{}END:VEVENT\n",
+            summary, summary, start, extra
+        )
+    }
+
+    fn calendar(events: &[String]) -> ParsedCalendar {
+        parse_ics(&format!(
+            "BEGIN:VCALENDAR\n{}END:VCALENDAR\n",
+            events.concat()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_plan_matches_by_email_and_name() {
+        let (_temp, repo) = workspace();
+        let cal = calendar(&[
+            event(
+                "Alex 1:1",
+                "20260105T100000",
+                "RRULE:FREQ=WEEKLY;COUNT=3\nATTENDEE;CN=Someone:mailto:ALEX@corp.com\n",
+            ),
+            event(
+                "Sam sync",
+                "20260106T150000",
+                "ATTENDEE;CN=\"Taylor, Sam\":mailto:sam@home.example\n",
+            ),
+        ]);
+
+        let plan = plan_import(
+            &repo,
+            &cal,
+            &CalendarSettings::default(),
+            at(2026, 1, 13, 0),
+        )
+        .unwrap();
+        let got: Vec<_> = plan
+            .meetings
+            .iter()
+            .map(|m| (m.slug.as_str(), m.start))
+            .collect();
+        // Third Alex occurrence (Jan 19) is in the future
+        assert_eq!(
+            got,
+            vec![
+                ("alex-chen", at(2026, 1, 5, 10)),
+                ("sam-taylor", at(2026, 1, 6, 15)),
+                ("alex-chen", at(2026, 1, 12, 10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_uses_mappings_and_reports_unmatched_and_ambiguous() {
+        let (_temp, repo) = workspace();
+        let cal = calendar(&[
+            event("Coffee w/ S", "20260105T100000", ""),
+            event("Mystery", "20260105T110000", ""),
+            event(
+                "Team sync",
+                "20260105T120000",
+                "ATTENDEE;CN=Alex Chen:mailto:a@x\nATTENDEE;CN=Sam Taylor:mailto:s@x\n",
+            ),
+        ]);
+        let mut settings = CalendarSettings::default();
+        settings
+            .mappings
+            .insert("coffee W/ s".to_string(), "sam-taylor".to_string());
+
+        let plan = plan_import(&repo, &cal, &settings, at(2026, 2, 1, 0)).unwrap();
+        assert_eq!(plan.meetings.len(), 1);
+        assert_eq!(plan.meetings[0].slug, "sam-taylor");
+        assert!(plan.unmatched.contains("Mystery"));
+        assert!(plan.ambiguous.contains("Team sync"));
+    }
+
+    #[test]
+    fn test_plan_skips_recorded_days_and_moved_instances() {
+        let (temp, repo) = workspace();
+        repo.report("alex-chen")
+            .entries()
+            .create_meeting(Some(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()))
+            .unwrap();
+        let attendee = "ATTENDEE;CN=Alex Chen:mailto:x@y\n";
+        let cal = calendar(&[
+            event(
+                "Alex 1:1",
+                "20260105T100000",
+                &format!("RRULE:FREQ=WEEKLY;COUNT=3\n{}", attendee),
+            ),
+            // Jan 12 occurrence moved to Jan 13
+            event(
+                "Alex 1:1",
+                "20260113T090000",
+                &format!("RECURRENCE-ID:20260112T100000\n{}", attendee),
+            ),
+        ]);
+
+        let plan =
+            plan_import(&repo, &cal, &CalendarSettings::default(), at(2026, 2, 1, 0)).unwrap();
+        let starts: Vec<_> = plan.meetings.iter().map(|m| m.start).collect();
+        assert_eq!(starts, vec![at(2026, 1, 13, 9), at(2026, 1, 19, 10)]);
+        assert_eq!(plan.already_recorded, 1);
+
+        // Applying writes context: meeting entries; a second plan finds nothing new
+        assert_eq!(apply_import(&plan).unwrap(), 2);
+        let written = fs::read_dir(temp.path().join("alex-chen/journal"))
+            .unwrap()
+            .count();
+        assert_eq!(written, 3);
+        let replan =
+            plan_import(&repo, &cal, &CalendarSettings::default(), at(2026, 2, 1, 0)).unwrap();
+        assert!(replan.meetings.is_empty());
+        assert_eq!(replan.already_recorded, 3);
+    }
+
+    #[test]
+    fn test_plan_sets_next_meeting_and_clears_passed_dates() {
+        let (_temp, repo) = workspace();
+        let sam = repo.report("sam-taylor");
+        let mut report = sam.load().unwrap();
+        report.profile.next_meeting = Some(at(2026, 1, 2, 9));
+        sam.save(&report).unwrap();
+        let cal = calendar(&[event(
+            "Alex 1:1",
+            "20260105T100000",
+            "RRULE:FREQ=WEEKLY\nATTENDEE;CN=Alex Chen:mailto:x@y\n",
+        )]);
+
+        let plan = plan_import(
+            &repo,
+            &cal,
+            &CalendarSettings::default(),
+            at(2026, 1, 13, 0),
+        )
+        .unwrap();
+        let planned: Vec<_> = plan
+            .planned
+            .iter()
+            .map(|p| (p.slug.as_str(), p.previous, p.next))
+            .collect();
+        assert_eq!(
+            planned,
+            vec![
+                ("alex-chen", None, Some(at(2026, 1, 19, 10))),
+                ("sam-taylor", Some(at(2026, 1, 2, 9)), None),
+            ]
+        );
+
+        apply_import(&plan).unwrap();
+        let alex = repo.report("alex-chen").load().unwrap();
+        assert_eq!(alex.profile.next_meeting, Some(at(2026, 1, 19, 10)));
+        assert_eq!(sam.load().unwrap().profile.next_meeting, None);
+    }
+
+    #[test]
+    fn test_normalize_name_swaps_last_first() {
+        assert_eq!(normalize_name("Chen, Alex"), "alex chen");
+        assert_eq!(normalize_name(" Alex Chen "), "alex chen");
+    }
+}
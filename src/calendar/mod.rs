@@ -0,0 +1,37 @@
+//! Calendar integration
+//!
+//! Reads exported iCalendar (`.ics`) files and turns past 1-on-1 events into
+//! journal entries, and writes the recommended meeting schedule back out as
+//! a subscribable `.ics`. With the `caldav` build feature, the same import
+//! can run against a CalDAV calendar instead of a file.
+
+#[cfg(feature = "caldav")]
+pub mod caldav;
+pub mod export;
+pub mod ics;
+pub mod import;
+
+pub use export::{build_schedule, write_ics, ScheduleKind, ScheduledEvent};
+pub use ics::{parse_ics, Attendee, CalendarEvent, ParsedCalendar};
+pub use import::{apply_import, plan_import, ImportPlan, PlannedDate, PlannedMeeting};
+
+use thiserror::Error;
+
+use crate::storage::StorageError;
+
+#[derive(Error, Debug)]
+pub enum CalendarError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Calendar parse error: {0}")]
+    Parse(String),
+
+    #[error("Calendar server error: {0}")]
+    Remote(String),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+pub type CalendarResult<T> = Result<T, CalendarError>;
@@ -8,7 +8,7 @@
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style, Stylize},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
     Frame,
@@ -17,7 +17,7 @@ use ratatui::{
 use crate::model::{MoodTrend, ReportSummary, ReportType};
 use crate::theme::{
     format_days_ago, mood_color, mood_gauge, mood_trend_icon, overdue_color, overdue_icon, sprites,
-    style_muted, style_title, COLOR_SECONDARY,
+    style_muted, style_report, style_title, theme,
 };
 
 use super::doorway_card::{DoorwayCard, DOORWAY_CARD_HEIGHT};
@@ -45,7 +45,7 @@ impl<'a> AvatarCard<'a> {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Double)
-                .border_style(Style::default().fg(COLOR_SECONDARY))
+                .border_style(Style::default().fg(theme().secondary))
                 .title(format!(" ★ {} ★ ", self.summary.level))
                 .title_alignment(Alignment::Center)
                 .title_style(style_title())
@@ -53,10 +53,10 @@ impl<'a> AvatarCard<'a> {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(self.summary.color))
+                .border_style(style_report(self.summary.color))
                 .title(format!(" ★ {} ★ ", self.summary.level))
                 .title_alignment(Alignment::Center)
-                .title_style(Style::default().fg(self.summary.color))
+                .title_style(style_report(self.summary.color))
         };
 
         let inner = block.inner(area);
@@ -83,7 +83,7 @@ impl<'a> AvatarCard<'a> {
             style_title()
         } else {
             Style::default()
-                .fg(self.summary.color)
+                .fg(theme().report(self.summary.color))
                 .add_modifier(Modifier::BOLD)
         };
         let name_para =
@@ -101,8 +101,8 @@ impl<'a> AvatarCard<'a> {
             .recent_mood
             .map_or(style_muted(), |m| Style::default().fg(mood_color(m)));
         let trend_style = match self.summary.mood_trend {
-            Some(MoodTrend::Rising) => Style::default().fg(Color::Green).bold(),
-            Some(MoodTrend::Falling) => Style::default().fg(Color::Rgb(255, 140, 0)).bold(),
+            Some(MoodTrend::Rising) => Style::default().fg(theme().rising).bold(),
+            Some(MoodTrend::Falling) => Style::default().fg(theme().falling).bold(),
             _ => style_muted(),
         };
         let mood_spans = if trend_icon.trim().is_empty() {
@@ -134,7 +134,7 @@ impl<'a> AvatarCard<'a> {
         let face_style = if self.is_selected {
             style_title()
         } else {
-            Style::default().fg(self.summary.color)
+            style_report(self.summary.color)
         };
 
         let sprite = sprites::FaceSprite::from_summary(self.summary, face_style);
@@ -168,8 +168,21 @@ impl<'a> AvatarGrid<'a> {
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect) {
+        for (idx, card_area) in self.card_areas(area) {
+            let summary = &self.summaries[idx];
+            let selected = idx == self.selected;
+            if matches!(summary.report_type, ReportType::Manager) {
+                DoorwayCard::new(summary, selected).render(frame, card_area);
+            } else {
+                AvatarCard::new(summary, selected).render(frame, card_area);
+            }
+        }
+    }
+
+    /// Where each card goes, by summary index
+    pub fn card_areas(&self, area: Rect) -> Vec<(usize, Rect)> {
         if self.summaries.is_empty() {
-            return;
+            return Vec::new();
         }
 
         let card_width: u16 = 18;
@@ -215,12 +228,10 @@ impl<'a> AvatarGrid<'a> {
             .constraints(row_constraints)
             .split(area);
 
+        let mut areas = Vec::with_capacity(self.summaries.len());
         for (grid_row, row_area) in grid_rows.iter().zip(rows.iter()) {
             match grid_row {
-                GridRow::Doorway(idx) => {
-                    let card = DoorwayCard::new(&self.summaries[*idx], *idx == self.selected);
-                    card.render(frame, *row_area);
-                }
+                GridRow::Doorway(idx) => areas.push((*idx, *row_area)),
                 GridRow::Ics(indices) => {
                     let col_constraints: Vec<Constraint> = indices
                         .iter()
@@ -232,13 +243,11 @@ impl<'a> AvatarGrid<'a> {
                         .constraints(col_constraints)
                         .split(*row_area);
 
-                    for (idx, col_area) in indices.iter().zip(cols.iter()) {
-                        let card = AvatarCard::new(&self.summaries[*idx], *idx == self.selected);
-                        card.render(frame, *col_area);
-                    }
+                    areas.extend(indices.iter().copied().zip(cols.iter().copied()));
                 }
             }
         }
+        areas
     }
 }
 
@@ -1,5 +1,7 @@
 //! Dashboard component - team overview with RPG-style avatars
 
+use std::rc::Rc;
+
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Style,
@@ -8,14 +10,13 @@ use ratatui::{
     Frame,
 };
 
-use crate::model::{ReportSummary, WorkspaceSummary};
+use crate::model::{MoodScoring, ReportSummary, RosterSort, WorkspaceSummary};
 use crate::theme::{
     health_bar, mood_gauge, rpg_block, simple_block, style_header, style_muted, style_success,
-    style_title, style_warning, COLOR_PRIMARY, COLOR_SECONDARY, COLOR_SUCCESS, COLOR_TEXT,
-    ICON_HEART, ICON_WARNING,
+    style_title, style_warning, theme, ICON_HEART, ICON_WARNING,
 };
 
-use super::AvatarGrid;
+use super::{AvatarGrid, RosterTable};
 
 /// Header data for a hall (the dashboard re-rooted at a manager's team)
 ///
@@ -39,12 +40,12 @@ pub fn render_vibe_manager_title(frame: &mut Frame, area: Rect) {
     let title_block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Double)
-        .border_style(Style::default().fg(COLOR_PRIMARY));
+        .border_style(Style::default().fg(theme().primary));
 
     let title_text = vec![Line::from(vec![
-        Span::styled("⚔ ", Style::default().fg(COLOR_SECONDARY)),
+        Span::styled("⚔ ", Style::default().fg(theme().secondary)),
         Span::styled("VIBE MANAGER", style_title()),
-        Span::styled(" ⚔", Style::default().fg(COLOR_SECONDARY)),
+        Span::styled(" ⚔", Style::default().fg(theme().secondary)),
     ])];
 
     let inner = title_block.inner(area);
@@ -60,6 +61,20 @@ pub struct Dashboard<'a> {
     workspace_summary: &'a WorkspaceSummary,
     selected: usize,
     hall: Option<HallHeader>,
+    /// Filter and sort indicator for the party title
+    view: Option<String>,
+    /// Shown instead of the cards when the filter hides everyone
+    no_match: String,
+    /// Set when the roster shows as a table rather than cards
+    table: Option<TableLayout<'a>>,
+}
+
+/// What the table layout needs beyond the summaries
+struct TableLayout<'a> {
+    /// Per summary: whether a manager's squad line is open
+    expanded: &'a [bool],
+    sort: RosterSort,
+    mood_scoring: MoodScoring,
 }
 
 impl<'a> Dashboard<'a> {
@@ -73,6 +88,9 @@ impl<'a> Dashboard<'a> {
             workspace_summary,
             selected,
             hall: None,
+            view: None,
+            no_match: String::new(),
+            table: None,
         }
     }
 
@@ -82,7 +100,81 @@ impl<'a> Dashboard<'a> {
         self
     }
 
+    /// Show a filtered or re-sorted roster: `indicator` goes in the party
+    /// title, `no_match` in place of the cards if nobody passes the filter
+    pub fn with_view(mut self, indicator: String, no_match: String) -> Self {
+        self.view = Some(indicator);
+        self.no_match = no_match;
+        self
+    }
+
+    /// Show the roster as a table, sorted by `sort` (for the heading arrow)
+    /// with the squad lines of the managers `expanded` marks open
+    pub fn with_table(
+        mut self,
+        expanded: &'a [bool],
+        sort: RosterSort,
+        mood_scoring: MoodScoring,
+    ) -> Self {
+        self.table = Some(TableLayout {
+            expanded,
+            sort,
+            mood_scoring,
+        });
+        self
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = self.layout(area);
+
+        self.render_title(frame, chunks[0]);
+        if self.hall.is_some() {
+            self.render_breadcrumb(frame, chunks[1]);
+            self.render_stats(frame, chunks[2]);
+            self.render_party(frame, chunks[3]);
+        } else {
+            self.render_stats(frame, chunks[1]);
+            self.render_party(frame, chunks[2]);
+        }
+    }
+
+    /// Where each avatar card (or table line) goes, by summary index
+    pub fn card_areas(&self, area: Rect) -> Vec<(usize, Rect)> {
+        let inner = self.party_area(area);
+        match self.roster_table() {
+            Some(table) => table.row_areas(inner),
+            None => AvatarGrid::new(self.summaries, self.selected).card_areas(inner),
+        }
+    }
+
+    /// Where the table's sortable headings are (none for cards)
+    pub fn column_areas(&self, area: Rect) -> Vec<(RosterSort, Rect)> {
+        let inner = self.party_area(area);
+        self.roster_table()
+            .filter(|_| !self.summaries.is_empty())
+            .map(|table| table.column_areas(inner))
+            .unwrap_or_default()
+    }
+
+    /// Inside of the party block
+    fn party_area(&self, area: Rect) -> Rect {
+        let chunks = self.layout(area);
+        let party = chunks[chunks.len() - 1];
+        self.party_block().inner(party)
+    }
+
+    fn roster_table(&self) -> Option<RosterTable<'_>> {
+        let table = self.table.as_ref()?;
+        Some(RosterTable::new(
+            self.summaries,
+            self.selected,
+            table.expanded,
+            table.sort,
+            table.mood_scoring,
+        ))
+    }
+
+    fn layout(&self, area: Rect) -> Rc<[Rect]> {
         // Layout: Title, [Breadcrumb in halls], Stats panel, Avatar grid
         let constraints: Vec<Constraint> = if self.hall.is_some() {
             vec![
@@ -98,20 +190,10 @@ impl<'a> Dashboard<'a> {
                 Constraint::Min(12),   // Avatar grid
             ]
         };
-        let chunks = Layout::default()
+        Layout::default()
             .direction(Direction::Vertical)
             .constraints(constraints)
-            .split(area);
-
-        self.render_title(frame, chunks[0]);
-        if self.hall.is_some() {
-            self.render_breadcrumb(frame, chunks[1]);
-            self.render_stats(frame, chunks[2]);
-            self.render_party(frame, chunks[3]);
-        } else {
-            self.render_stats(frame, chunks[1]);
-            self.render_party(frame, chunks[2]);
-        }
+            .split(area)
     }
 
     fn render_title(&self, frame: &mut Frame, area: Rect) {
@@ -134,7 +216,7 @@ impl<'a> Dashboard<'a> {
             Span::styled(health_bar(hall.health_score, 8), style_header()),
             Span::styled(
                 format!("{}%", hall.health_score),
-                Style::default().fg(COLOR_TEXT),
+                Style::default().fg(theme().text),
             ),
         ]);
         frame.render_widget(Paragraph::new(line), area);
@@ -183,7 +265,7 @@ impl<'a> Dashboard<'a> {
                     Line::from(vec![
                         Span::styled(
                             format!("{} ", ICON_HEART),
-                            Style::default().fg(COLOR_SUCCESS),
+                            Style::default().fg(theme().success),
                         ),
                         Span::raw(mood_gauge(rounded)),
                     ]),
@@ -201,19 +283,37 @@ impl<'a> Dashboard<'a> {
         frame.render_widget(mood_para, stats_chunks[1]);
     }
 
-    fn render_party(&self, frame: &mut Frame, area: Rect) {
-        // Outer block with RPG title (squad name inside a hall)
+    /// Outer block with RPG title (squad name inside a hall), followed by
+    /// the filter and sort when they aren't the default
+    fn party_block(&self) -> Block<'_> {
         let title = self
             .hall
             .as_ref()
             .map_or("Your Party", |hall| hall.block_title.as_str());
-        let block = rpg_block(title);
+        match &self.view {
+            Some(view) => rpg_block(&format!("{} · {}", title, view)),
+            None => rpg_block(title),
+        }
+    }
+
+    fn render_party(&self, frame: &mut Frame, area: Rect) {
+        let block = self.party_block();
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        // Render avatar grid
-        let grid = AvatarGrid::new(self.summaries, self.selected);
-        grid.render(frame, inner);
+        if self.summaries.is_empty() {
+            let lines = vec![
+                Line::from(""),
+                Line::from(Span::styled(self.no_match.as_str(), style_muted())),
+            ];
+            frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), inner);
+            return;
+        }
+
+        match self.roster_table() {
+            Some(table) => table.render(frame, inner),
+            None => AvatarGrid::new(self.summaries, self.selected).render(frame, inner),
+        }
     }
 }
 
@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 use super::render_modal;
-use crate::theme::{focused_block, style_header, style_muted, COLOR_SECONDARY};
+use crate::theme::{focused_block, style_header, style_muted, theme};
 
 /// Confirmation dialog for deleting a meeting
 pub struct DeleteConfirmModal<'a> {
@@ -40,7 +40,7 @@ impl<'a> DeleteConfirmModal<'a> {
                 Span::styled("[y]", style_header()),
                 Span::styled(
                     " Delete  ",
-                    ratatui::style::Style::default().fg(COLOR_SECONDARY),
+                    ratatui::style::Style::default().fg(theme().secondary),
                 ),
                 Span::styled("[n]", style_header()),
                 Span::raw(" Cancel"),
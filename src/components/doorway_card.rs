@@ -14,11 +14,11 @@ use ratatui::{
     Frame,
 };
 
-use crate::model::{MoodTrend, OutlierInfo, ReportSummary};
+use crate::model::ReportSummary;
 use crate::theme::{
     format_compact_age, health_bar, mood_color, mood_gauge, overdue_color, overdue_icon,
-    sprites::FaceSprite, style_danger, style_header, style_muted, style_success, style_title,
-    COLOR_TEXT, ICON_WARNING,
+    sprites::FaceSprite, style_danger, style_header, style_muted, style_report, style_success,
+    style_title, theme, ICON_WARNING,
 };
 use crate::utils::abbreviate_name;
 
@@ -73,7 +73,7 @@ impl<'a> DoorwayCard<'a> {
         let face_style = if self.is_selected {
             style_title()
         } else {
-            Style::default().fg(self.summary.color)
+            style_report(self.summary.color)
         };
         // Always the non-overdue render path: the floating-z overdue variant is
         // up to 13 cells wide and would clip in the 9-cell column. Overdue is
@@ -106,7 +106,7 @@ impl<'a> DoorwayCard<'a> {
             style_title()
         } else {
             Style::default()
-                .fg(self.summary.color)
+                .fg(theme().report(self.summary.color))
                 .add_modifier(Modifier::BOLD)
         };
 
@@ -178,7 +178,7 @@ impl<'a> DoorwayCard<'a> {
             Span::styled(health_bar(tm.team_health_score, 8), style_header()),
             Span::styled(
                 format!("{}%", tm.team_health_score),
-                Style::default().fg(COLOR_TEXT),
+                Style::default().fg(theme().text),
             ),
             Span::raw("  "),
         ];
@@ -190,7 +190,7 @@ impl<'a> DoorwayCard<'a> {
                         "{} {}: {} · {}",
                         ICON_WARNING,
                         abbreviate_name(&worst.name),
-                        outlier_label(worst),
+                        worst.label(),
                         format_compact_age(worst.days_since_meeting)
                     ),
                     style_danger().add_modifier(Modifier::BOLD),
@@ -223,15 +223,3 @@ impl<'a> DoorwayCard<'a> {
         frame.render_widget(Paragraph::new(line), area);
     }
 }
-
-/// Label for the named outlier, by severity of signal:
-/// falling trend > low mood value > overdue
-fn outlier_label(outlier: &OutlierInfo) -> String {
-    if outlier.mood_trend == Some(MoodTrend::Falling) {
-        "mood ↘".to_string()
-    } else if let Some(mood) = outlier.recent_mood.filter(|m| *m <= 2) {
-        format!("mood {}", mood)
-    } else {
-        "overdue".to_string()
-    }
-}
@@ -9,10 +9,7 @@ use ratatui::{
 };
 
 use crate::model::Context;
-use crate::theme::{
-    focused_block, mood_color, mood_gauge, style_header, style_muted, COLOR_PRIMARY,
-    COLOR_SECONDARY,
-};
+use crate::theme::{focused_block, mood_color, mood_gauge, style_header, style_muted, theme};
 
 use super::modal::render_modal;
 
@@ -101,7 +98,7 @@ impl<'a> EntryInputModal<'a> {
             .map(|ctx| {
                 let is_selected = *ctx == self.context;
                 let style = if is_selected {
-                    Style::default().fg(COLOR_SECONDARY)
+                    Style::default().fg(theme().secondary)
                 } else {
                     style_muted()
                 };
@@ -124,15 +121,15 @@ impl<'a> EntryInputModal<'a> {
         let cursor = "█";
         let notes_display = if self.notes.is_empty() {
             Line::from(vec![
-                Span::styled(cursor, Style::default().fg(COLOR_PRIMARY)),
+                Span::styled(cursor, Style::default().fg(theme().primary)),
                 Span::styled(" (optional)", style_muted()),
             ])
         } else {
             // Truncate notes if too long
             let display_notes: String = self.notes.chars().take(35).collect();
             Line::from(vec![
-                Span::styled(display_notes, Style::default().fg(COLOR_SECONDARY)),
-                Span::styled(cursor, Style::default().fg(COLOR_PRIMARY)),
+                Span::styled(display_notes, Style::default().fg(theme().secondary)),
+                Span::styled(cursor, Style::default().fg(theme().primary)),
             ])
         };
 
@@ -0,0 +1,83 @@
+//! File history component
+//!
+//! Lists the commits that touched a note or profile on the left and shows
+//! the file as it was at the selected commit on the right.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::HistoryState;
+use crate::theme::{rpg_block, selection_style, style_muted};
+
+/// Width of the revision list
+const REVISION_LIST_WIDTH: u16 = 40;
+
+pub struct FileHistory<'a> {
+    history: &'a HistoryState,
+    selected_index: usize,
+}
+
+impl<'a> FileHistory<'a> {
+    pub fn new(history: &'a HistoryState, selected_index: usize) -> Self {
+        Self {
+            history,
+            selected_index,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(REVISION_LIST_WIDTH), Constraint::Min(20)])
+            .split(area);
+
+        self.render_revisions(frame, chunks[0]);
+        self.render_content(frame, chunks[1]);
+    }
+
+    fn render_revisions(&self, frame: &mut Frame, area: Rect) {
+        let block = rpg_block(&self.history.title);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Two lines per revision; scroll so the selection stays visible
+        let visible = (inner.height as usize / 2).max(1);
+        let skip = (self.selected_index + 1).saturating_sub(visible);
+
+        let lines: Vec<Line> = self
+            .history
+            .revisions
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .take(visible)
+            .flat_map(|(idx, revision)| {
+                let style = selection_style(idx == self.selected_index);
+                [
+                    Line::from(vec![
+                        Span::styled(format!("{} ", revision.date), style),
+                        Span::styled(revision.short_commit().to_string(), style_muted()),
+                    ]),
+                    Line::from(Span::styled(format!("  {}", revision.summary), style)),
+                ]
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect) {
+        let title = self
+            .history
+            .revisions
+            .get(self.selected_index)
+            .map(|r| format!("As of {}", r.short_commit()))
+            .unwrap_or_default();
+        let para = Paragraph::new(self.history.content.as_str()).block(rpg_block(&title));
+        frame.render_widget(para, area);
+    }
+}
@@ -0,0 +1,779 @@
+//! Markdown rendering for the note viewer
+//!
+//! Turns a note's CommonMark into styled lines wrapped to a width. Lists
+//! nest and wrap under their own text, task items show as ☐/☑, code blocks
+//! and tables keep their shape, and quotes carry a bar down the side. Line
+//! breaks inside a paragraph are kept: notes are written a thought per line.
+
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::theme::{style_header, style_muted, theme};
+
+/// Bullets for each level of list nesting, repeating past the last
+const BULLETS: [&str; 3] = ["• ", "◦ ", "▪ "];
+
+/// Text is never wrapped narrower than this, however deep the nesting
+const MIN_TEXT_WIDTH: usize = 10;
+
+/// Columns of a table never shrink below this to fit the width
+const MIN_COLUMN_WIDTH: usize = 3;
+
+/// `source` rendered as lines at most `width` cells wide
+pub fn render_markdown(source: &str, width: u16) -> Vec<Line<'static>> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH;
+    let mut renderer = Renderer::new(width as usize);
+    for event in Parser::new_ext(source, options) {
+        renderer.event(event);
+    }
+    renderer.finish()
+}
+
+/// Lines containing `query`, ignoring case
+pub fn find_matches(lines: &[Line], query: &str) -> Vec<usize> {
+    let query: Vec<char> = query.chars().collect();
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !match_ranges(&line_chars(line), &query).is_empty())
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// `line` with each occurrence of `query` (ignoring case) in `style`
+pub fn highlight_matches(line: Line<'static>, query: &str, style: Style) -> Line<'static> {
+    let query: Vec<char> = query.chars().collect();
+    let ranges = match_ranges(&line_chars(&line), &query);
+    if ranges.is_empty() {
+        return line;
+    }
+
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for span in line.spans {
+        // Cut the span wherever a match starts or ends inside it
+        let chars: Vec<char> = span.content.chars().collect();
+        let end = pos + chars.len();
+        let mut start = 0;
+        while start < chars.len() {
+            let at = pos + start;
+            let in_match = ranges.iter().find(|(from, to)| *from <= at && at < *to);
+            let until = match in_match {
+                Some((_, to)) => *to,
+                None => ranges
+                    .iter()
+                    .map(|(from, _)| *from)
+                    .find(|from| *from > at)
+                    .unwrap_or(end),
+            }
+            .min(end);
+            let text: String = chars[start..until - pos].iter().collect();
+            let piece_style = match in_match {
+                Some(_) => span.style.patch(style),
+                None => span.style,
+            };
+            spans.push(Span::styled(text, piece_style));
+            start = until - pos;
+        }
+        pos = end;
+    }
+    Line::from(spans).style(line.style)
+}
+
+fn line_chars(line: &Line) -> Vec<char> {
+    line.spans
+        .iter()
+        .flat_map(|span| span.content.chars())
+        .collect()
+}
+
+/// Char ranges where `query` occurs, ignoring case, without overlaps
+fn match_ranges(chars: &[char], query: &[char]) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    if query.is_empty() {
+        return ranges;
+    }
+    let same = |a: char, b: char| a == b || a.to_lowercase().eq(b.to_lowercase());
+    let mut start = 0;
+    while start + query.len() <= chars.len() {
+        let window = &chars[start..start + query.len()];
+        if window.iter().zip(query).all(|(a, b)| same(*a, *b)) {
+            ranges.push((start, start + query.len()));
+            start += query.len();
+        } else {
+            start += 1;
+        }
+    }
+    ranges
+}
+
+/// What a line's left edge carries for the block it's in
+enum Container {
+    /// A bar down the side of a block quote
+    Quote,
+    /// A list item: its marker on the first line, then blank indent
+    Item {
+        marker: Option<Span<'static>>,
+        width: usize,
+    },
+}
+
+/// A table being collected, rendered once its last row is in
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    /// Whether the first row is the heading
+    has_head: bool,
+}
+
+/// A run of text in one style
+type Run = (String, Style);
+
+struct Renderer {
+    width: usize,
+    lines: Vec<Line<'static>>,
+    containers: Vec<Container>,
+    /// Next number of each open list, innermost last (None for bullets)
+    lists: Vec<Option<u64>>,
+    /// The current block's text, one entry per line break
+    text: Vec<Vec<Run>>,
+    /// Inline styles in force, outermost first
+    styles: Vec<Style>,
+    /// Style of the whole block, for headings
+    block_style: Style,
+    /// Destinations of open links, with the text shown for them so far
+    links: Vec<(String, String)>,
+    /// Text of the fenced or indented code block being read
+    code: Option<String>,
+    table: Option<Table>,
+    /// A blank line goes before the next block
+    gap: bool,
+}
+
+impl Renderer {
+    fn new(width: usize) -> Self {
+        Self {
+            width,
+            lines: Vec::new(),
+            containers: Vec::new(),
+            lists: Vec::new(),
+            text: vec![Vec::new()],
+            styles: Vec::new(),
+            block_style: Style::default(),
+            links: Vec::new(),
+            code: None,
+            table: None,
+            gap: false,
+        }
+    }
+
+    fn finish(mut self) -> Vec<Line<'static>> {
+        self.flush_text();
+        while self.lines.last().is_some_and(|line| line.width() == 0) {
+            self.lines.pop();
+        }
+        self.lines
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => match &mut self.code {
+                Some(code) => code.push_str(&text),
+                None => self.push_text(&text, self.style()),
+            },
+            Event::Code(code) => {
+                let style = self.style().fg(theme().accent);
+                self.push_text(&code, style);
+            }
+            // Shown as written; a block of it keeps its lines
+            Event::Html(html) => {
+                for line in html.lines() {
+                    if self.text.last().is_some_and(|runs| !runs.is_empty()) {
+                        self.text.push(Vec::new());
+                    }
+                    self.push_text(line, style_muted());
+                }
+            }
+            Event::InlineHtml(html) => self.push_text(&html, style_muted()),
+            Event::SoftBreak | Event::HardBreak => self.text.push(Vec::new()),
+            Event::Rule => {
+                self.start_block();
+                let width = self.text_width();
+                let rule = Span::styled("─".repeat(width), style_muted());
+                self.push_line(vec![rule]);
+                self.gap = true;
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = if checked {
+                    Span::styled("☑ ", Style::default().fg(theme().primary))
+                } else {
+                    Span::styled("☐ ", style_muted())
+                };
+                if let Some(Container::Item {
+                    marker: slot,
+                    width,
+                }) = self.containers.last_mut()
+                {
+                    *slot = Some(marker);
+                    *width = 2;
+                }
+            }
+            Event::FootnoteReference(label) => {
+                self.push_text(&format!("[^{}]", label), style_muted());
+            }
+            Event::InlineMath(math) | Event::DisplayMath(math) => {
+                self.push_text(&math, self.style());
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::HtmlBlock => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                self.block_style = match level {
+                    HeadingLevel::H1 => style_header(),
+                    HeadingLevel::H2 => Style::default().fg(theme().secondary),
+                    _ => Style::default()
+                        .fg(theme().text)
+                        .add_modifier(Modifier::BOLD),
+                };
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.containers.push(Container::Quote);
+            }
+            Tag::CodeBlock(kind) => {
+                self.start_block();
+                if let CodeBlockKind::Fenced(lang) = kind {
+                    if !lang.is_empty() {
+                        self.push_line(vec![Span::styled(lang.to_string(), style_muted())]);
+                    }
+                }
+                self.code = Some(String::new());
+            }
+            Tag::List(start) => {
+                // A list inside an item follows straight on from its text
+                if self.lists.is_empty() {
+                    self.start_block();
+                } else {
+                    self.flush_text();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush_text();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        let marker = format!("{}. ", number);
+                        *number += 1;
+                        marker
+                    }
+                    _ => BULLETS[depth % BULLETS.len()].to_string(),
+                };
+                let width = marker.width();
+                self.containers.push(Container::Item {
+                    marker: Some(Span::styled(marker, style_muted())),
+                    width,
+                });
+            }
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    has_head: false,
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => self.text = vec![Vec::new()],
+            Tag::Emphasis => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.links.push((dest_url.to_string(), String::new()));
+                self.styles.push(
+                    Style::default()
+                        .fg(theme().primary)
+                        .add_modifier(Modifier::UNDERLINED),
+                );
+            }
+            Tag::Image { .. } => {
+                self.push_text("[image: ", style_muted());
+                self.styles.push(style_muted());
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::HtmlBlock => {
+                self.flush_text();
+                self.gap = true;
+            }
+            TagEnd::Heading(_) => {
+                self.flush_text();
+                self.block_style = Style::default();
+                self.gap = true;
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_text();
+                self.containers.pop();
+                self.gap = true;
+            }
+            TagEnd::CodeBlock => {
+                let code = self.code.take().unwrap_or_default();
+                self.push_code(&code);
+                self.gap = true;
+            }
+            TagEnd::List(_) => {
+                self.flush_text();
+                self.lists.pop();
+                self.gap = true;
+            }
+            TagEnd::Item => {
+                self.flush_text();
+                // An empty item still shows its marker
+                if let Some(Container::Item {
+                    marker: Some(_), ..
+                }) = self.containers.last()
+                {
+                    self.push_line(Vec::new());
+                }
+                self.containers.pop();
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.push_table(table);
+                }
+                self.text = vec![Vec::new()];
+                self.gap = true;
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.has_head = true;
+                }
+            }
+            TagEnd::TableCell => {
+                let cell: String = self
+                    .text
+                    .iter()
+                    .flatten()
+                    .map(|(text, _)| text.as_str())
+                    .collect();
+                if let Some(row) = self.table.as_mut().and_then(|t| t.rows.last_mut()) {
+                    row.push(cell.trim().to_string());
+                }
+                self.text = vec![Vec::new()];
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link => {
+                self.styles.pop();
+                if let Some((dest, shown)) = self.links.pop() {
+                    // Autolinks already show where they go
+                    let bare = dest.strip_prefix("mailto:").unwrap_or(&dest);
+                    if !dest.is_empty() && shown != dest && shown != bare {
+                        self.push_text(&format!(" ({})", dest), style_muted());
+                    }
+                }
+            }
+            TagEnd::Image => {
+                self.styles.pop();
+                self.push_text("]", style_muted());
+            }
+            _ => {}
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.styles
+            .iter()
+            .fold(self.block_style, |style, inline| style.patch(*inline))
+    }
+
+    fn push_text(&mut self, text: &str, style: Style) {
+        if let Some((_, shown)) = self.links.last_mut() {
+            shown.push_str(text);
+        }
+        if let Some(line) = self.text.last_mut() {
+            line.push((text.to_string(), style));
+        }
+    }
+
+    /// Close off whatever was before a new block, with a blank line when
+    /// the last block asked for one
+    fn start_block(&mut self) {
+        self.flush_text();
+        if self.gap && !self.lines.is_empty() {
+            let (prefix, _) = self.prefix(false);
+            self.lines.push(Line::from(prefix));
+        }
+        self.gap = false;
+    }
+
+    /// Wrap the text collected so far into lines
+    fn flush_text(&mut self) {
+        let text = std::mem::replace(&mut self.text, vec![Vec::new()]);
+        if text
+            .iter()
+            .all(|line| line.iter().all(|(t, _)| t.is_empty()))
+        {
+            return;
+        }
+        let width = self.text_width();
+        for line in text {
+            for wrapped in wrap_runs(&line, width) {
+                let spans = wrapped
+                    .into_iter()
+                    .map(|(text, style)| Span::styled(text, style))
+                    .collect();
+                self.push_line(spans);
+            }
+        }
+    }
+
+    fn push_code(&mut self, code: &str) {
+        let style = Style::default().fg(theme().accent);
+        let width = self.text_width().saturating_sub(2).max(1);
+        for line in code.trim_end_matches('\n').lines() {
+            let chars: Vec<char> = line.chars().collect();
+            for piece in split_at_width(&chars, width) {
+                let text: String = piece.iter().collect();
+                self.push_line(vec![Span::raw("  "), Span::styled(text, style)]);
+            }
+        }
+    }
+
+    fn push_table(&mut self, table: Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+        let mut widths = vec![MIN_COLUMN_WIDTH; columns];
+        for row in &table.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.width());
+            }
+        }
+        // Take from the widest column until the table fits
+        let room = self.text_width().saturating_sub(3 * (columns - 1));
+        while widths.iter().sum::<usize>() > room {
+            let widest = widths
+                .iter_mut()
+                .max_by_key(|width| **width)
+                .filter(|width| **width > MIN_COLUMN_WIDTH);
+            match widest {
+                Some(width) => *width -= 1,
+                None => break,
+            }
+        }
+
+        let separator = || Span::styled(" │ ", style_muted());
+        for (idx, row) in table.rows.iter().enumerate() {
+            let is_head = table.has_head && idx == 0;
+            let style = if is_head {
+                style_header()
+            } else {
+                Style::default()
+            };
+            let mut spans = Vec::new();
+            for (column, width) in widths.iter().enumerate() {
+                if column > 0 {
+                    spans.push(separator());
+                }
+                let cell = row.get(column).map_or("", String::as_str);
+                let alignment = table.alignments.get(column).copied();
+                spans.push(Span::styled(align(cell, *width, alignment), style));
+            }
+            self.push_line(spans);
+            if is_head {
+                let rule = widths
+                    .iter()
+                    .map(|width| "─".repeat(*width))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                self.push_line(vec![Span::styled(rule, style_muted())]);
+            }
+        }
+    }
+
+    /// Room left for text once the quote bars and list indents are drawn
+    fn text_width(&self) -> usize {
+        let indent: usize = self
+            .containers
+            .iter()
+            .map(|container| match container {
+                Container::Quote => 2,
+                Container::Item { width, .. } => *width,
+            })
+            .sum();
+        self.width.saturating_sub(indent).max(MIN_TEXT_WIDTH)
+    }
+
+    /// The left edge of the next line; list markers are used up unless it's
+    /// a blank line
+    fn prefix(&mut self, take_markers: bool) -> (Vec<Span<'static>>, usize) {
+        let mut spans = Vec::new();
+        let mut width = 0;
+        for container in &mut self.containers {
+            let span = match container {
+                Container::Quote => Span::styled("│ ", style_muted()),
+                Container::Item { marker, width } => match marker.take() {
+                    Some(marker) if take_markers => marker,
+                    Some(kept) => {
+                        *marker = Some(kept);
+                        Span::raw(" ".repeat(*width))
+                    }
+                    None => Span::raw(" ".repeat(*width)),
+                },
+            };
+            width += span.width();
+            spans.push(span);
+        }
+        (spans, width)
+    }
+
+    fn push_line(&mut self, spans: Vec<Span<'static>>) {
+        let (mut line, _) = self.prefix(true);
+        line.extend(spans);
+        self.lines.push(Line::from(line));
+    }
+}
+
+/// `runs` broken into lines of at most `width` cells, between words where
+/// possible
+fn wrap_runs(runs: &[Run], width: usize) -> Vec<Vec<Run>> {
+    let mut lines: Vec<Vec<Run>> = vec![Vec::new()];
+    let mut column = 0;
+    let mut space: Option<Run> = None;
+    let mut word: Vec<Run> = Vec::new();
+
+    let mut place_word =
+        |word: &mut Vec<Run>, space: &mut Option<Run>, lines: &mut Vec<Vec<Run>>| {
+            let word_width: usize = word.iter().map(|(text, _)| text.width()).sum();
+            if word_width == 0 {
+                return;
+            }
+            let space_width = space.as_ref().map_or(0, |(text, _)| text.width());
+            if column > 0 && column + space_width + word_width > width {
+                lines.push(Vec::new());
+                column = 0;
+            } else if column > 0 {
+                if let Some((spaces, style)) = space.take() {
+                    let line = lines.last_mut().unwrap();
+                    spaces.chars().for_each(|c| push_char(line, c, style));
+                    column += space_width;
+                }
+            }
+            *space = None;
+            for (text, style) in word.drain(..) {
+                for c in text.chars() {
+                    let c_width = c.width().unwrap_or(0);
+                    if column + c_width > width && column > 0 {
+                        lines.push(Vec::new());
+                        column = 0;
+                    }
+                    push_char(lines.last_mut().unwrap(), c, style);
+                    column += c_width;
+                }
+            }
+        };
+
+    for (text, style) in runs {
+        for c in text.chars() {
+            if c.is_whitespace() {
+                place_word(&mut word, &mut space, &mut lines);
+                match &mut space {
+                    Some((spaces, _)) => spaces.push(c),
+                    None => space = Some((c.to_string(), *style)),
+                }
+            } else {
+                push_char(&mut word, c, *style);
+            }
+        }
+    }
+    place_word(&mut word, &mut space, &mut lines);
+    lines
+}
+
+/// Add `c` to the last run if it's in the same style, else start a run
+fn push_char(runs: &mut Vec<Run>, c: char, style: Style) {
+    match runs.last_mut() {
+        Some((text, last)) if *last == style => text.push(c),
+        _ => runs.push((c.to_string(), style)),
+    }
+}
+
+/// `chars` cut into pieces at most `width` cells wide
+fn split_at_width(chars: &[char], width: usize) -> Vec<&[char]> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut column = 0;
+    for (idx, c) in chars.iter().enumerate() {
+        let c_width = c.width().unwrap_or(0);
+        if column + c_width > width && idx > start {
+            pieces.push(&chars[start..idx]);
+            start = idx;
+            column = 0;
+        }
+        column += c_width;
+    }
+    pieces.push(&chars[start..]);
+    pieces
+}
+
+/// `text` fitted to `width` cells: padded per the column's alignment, or
+/// cut short with an ellipsis
+fn align(text: &str, width: usize, alignment: Option<Alignment>) -> String {
+    if text.width() > width {
+        let mut cut = String::new();
+        let mut column = 0;
+        for c in text.chars() {
+            let c_width = c.width().unwrap_or(0);
+            if column + c_width + 1 > width {
+                break;
+            }
+            cut.push(c);
+            column += c_width;
+        }
+        cut.push('…');
+        return format!("{}{}", cut, " ".repeat(width.saturating_sub(column + 1)));
+    }
+    let room = width - text.width();
+    let (left, right) = match alignment {
+        Some(Alignment::Right) => (room, 0),
+        Some(Alignment::Center) => (room / 2, room - room / 2),
+        _ => (0, room),
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(source: &str, width: u16) -> Vec<String> {
+        render_markdown(source, width)
+            .iter()
+            .map(|line| line.to_string().trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_lists_nest_and_wrap_under_their_text() {
+        let source = "- Career growth and the\n  promo packet\n  - [ ] draft the doc\n  - [x] pick a sponsor\n1. first\n2. second";
+        assert_eq!(
+            rendered(source, 20),
+            [
+                "• Career growth and",
+                "  the",
+                "  promo packet",
+                "  ☐ draft the doc",
+                "  ☑ pick a sponsor",
+                "",
+                "1. first",
+                "2. second",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blocks_keep_their_shape() {
+        let source = "# Notes\n\n> Said the team is\n> stretched\n\n```rust\nlet x = 1;\n```\n\nSee [the doc](https://example.com) and <https://x.io>.";
+        assert_eq!(
+            rendered(source, 40),
+            [
+                "Notes",
+                "",
+                "│ Said the team is",
+                "│ stretched",
+                "",
+                "rust",
+                "  let x = 1;",
+                "",
+                "See the doc (https://example.com) and",
+                "https://x.io.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tables_line_up_and_fit() {
+        let source = "| Goal | Status |\n|:-----|-------:|\n| Ship v2 | 80% |\n| Mentor | done |";
+        assert_eq!(
+            rendered(source, 40),
+            [
+                "Goal    │ Status",
+                "────────┼───────",
+                "Ship v2 │    80%",
+                "Mentor  │   done",
+            ]
+        );
+        assert_eq!(
+            rendered(
+                "| a | b |\n|---|---|\n| a long cell | another long one |",
+                20
+            )[2],
+            "a long c… │ another…"
+        );
+    }
+
+    #[test]
+    fn test_inline_styles() {
+        let lines = render_markdown("**bold** and *it* and `code`", 40);
+        let styled: Vec<(&str, Style)> = lines[0]
+            .spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style))
+            .collect();
+        assert!(styled[0].1.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(styled[0].0, "bold");
+        assert!(styled
+            .iter()
+            .any(|(text, style)| *text == "it" && style.add_modifier.contains(Modifier::ITALIC)));
+        assert!(styled
+            .iter()
+            .any(|(text, style)| *text == "code" && style.fg == Some(theme().accent)));
+    }
+
+    #[test]
+    fn test_search_matches_ignore_case() {
+        let lines = render_markdown("Roadmap\n\nthe ROADMAP slipped\n\nnothing", 40);
+        assert_eq!(find_matches(&lines, "roadmap"), [0, 2]);
+        assert!(find_matches(&lines, "").is_empty());
+
+        let marked = Style::default().add_modifier(Modifier::REVERSED);
+        let line = highlight_matches(lines[2].clone(), "roadmap", marked);
+        let pieces: Vec<(&str, bool)> = line
+            .spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style == marked))
+            .collect();
+        assert_eq!(
+            pieces,
+            [("the ", false), ("ROADMAP", true), (" slipped", false)]
+        );
+    }
+}
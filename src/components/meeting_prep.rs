@@ -0,0 +1,214 @@
+//! Meeting prep component
+//!
+//! One screen to read before a 1-on-1: the last two notes on the left, and on
+//! the right open action items, the mood trend, what was observed since,
+//! dates coming up, goals and (for managers) the squad.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use super::markdown::render_markdown;
+use crate::model::{MeetingPrep, MoodTrend, PrepNote, UPCOMING_DAYS};
+use crate::theme::{mood_color, rpg_block, style_header, style_muted, style_title, theme};
+
+pub struct MeetingPrepPanel<'a> {
+    prep: &'a MeetingPrep,
+}
+
+impl<'a> MeetingPrepPanel<'a> {
+    pub fn new(prep: &'a MeetingPrep) -> Self {
+        Self { prep }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let last = match self.prep.last_meeting {
+            Some(date) => format!("last 1-on-1 {}", date.format("%b %d")),
+            None => "no 1-on-1s yet".to_string(),
+        };
+        let title = format!("Meeting Prep · {} · {}", self.prep.name, last);
+        let block = rpg_block(&title);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(inner);
+
+        self.render_notes(frame, columns[0]);
+        let block = panel_block(" ⚔ BEFORE YOU START ");
+        let agenda = block.inner(columns[1]);
+        frame.render_widget(block, columns[1]);
+        frame.render_widget(
+            Paragraph::new(self.agenda_lines()).wrap(Wrap { trim: false }),
+            agenda,
+        );
+    }
+
+    /// The latest notes, stacked, newest on top
+    fn render_notes(&self, frame: &mut Frame, area: Rect) {
+        let notes = &self.prep.recent_notes;
+        if notes.is_empty() {
+            let block = panel_block(" ☰ LAST 1-ON-1 ");
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            frame.render_widget(
+                Paragraph::new(Span::styled("No 1-on-1 notes yet", style_muted())),
+                inner,
+            );
+            return;
+        }
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, notes.len() as u32); notes.len()])
+            .split(area);
+        for (note, row) in notes.iter().zip(rows.iter()) {
+            render_note(frame, *row, note);
+        }
+    }
+
+    fn agenda_lines(&self) -> Vec<Line<'static>> {
+        let prep = self.prep;
+        let mut lines = Vec::new();
+
+        section(&mut lines, "ACTION ITEMS");
+        if prep.action_items.is_empty() {
+            lines.push(empty("None open"));
+        }
+        for item in &prep.action_items {
+            lines.push(Line::from(vec![
+                Span::raw(format!("☐ {} ", item.text)),
+                Span::styled(item.date.format("%b %d").to_string(), style_muted()),
+            ]));
+        }
+
+        section(&mut lines, "MOOD");
+        if prep.moods.is_empty() {
+            lines.push(empty("No readings"));
+        } else {
+            let mut spans: Vec<Span> = prep
+                .moods
+                .iter()
+                .map(|&mood| {
+                    Span::styled(format!("{} ", mood), Style::default().fg(mood_color(mood)))
+                })
+                .collect();
+            match prep.mood_trend {
+                Some(MoodTrend::Rising) => spans.push(Span::styled(
+                    " ↗ rising",
+                    Style::default().fg(theme().rising),
+                )),
+                Some(MoodTrend::Falling) => spans.push(Span::styled(
+                    " ↘ falling",
+                    Style::default().fg(theme().falling),
+                )),
+                Some(MoodTrend::Stable) => spans.push(Span::styled(" steady", style_muted())),
+                None => {}
+            }
+            lines.push(Line::from(spans));
+        }
+
+        section(&mut lines, "SINCE LAST 1-ON-1");
+        if prep.observations.is_empty() {
+            lines.push(empty("Nothing logged"));
+        }
+        for observation in &prep.observations {
+            let mut spans = vec![
+                Span::styled(observation.date.format("%b %d ").to_string(), style_muted()),
+                Span::raw(format!("{:<8}", observation.context.as_str())),
+            ];
+            if let Some(mood) = observation.mood {
+                spans.push(Span::styled(
+                    format!("{} ", mood),
+                    Style::default().fg(mood_color(mood)),
+                ));
+            }
+            if let Some(summary) = &observation.summary {
+                spans.push(Span::raw(summary.clone()));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        section(&mut lines, "COMING UP");
+        if prep.upcoming_dates.is_empty() {
+            lines.push(empty(&format!(
+                "Nothing in the next {} days",
+                UPCOMING_DAYS
+            )));
+        }
+        for date in &prep.upcoming_dates {
+            lines.push(Line::from(vec![
+                Span::styled(date.date.format("%b %d ").to_string(), style_muted()),
+                Span::styled(date.label(), style_title()),
+                Span::styled(format!(" · {}", date.when()), style_muted()),
+            ]));
+        }
+
+        section(&mut lines, "GOALS");
+        if prep.goals.is_empty() {
+            lines.push(empty("None under ## Goals in their profile"));
+        }
+        for goal in &prep.goals {
+            lines.push(Line::raw(format!("◆ {}", goal)));
+        }
+
+        if let Some(outliers) = &prep.squad_outliers {
+            section(&mut lines, "SQUAD");
+            if outliers.is_empty() {
+                lines.push(empty("All well"));
+            }
+            for outlier in outliers {
+                lines.push(Line::from(vec![
+                    Span::raw(format!("{} ", outlier.name)),
+                    Span::styled(outlier.label(), Style::default().fg(theme().warning)),
+                ]));
+            }
+        }
+
+        lines
+    }
+}
+
+fn render_note(frame: &mut Frame, area: Rect, note: &PrepNote) {
+    let mut title = format!(" ☰ {} ", note.date.format("%a %b %d, %Y"));
+    if let Some(mood) = note.mood {
+        title.push_str(&format!("· mood {} ", mood));
+    }
+    let block = panel_block(&title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let lines = if note.content.is_empty() {
+        vec![empty("No notes")]
+    } else {
+        render_markdown(&note.content, inner.width)
+    };
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// A section heading, spaced from the one before
+fn section(lines: &mut Vec<Line<'static>>, title: &'static str) {
+    if !lines.is_empty() {
+        lines.push(Line::default());
+    }
+    lines.push(Line::from(Span::styled(title, style_header())));
+}
+
+fn empty(text: &str) -> Line<'static> {
+    Line::from(Span::styled(text.to_string(), style_muted()))
+}
+
+fn panel_block(title: &str) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme().muted))
+        .title(title)
+        .title_style(style_header())
+}
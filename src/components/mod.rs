@@ -7,10 +7,17 @@ pub mod dashboard;
 pub mod delete_modal;
 pub mod doorway_card;
 pub mod entry_modal;
+pub mod file_history;
+pub mod markdown;
+pub mod meeting_prep;
 pub mod modal;
+pub mod mood_analytics;
 pub mod mood_chart;
+pub mod mood_heatmap;
+pub mod note_editor;
 pub mod note_viewer;
 pub mod report_detail;
+pub mod roster_table;
 pub mod status_bar;
 
 // Avatar and card components
@@ -23,10 +30,19 @@ pub use dashboard::{render_empty_state, render_vibe_manager_title, Dashboard, Ha
 // Modal components
 pub use delete_modal::DeleteConfirmModal;
 pub use entry_modal::EntryInputModal;
-pub use modal::{render_modal, HelpModal, NewReportField, NewReportModal, NewReportState};
+pub use modal::{
+    render_modal, CommandPaletteModal, HelpModal, MigrateConfirmModal, NewReportField,
+    NewReportModal, NewReportState, WarningsModal,
+};
 
 // Other components
-pub use mood_chart::render_mood_chart_with_axis;
+pub use file_history::FileHistory;
+pub use meeting_prep::MeetingPrepPanel;
+pub use mood_analytics::MoodAnalyticsPanel;
+pub use mood_chart::{render_mood_chart_with_axis, render_mood_timeline};
+pub use mood_heatmap::HeatmapGrid;
+pub use note_editor::NoteEditor;
 pub use note_viewer::NoteViewer;
 pub use report_detail::ReportDetail;
+pub use roster_table::RosterTable;
 pub use status_bar::StatusBar;
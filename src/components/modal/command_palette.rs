@@ -0,0 +1,98 @@
+//! Command palette modal
+//!
+//! The query on top, then the matching commands with their keys (or, for
+//! people, their halls) on the right. The person finder is the same modal
+//! with only people in it.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::{CommandPaletteState, PaletteKind};
+use crate::theme::{focused_block, style_highlight, style_muted, theme};
+
+/// Command palette listing the commands matching the query
+pub struct CommandPaletteModal<'a> {
+    state: &'a CommandPaletteState,
+}
+
+impl<'a> CommandPaletteModal<'a> {
+    pub fn new(state: &'a CommandPaletteState) -> Self {
+        Self { state }
+    }
+
+    /// Render the command palette
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = area.width.saturating_sub(4).min(72);
+        let height = area.height.saturating_sub(4).min(20);
+        let modal_area = super::render_modal(frame, area, width, height);
+
+        let (title, no_matches) = match self.state.kind {
+            PaletteKind::Commands => ("Commands", "No matching commands"),
+            PaletteKind::People => ("Find someone", "Nobody matches"),
+        };
+        let block = focused_block(title);
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(1),
+            ])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("> ", Style::default().fg(theme().secondary)),
+                Span::raw(self.state.query.as_str()),
+                Span::styled("█", Style::default().fg(theme().secondary)),
+            ])),
+            rows[0],
+        );
+
+        let matches = self.state.matches();
+        if matches.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Span::styled(no_matches, style_muted())),
+                rows[2],
+            );
+            return;
+        }
+
+        // Keep the selection visible
+        let visible = rows[2].height as usize;
+        let skip = (self.state.selected + 1).saturating_sub(visible);
+        let row_width = rows[2].width as usize;
+        let lines: Vec<Line> = matches
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .take(visible)
+            .map(|(idx, entry)| {
+                let label = format!(" {}", entry.label);
+                let used = label.chars().count() + entry.detail.chars().count() + 1;
+                let padding = " ".repeat(row_width.saturating_sub(used).max(1));
+                if idx == self.state.selected {
+                    Line::styled(
+                        format!("{}{}{} ", label, padding, entry.detail),
+                        style_highlight(),
+                    )
+                } else {
+                    Line::from(vec![
+                        Span::raw(label),
+                        Span::raw(padding),
+                        Span::styled(format!("{} ", entry.detail), style_muted()),
+                    ])
+                }
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), rows[2]);
+    }
+}
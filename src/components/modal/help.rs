@@ -1,86 +1,113 @@
 //! Help modal
 //!
-//! Modal dialog displaying keyboard shortcuts and usage help.
+//! Modal dialog listing the active keybindings, generated from the
+//! [`Keymap`] so rebound keys show up as they are.
 
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::Style,
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 
-use crate::theme::{focused_block, style_header, style_muted, COLOR_SECONDARY};
+use crate::app::keymap::{Action, Scope};
+use crate::app::Keymap;
+use crate::theme::{focused_block, style_header, style_muted, theme};
+
+/// Sections per column: heading and the scope whose keys it lists
+const COLUMNS: [&[(&str, Scope)]; 2] = [
+    &[
+        ("Party View", Scope::Dashboard),
+        ("Anywhere", Scope::Global),
+    ],
+    &[
+        ("Member Details", Scope::ReportDetail),
+        ("Meeting Notes", Scope::NoteViewer),
+    ],
+];
+
+/// Below this width the columns are stacked
+const TWO_COLUMN_WIDTH: u16 = 96;
 
 /// Help modal showing keyboard shortcuts
 pub struct HelpModal;
 
 impl HelpModal {
     /// Render the help modal
-    pub fn render(frame: &mut Frame, area: Rect) {
-        let modal_area = super::render_modal(frame, area, 60, 20);
+    pub fn render(frame: &mut Frame, area: Rect, keymap: &Keymap) {
+        let columns: Vec<Vec<Line>> = COLUMNS
+            .iter()
+            .map(|sections| Self::column(keymap, sections))
+            .collect();
+        let two_columns = area.width >= TWO_COLUMN_WIDTH + 4;
+        let body_height = if two_columns {
+            columns.iter().map(Vec::len).max().unwrap_or(0)
+        } else {
+            columns.iter().map(Vec::len).sum()
+        };
+        let width = if two_columns { TWO_COLUMN_WIDTH } else { 60 };
+        // Body, footer and borders
+        let height = body_height as u16 + 3;
+        let modal_area = super::render_modal(frame, area, width, height);
 
         let block = focused_block("Help");
         let inner = block.inner(modal_area);
         frame.render_widget(block, modal_area);
 
-        let sections = vec![
-            (
-                "Party View",
-                vec![
-                    ("h/l or ←/→", "Select party member"),
-                    ("j/k or ↑/↓", "Navigate grid"),
-                    ("Enter/Space", "View member details"),
-                    ("n", "Recruit new member"),
-                    ("g/G", "Jump to first/last"),
-                    ("r", "Refresh data"),
-                    ("q", "Quit"),
-                ],
-            ),
-            (
-                "Member Details",
-                vec![
-                    ("n", "New 1-on-1 meeting"),
-                    ("m", "Record mood observation"),
-                    ("Enter", "View entry notes"),
-                    ("Del", "Delete entry"),
-                    ("Esc", "Back to party view"),
-                ],
-            ),
-            (
-                "Meeting Notes",
-                vec![
-                    ("←↑↓→", "Move cursor"),
-                    ("Home/End", "Start/end of line"),
-                    ("Del", "Delete character"),
-                    ("Ctrl+S", "Save note"),
-                    ("F1-F5", "Set mood (1-5)"),
-                    ("Esc", "Back"),
-                ],
-            ),
-        ];
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        if two_columns {
+            let halves = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(rows[0]);
+            for (lines, half) in columns.into_iter().zip(halves.iter()) {
+                frame.render_widget(Paragraph::new(lines), *half);
+            }
+        } else {
+            let lines: Vec<Line> = columns.into_iter().flatten().collect();
+            frame.render_widget(Paragraph::new(lines), rows[0]);
+        }
+
+        let close = match keymap.keys(Scope::Help, Action::CloseHelp).as_slice() {
+            [] => "Esc".to_string(),
+            [only] => only.clone(),
+            [rest @ .., last] => format!("{} or {}", rest.join(", "), last),
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                format!("Press {} to close", close),
+                style_muted(),
+            ))),
+            rows[1],
+        );
+    }
 
+    fn column<'a>(keymap: &Keymap, sections: &[(&'a str, Scope)]) -> Vec<Line<'a>> {
         let mut lines = Vec::new();
-        for (section, bindings) in sections {
-            lines.push(Line::from(Span::styled(section, style_header())));
-            for (key, desc) in bindings {
+        for (heading, scope) in sections {
+            let rows = keymap.help_rows(*scope);
+            let key_width = rows
+                .iter()
+                .map(|(keys, _)| keys.chars().count())
+                .max()
+                .unwrap_or(0)
+                .clamp(8, 16);
+            lines.push(Line::from(Span::styled(*heading, style_header())));
+            for (keys, description) in rows {
                 lines.push(Line::from(vec![
                     Span::styled(
-                        format!("  {:12}", key),
-                        Style::default().fg(COLOR_SECONDARY),
+                        format!("  {:width$}  ", keys, width = key_width),
+                        Style::default().fg(theme().secondary),
                     ),
-                    Span::raw(desc),
+                    Span::raw(description),
                 ]));
             }
             lines.push(Line::from(""));
         }
-
-        lines.push(Line::from(Span::styled(
-            "Press ? or Esc to close",
-            style_muted(),
-        )));
-
-        let para = Paragraph::new(lines);
-        frame.render_widget(para, inner);
+        lines
     }
 }
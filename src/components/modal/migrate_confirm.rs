@@ -0,0 +1,105 @@
+//! Migration confirmation modal
+//!
+//! Lists what `vibe-manager migrate --dry-run` would print before the
+//! palette's migrate command moves anything.
+
+use std::path::Path;
+
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::storage::migrate::MigrationStep;
+use crate::theme::{focused_block, style_danger, style_header, style_muted, theme};
+
+/// Confirmation dialog for the pending format migrations
+pub struct MigrateConfirmModal<'a> {
+    steps: &'a [MigrationStep],
+    root: &'a Path,
+}
+
+impl<'a> MigrateConfirmModal<'a> {
+    pub fn new(steps: &'a [MigrationStep], root: &'a Path) -> Self {
+        Self { steps, root }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = area.width.saturating_sub(4).min(90);
+        let height = area.height.saturating_sub(4).min(20);
+        let modal_area = super::render_modal(frame, area, width, height);
+
+        let block = focused_block("Migrate Workspace");
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        // Room for the changes between the step headings and the footer
+        let footer = 3;
+        let budget = (inner.height as usize).saturating_sub(footer + self.steps.len());
+        let prefix = format!("{}/", self.root.display());
+        let conflicted = self.steps.iter().any(|step| !step.conflicts.is_empty());
+
+        let mut lines = Vec::new();
+        let mut shown = 0;
+        let mut hidden = 0;
+        for step in self.steps {
+            lines.push(Line::from(Span::styled(
+                format!("Version {}: {}", step.to, step.description),
+                style_header(),
+            )));
+            let details = step
+                .conflicts
+                .iter()
+                .map(|conflict| {
+                    Line::from(Span::styled(format!("  ✗ {}", conflict), style_danger()))
+                })
+                .chain(step.changes.iter().map(|change| {
+                    Line::raw(format!("  {}", change.to_string().replace(&prefix, "")))
+                }));
+            for line in details {
+                if shown < budget {
+                    lines.push(line);
+                    shown += 1;
+                } else {
+                    hidden += 1;
+                }
+            }
+        }
+        if hidden > 0 {
+            lines.push(Line::from(Span::styled(
+                format!("  … and {} more", hidden),
+                style_muted(),
+            )));
+        }
+
+        lines.push(Line::from(""));
+        if conflicted {
+            lines.push(Line::from(Span::styled(
+                "Resolve the conflicts above before migrating",
+                style_danger(),
+            )));
+            lines.push(Line::from(vec![
+                Span::styled("[n]", style_header()),
+                Span::raw(" Close"),
+            ]));
+        } else {
+            lines.push(Line::from(Span::styled(
+                "The workspace is backed up first.",
+                style_muted(),
+            )));
+            lines.push(Line::from(vec![
+                Span::styled("[y]", style_header()),
+                Span::styled(
+                    " Migrate  ",
+                    ratatui::style::Style::default().fg(theme().secondary),
+                ),
+                Span::styled("[n]", style_header()),
+                Span::raw(" Cancel"),
+            ]));
+        }
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
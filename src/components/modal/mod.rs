@@ -3,8 +3,11 @@
 //! This module provides modal dialogs for user input and information display.
 //! Modals are rendered as centered overlays that capture keyboard input.
 
+mod command_palette;
 mod help;
+mod migrate_confirm;
 mod new_report;
+mod warnings;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -13,8 +16,11 @@ use ratatui::{
 };
 
 // Re-export public API
+pub use command_palette::CommandPaletteModal;
 pub use help::HelpModal;
+pub use migrate_confirm::MigrateConfirmModal;
 pub use new_report::{NewReportField, NewReportModal, NewReportState};
+pub use warnings::WarningsModal;
 
 /// Render a centered modal dialog and return the inner area
 ///
@@ -30,6 +36,16 @@ pub use new_report::{NewReportField, NewReportModal, NewReportState};
 /// # Returns
 /// The centered Rect where the modal content should be rendered
 pub fn render_modal(frame: &mut Frame, area: Rect, width: u16, height: u16) -> Rect {
+    let popup_area = centered_rect(area, width, height);
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, popup_area);
+
+    popup_area
+}
+
+/// Where [`render_modal`] places a modal of the given dimensions
+pub fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -39,17 +55,12 @@ pub fn render_modal(frame: &mut Frame, area: Rect, width: u16, height: u16) -> R
         ])
         .split(area);
 
-    let popup_area = Layout::default()
+    Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
             Constraint::Length((area.width.saturating_sub(width)) / 2),
             Constraint::Length(width),
             Constraint::Min(0),
         ])
-        .split(popup_layout[1])[1];
-
-    // Clear the area behind the modal
-    frame.render_widget(Clear, popup_area);
-
-    popup_area
+        .split(popup_layout[1])[1]
 }
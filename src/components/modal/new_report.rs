@@ -2,8 +2,10 @@
 //!
 //! Modal dialog for creating a new report (team member) with IC/Manager support.
 
+use std::rc::Rc;
+
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
@@ -11,10 +13,16 @@ use ratatui::{
 };
 
 use crate::model::ReportType;
-use crate::theme::{
-    focused_block, selection_style, style_header, style_muted, style_title, COLOR_PRIMARY,
-    COLOR_SECONDARY,
-};
+use crate::theme::{focused_block, selection_style, style_header, style_muted, style_title, theme};
+
+const TITLE: &str = "Recruit New Report";
+const WIDTH: u16 = 76;
+const HEIGHT: u16 = 16;
+
+/// Focus marker and label in front of each row's value, e.g. "▸ Level: "
+const LABEL_WIDTH: u16 = 9;
+
+const FREQUENCIES: [&str; 3] = ["Weekly", "Biweekly", "Monthly"];
 
 /// Field indices for the new report modal
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -55,6 +63,15 @@ impl NewReportField {
     }
 }
 
+/// Fields in the order the form shows them
+const FIELDS: [NewReportField; 5] = [
+    NewReportField::ReportType,
+    NewReportField::Name,
+    NewReportField::Title,
+    NewReportField::Level,
+    NewReportField::Frequency,
+];
+
 /// State for the new report modal form
 #[derive(Debug, Clone)]
 pub struct NewReportState {
@@ -125,10 +142,8 @@ impl NewReportState {
                     ReportType::Manager
                 };
             }
-            NewReportField::Level => {
-                if self.level_index > 0 {
-                    self.level_index -= 1;
-                }
+            NewReportField::Level if self.level_index > 0 => {
+                self.level_index -= 1;
             }
             NewReportField::Frequency if self.frequency_index > 0 => {
                 self.frequency_index -= 1;
@@ -147,10 +162,8 @@ impl NewReportState {
                     ReportType::Manager
                 };
             }
-            NewReportField::Level => {
-                if self.level_index < 4 {
-                    self.level_index += 1;
-                }
+            NewReportField::Level if self.level_index < 4 => {
+                self.level_index += 1;
             }
             NewReportField::Frequency if self.frequency_index < 2 => {
                 self.frequency_index += 1;
@@ -159,6 +172,18 @@ impl NewReportState {
         }
     }
 
+    /// Focus `field`, picking its `option` (by position in the row) if given
+    pub fn select(&mut self, field: NewReportField, option: Option<usize>) {
+        self.current_field = field;
+        match (field, option) {
+            (NewReportField::ReportType, Some(0)) => self.report_type = ReportType::Individual,
+            (NewReportField::ReportType, Some(_)) => self.report_type = ReportType::Manager,
+            (NewReportField::Level, Some(i)) => self.level_index = i.min(4),
+            (NewReportField::Frequency, Some(i)) => self.frequency_index = i.min(2),
+            _ => {}
+        }
+    }
+
     /// Handle character input
     pub fn handle_char(&mut self, c: char) {
         match self.current_field {
@@ -200,11 +225,60 @@ impl<'a> NewReportModal<'a> {
 
     /// Render the modal
     pub fn render(&self, frame: &mut Frame, area: Rect) {
-        let modal_area = super::render_modal(frame, area, 76, 16);
+        let modal_area = super::render_modal(frame, area, WIDTH, HEIGHT);
+        frame.render_widget(focused_block(TITLE), modal_area);
+
+        let (rows, preview) = Self::layout(modal_area);
+
+        // Left side: form fields
+        self.render_type_row(frame, rows[0]);
+        self.render_text_input_row(
+            frame,
+            rows[1],
+            "Name",
+            &self.state.name,
+            "e.g. Alex Chen",
+            NewReportField::Name,
+        );
+        self.render_text_input_row(
+            frame,
+            rows[2],
+            "Title",
+            &self.state.title,
+            "e.g. Software Engineer",
+            NewReportField::Title,
+        );
+        self.render_level_row(frame, rows[3]);
+        self.render_frequency_row(frame, rows[4]);
+        self.render_help(frame, rows[5]);
+
+        // Right side: avatar preview
+        self.render_preview(frame, preview);
+    }
 
-        let block = focused_block("Recruit New Report");
-        let inner = block.inner(modal_area);
-        frame.render_widget(block, modal_area);
+    /// The field, and the option within it, at `position` on a screen of
+    /// size `area`
+    pub fn field_at(
+        &self,
+        area: Rect,
+        position: Position,
+    ) -> Option<(NewReportField, Option<usize>)> {
+        let (rows, _) = Self::layout(super::centered_rect(area, WIDTH, HEIGHT));
+        let (field, row) = FIELDS
+            .into_iter()
+            .zip(rows.iter())
+            .find(|(_, row)| row.contains(position))?;
+        let option = position.x.checked_sub(row.x + LABEL_WIDTH).and_then(|x| {
+            Self::option_spans(field)
+                .iter()
+                .position(|(start, end)| (*start..*end).contains(&x))
+        });
+        Some((field, option))
+    }
+
+    /// Form rows (one per field, then help) and the preview pane
+    fn layout(modal_area: Rect) -> (Rc<[Rect]>, Rect) {
+        let inner = focused_block(TITLE).inner(modal_area);
 
         // Layout with preview on right
         let main_chunks = Layout::default()
@@ -212,14 +286,6 @@ impl<'a> NewReportModal<'a> {
             .constraints([Constraint::Min(30), Constraint::Length(20)])
             .split(inner);
 
-        // Left side: form fields
-        self.render_form(frame, main_chunks[0]);
-
-        // Right side: avatar preview
-        self.render_preview(frame, main_chunks[1]);
-    }
-
-    fn render_form(&self, frame: &mut Frame, area: Rect) {
         let constraints = vec![
             Constraint::Length(2), // Report type
             Constraint::Length(2), // Name
@@ -229,32 +295,36 @@ impl<'a> NewReportModal<'a> {
             Constraint::Length(2), // Help
         ];
 
-        let chunks = Layout::default()
+        let rows = Layout::default()
             .direction(Direction::Vertical)
             .constraints(constraints)
             .margin(1)
-            .split(area);
+            .split(main_chunks[0]);
+        (rows, main_chunks[1])
+    }
 
-        self.render_type_row(frame, chunks[0]);
-        self.render_text_input_row(
-            frame,
-            chunks[1],
-            "Name",
-            &self.state.name,
-            "e.g. Alex Chen",
-            NewReportField::Name,
-        );
-        self.render_text_input_row(
-            frame,
-            chunks[2],
-            "Title",
-            &self.state.title,
-            "e.g. Software Engineer",
-            NewReportField::Title,
-        );
-        self.render_level_row(frame, chunks[3]);
-        self.render_frequency_row(frame, chunks[4]);
-        self.render_help(frame, chunks[5]);
+    /// Columns each option of a selector row covers, counted from the end
+    /// of the row's label
+    fn option_spans(field: NewReportField) -> Vec<(u16, u16)> {
+        match field {
+            // "[IC]  ◀──▶  [Manager]"
+            NewReportField::ReportType => vec![(0, 4), (12, 21)],
+            // "[P1][P2]…"
+            NewReportField::Level => (0..5).map(|i| (i * 4, i * 4 + 4)).collect(),
+            // "[Weekly] [Biweekly] …"
+            NewReportField::Frequency => {
+                let mut start = 0;
+                FREQUENCIES
+                    .iter()
+                    .map(|freq| {
+                        let span = (start, start + freq.len() as u16 + 2);
+                        start = span.1 + 1;
+                        span
+                    })
+                    .collect()
+            }
+            NewReportField::Name | NewReportField::Title => Vec::new(),
+        }
     }
 
     fn render_type_row(&self, frame: &mut Frame, area: Rect) {
@@ -262,7 +332,7 @@ impl<'a> NewReportModal<'a> {
         let is_manager = self.state.report_type.is_manager();
 
         let focus = if is_active { "▸ " } else { "  " };
-        let focus_style = Style::default().fg(COLOR_PRIMARY);
+        let focus_style = Style::default().fg(theme().primary);
 
         let ic_selected = !is_manager;
         let ic_style = selection_style(ic_selected);
@@ -270,7 +340,7 @@ impl<'a> NewReportModal<'a> {
 
         let arrow_style = if is_active {
             Style::default()
-                .fg(COLOR_PRIMARY)
+                .fg(theme().primary)
                 .add_modifier(Modifier::BOLD)
         } else {
             style_muted()
@@ -297,7 +367,7 @@ impl<'a> NewReportModal<'a> {
             spans.push(Span::styled(
                 "  ◀▶",
                 Style::default()
-                    .fg(COLOR_PRIMARY)
+                    .fg(theme().primary)
                     .add_modifier(Modifier::BOLD),
             ));
         }
@@ -317,13 +387,13 @@ impl<'a> NewReportModal<'a> {
     ) {
         let is_active = self.state.current_field == field;
         let style = if is_active {
-            Style::default().fg(COLOR_SECONDARY)
+            Style::default().fg(theme().secondary)
         } else {
             Style::default()
         };
 
         let focus = if is_active { "▸ " } else { "  " };
-        let focus_style = Style::default().fg(COLOR_PRIMARY);
+        let focus_style = Style::default().fg(theme().primary);
         let cursor = if is_active { "█" } else { "" };
         let display_hint = if value.is_empty() && is_active {
             hint
@@ -338,7 +408,7 @@ impl<'a> NewReportModal<'a> {
             Span::styled(focus, focus_style),
             Span::styled(padded_label, style_header()),
             Span::styled(value, style),
-            Span::styled(cursor, Style::default().fg(COLOR_PRIMARY)),
+            Span::styled(cursor, Style::default().fg(theme().primary)),
             Span::styled(display_hint, style_muted()),
         ]);
 
@@ -354,7 +424,7 @@ impl<'a> NewReportModal<'a> {
         };
 
         let focus = if is_active { "▸ " } else { "  " };
-        let focus_style = Style::default().fg(COLOR_PRIMARY);
+        let focus_style = Style::default().fg(theme().primary);
 
         let mut spans = vec![
             Span::styled(focus, focus_style),
@@ -378,7 +448,7 @@ impl<'a> NewReportModal<'a> {
             spans.push(Span::styled(
                 "  ◀▶",
                 Style::default()
-                    .fg(COLOR_PRIMARY)
+                    .fg(theme().primary)
                     .add_modifier(Modifier::BOLD),
             ));
         }
@@ -389,17 +459,16 @@ impl<'a> NewReportModal<'a> {
 
     fn render_frequency_row(&self, frame: &mut Frame, area: Rect) {
         let is_active = self.state.current_field == NewReportField::Frequency;
-        let frequencies = ["Weekly", "Biweekly", "Monthly"];
 
         let focus = if is_active { "▸ " } else { "  " };
-        let focus_style = Style::default().fg(COLOR_PRIMARY);
+        let focus_style = Style::default().fg(theme().primary);
 
         let mut spans = vec![
             Span::styled(focus, focus_style),
             Span::styled("1:1s:  ", style_header()),
         ];
 
-        for (i, freq) in frequencies.iter().enumerate() {
+        for (i, freq) in FREQUENCIES.iter().enumerate() {
             let is_selected = i == self.state.frequency_index;
             let style = selection_style(is_selected);
 
@@ -416,7 +485,7 @@ impl<'a> NewReportModal<'a> {
             spans.push(Span::styled(
                 " ◀▶",
                 Style::default()
-                    .fg(COLOR_PRIMARY)
+                    .fg(theme().primary)
                     .add_modifier(Modifier::BOLD),
             ));
         }
@@ -489,28 +558,28 @@ impl<'a> NewReportModal<'a> {
 
         let mut lines = vec![
             Line::from(Span::styled(format!(" ★ {} ★ ", level), style_title())),
-            Line::from(Span::styled(top, Style::default().fg(COLOR_PRIMARY))),
+            Line::from(Span::styled(top, Style::default().fg(theme().primary))),
         ];
 
         if is_manager && !headband.is_empty() {
             lines.push(Line::from(Span::styled(
                 headband,
-                Style::default().fg(COLOR_PRIMARY),
+                Style::default().fg(theme().primary),
             )));
         }
 
         lines.push(Line::from(Span::styled(
             face,
-            Style::default().fg(COLOR_PRIMARY),
+            Style::default().fg(theme().primary),
         )));
         lines.push(Line::from(Span::styled(
             bot,
-            Style::default().fg(COLOR_PRIMARY),
+            Style::default().fg(theme().primary),
         )));
         lines.push(Line::from(Span::styled(
             display_name,
             Style::default()
-                .fg(COLOR_SECONDARY)
+                .fg(theme().secondary)
                 .add_modifier(Modifier::BOLD),
         )));
 
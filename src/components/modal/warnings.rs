@@ -0,0 +1,82 @@
+//! Workspace warnings modal
+//!
+//! Lists the problems `vibe-manager doctor` would report, so a profile with
+//! a YAML typo doesn't just silently vanish from the dashboard.
+
+use std::path::Path;
+
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::storage::{Issue, Severity};
+use crate::theme::{focused_block, selection_style, style_danger, style_muted, style_warning};
+
+/// Warnings modal showing workspace problems
+pub struct WarningsModal<'a> {
+    issues: &'a [Issue],
+    root: &'a Path,
+    selected_index: usize,
+}
+
+impl<'a> WarningsModal<'a> {
+    pub fn new(issues: &'a [Issue], root: &'a Path, selected_index: usize) -> Self {
+        Self {
+            issues,
+            root,
+            selected_index,
+        }
+    }
+
+    /// Render the warnings modal
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = area.width.saturating_sub(4).min(100);
+        let height = area.height.saturating_sub(4).min(24);
+        let modal_area = super::render_modal(frame, area, width, height);
+
+        let title = format!("Workspace problems ({})", self.issues.len());
+        let block = focused_block(&title);
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        // Two lines per issue plus the footer; keep the selection visible
+        let visible = (inner.height.saturating_sub(2) as usize / 2).max(1);
+        let skip = (self.selected_index + 1).saturating_sub(visible);
+
+        let mut lines: Vec<Line> = self
+            .issues
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .take(visible)
+            .flat_map(|(idx, issue)| {
+                let (mark, mark_style) = match issue.problem.severity() {
+                    Severity::Error => ("✗ ", style_danger()),
+                    Severity::Warning => ("⚠ ", style_warning()),
+                };
+                let path = issue.path.strip_prefix(self.root).unwrap_or(&issue.path);
+                [
+                    Line::from(vec![
+                        Span::styled(mark, mark_style),
+                        Span::styled(
+                            path.display().to_string(),
+                            selection_style(idx == self.selected_index),
+                        ),
+                    ]),
+                    Line::from(Span::styled(format!("  {}", issue.problem), style_muted())),
+                ]
+            })
+            .collect();
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Run `vibe-manager doctor --fix` to repair what it can · Esc to close",
+            style_muted(),
+        )));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
@@ -0,0 +1,212 @@
+//! Mood analytics component
+//!
+//! Full-screen breakdown of a report's moods over the selected window: a
+//! time-axis chart, summary statistics, a per-context breakdown, and
+//! weekly/monthly averages.
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use super::mood_chart::render_mood_timeline;
+use crate::model::{MoodAnalytics, PeriodAverage};
+use crate::theme::{mood_color, rpg_block, style_header, style_muted, style_title, theme};
+
+pub struct MoodAnalyticsPanel<'a> {
+    name: &'a str,
+    analytics: &'a MoodAnalytics,
+}
+
+impl<'a> MoodAnalyticsPanel<'a> {
+    pub fn new(name: &'a str, analytics: &'a MoodAnalytics) -> Self {
+        Self { name, analytics }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let title = format!(
+            "Mood Analytics · {} · last {}",
+            self.name,
+            self.analytics.window.label()
+        );
+        let block = rpg_block(&title);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(9), // Chart: 5 mood rows + axis + labels + borders
+                Constraint::Min(6),    // Stat panels
+            ])
+            .split(inner);
+
+        self.render_chart(frame, chunks[0]);
+
+        let panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+            ])
+            .split(chunks[1]);
+
+        self.render_stats(frame, panels[0]);
+        self.render_contexts(frame, panels[1]);
+        render_periods(
+            frame,
+            panels[2],
+            " WEEKLY ",
+            &self.analytics.weekly,
+            "%b %d",
+        );
+        render_periods(
+            frame,
+            panels[3],
+            " MONTHLY ",
+            &self.analytics.monthly,
+            "%b %Y",
+        );
+    }
+
+    fn render_chart(&self, frame: &mut Frame, area: Rect) {
+        let block = panel_block(" ♥ MORALE OVER TIME ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let lines = render_mood_timeline(
+            &self.analytics.readings,
+            self.analytics.start,
+            self.analytics.end,
+            inner.width as usize,
+        );
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    fn render_stats(&self, frame: &mut Frame, area: Rect) {
+        let block = panel_block(" ⚔ STATS ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let Some(stats) = &self.analytics.overall else {
+            render_empty(frame, inner);
+            return;
+        };
+
+        let rows = vec![
+            Row::new(vec![
+                Cell::from("Readings"),
+                Cell::from(stats.count.to_string()).style(style_title()),
+            ]),
+            Row::new(vec![
+                Cell::from("Average"),
+                Cell::from(format!("{:.1}", stats.average))
+                    .style(Style::default().fg(mood_color(stats.average.round() as u8))),
+            ]),
+            Row::new(vec![
+                Cell::from("Min / Max"),
+                Cell::from(format!("{} / {}", stats.min, stats.max)),
+            ]),
+            Row::new(vec![
+                Cell::from("Variance"),
+                Cell::from(format!("{:.2}", stats.variance)),
+            ]),
+        ];
+        let table = Table::new(rows, [Constraint::Length(10), Constraint::Min(5)]);
+        frame.render_widget(table, inner);
+    }
+
+    fn render_contexts(&self, frame: &mut Frame, area: Rect) {
+        let block = panel_block(" BY CONTEXT ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.analytics.by_context.is_empty() {
+            render_empty(frame, inner);
+            return;
+        }
+
+        let rows: Vec<Row> = self
+            .analytics
+            .by_context
+            .iter()
+            .map(|(context, stats)| {
+                Row::new(vec![
+                    Cell::from(context.as_str()),
+                    Cell::from(format!("{:.1}", stats.average))
+                        .style(Style::default().fg(mood_color(stats.average.round() as u8))),
+                    Cell::from(format!("×{}", stats.count)).style(style_muted()),
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Length(4),
+                Constraint::Min(3),
+            ],
+        );
+        frame.render_widget(table, inner);
+    }
+}
+
+/// Period averages, newest first (as many as fit)
+fn render_periods(
+    frame: &mut Frame,
+    area: Rect,
+    title: &'static str,
+    periods: &[PeriodAverage],
+    date_format: &str,
+) {
+    let block = panel_block(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if periods.is_empty() {
+        render_empty(frame, inner);
+        return;
+    }
+
+    let rows: Vec<Row> = periods
+        .iter()
+        .rev()
+        .map(|period| {
+            Row::new(vec![
+                Cell::from(period.start.format(date_format).to_string()),
+                Cell::from(format!("{:.1}", period.average))
+                    .style(Style::default().fg(mood_color(period.average.round() as u8))),
+                Cell::from(format!("×{}", period.count)).style(style_muted()),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(9),
+            Constraint::Length(4),
+            Constraint::Min(3),
+        ],
+    );
+    frame.render_widget(table, inner);
+}
+
+fn panel_block(title: &str) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme().muted))
+        .title(title)
+        .title_style(style_header())
+}
+
+fn render_empty(frame: &mut Frame, area: Rect) {
+    let text = Paragraph::new(Line::from(Span::styled("No data", style_muted())))
+        .alignment(Alignment::Center);
+    frame.render_widget(text, area);
+}
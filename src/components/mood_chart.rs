@@ -1,13 +1,13 @@
 //! Mood history chart component
 
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use ratatui::{
     style::Style,
     text::{Line, Span},
 };
 
 use crate::model::JournalEntry;
-use crate::theme::{mood_color, style_muted, COLOR_MUTED};
+use crate::theme::{mood_color, style_muted, theme};
 
 /// Render a simple ASCII chart with axis
 pub fn render_mood_chart_with_axis(
@@ -34,7 +34,7 @@ pub fn render_mood_chart_with_axis(
     for level in (1..=5).rev() {
         let mut row_spans = vec![Span::styled(
             format!("{}│", level),
-            Style::default().fg(COLOR_MUTED),
+            Style::default().fg(theme().muted),
         )];
 
         for (_, mood) in data.iter() {
@@ -56,8 +56,125 @@ pub fn render_mood_chart_with_axis(
     let x_axis = format!(" └{}", "─".repeat(data.len().min(width)));
     lines.push(Line::from(Span::styled(
         x_axis,
-        Style::default().fg(COLOR_MUTED),
+        Style::default().fg(theme().muted),
     )));
 
     lines
 }
+
+/// Render a mood chart with a real time axis
+///
+/// Each column covers an equal slice of the `start..=end` date range, so gaps
+/// between readings show up as gaps in the chart. Readings that land in the
+/// same column are averaged. Below the axis, the first and last dates label
+/// the range.
+pub fn render_mood_timeline(
+    readings: &[(NaiveDateTime, u8)],
+    start: NaiveDate,
+    end: NaiveDate,
+    width: usize,
+) -> Vec<Line<'static>> {
+    if readings.is_empty() {
+        return vec![Line::from(Span::styled(
+            "No mood data in this window",
+            style_muted(),
+        ))];
+    }
+
+    let columns = width.saturating_sub(2).max(1); // Leave room for Y axis
+    let span_days = (end - start).num_days().max(1) as usize;
+
+    // Sum and count per column, then average
+    let mut buckets = vec![(0u32, 0u32); columns];
+    for (timestamp, mood) in readings {
+        let offset = (timestamp.date() - start)
+            .num_days()
+            .clamp(0, span_days as i64) as usize;
+        let col = offset * (columns - 1) / span_days;
+        buckets[col].0 += *mood as u32;
+        buckets[col].1 += 1;
+    }
+    let points: Vec<Option<u8>> = buckets
+        .iter()
+        .map(|&(sum, count)| (count > 0).then(|| ((sum as f32 / count as f32).round()) as u8))
+        .collect();
+
+    let mut lines = Vec::new();
+
+    // Chart rows from top (5) to bottom (1)
+    for level in (1..=5).rev() {
+        let mut row_spans = vec![Span::styled(
+            format!("{}│", level),
+            Style::default().fg(theme().muted),
+        )];
+        for point in &points {
+            match point {
+                Some(mood) if *mood == level => {
+                    row_spans.push(Span::styled("●", Style::default().fg(mood_color(*mood))))
+                }
+                _ => row_spans.push(Span::raw(" ")),
+            }
+        }
+        lines.push(Line::from(row_spans));
+    }
+
+    // X axis with start/end date labels
+    lines.push(Line::from(Span::styled(
+        format!(" └{}", "─".repeat(columns)),
+        Style::default().fg(theme().muted),
+    )));
+    let start_label = start.format("%b %d").to_string();
+    let end_label = end.format("%b %d").to_string();
+    let gap = columns
+        .saturating_sub(start_label.len() + end_label.len())
+        .max(1);
+    lines.push(Line::from(Span::styled(
+        format!("  {}{}{}", start_label, " ".repeat(gap), end_label),
+        style_muted(),
+    )));
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    fn row_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_timeline_places_points_by_date() {
+        let start = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 3, 11).unwrap();
+        // 10-day span across 11 columns (13 wide minus the Y axis): one column per day
+        let readings = vec![(at(2026, 3, 1), 5), (at(2026, 3, 11), 1)];
+
+        let lines = render_mood_timeline(&readings, start, end, 13);
+        assert_eq!(lines.len(), 7);
+        assert_eq!(row_text(&lines[0]), "5│●          ");
+        assert_eq!(row_text(&lines[4]), "1│          ●");
+        assert_eq!(row_text(&lines[6]), "  Mar 01 Mar 11");
+    }
+
+    #[test]
+    fn test_timeline_averages_shared_column() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+        let readings = vec![(at(2026, 6, 1), 2), (at(2026, 6, 2), 4)];
+
+        let lines = render_mood_timeline(&readings, start, end, 12);
+        // Both readings share a column and average to 3
+        assert!(row_text(&lines[2]).contains('●'));
+        assert!(!row_text(&lines[1]).contains('●'));
+        assert!(!row_text(&lines[3]).contains('●'));
+    }
+}
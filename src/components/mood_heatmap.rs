@@ -0,0 +1,132 @@
+//! Team mood heatmap component
+//!
+//! A grid of reports × weeks, each cell colored by that week's average mood,
+//! with a team-average row underneath. Makes team-wide dips visible at a
+//! glance where per-person charts hide them.
+
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::model::MoodHeatmap;
+use crate::theme::{mood_color, rpg_block, style_header, style_muted, style_title};
+
+/// Width of the name column, including the trailing space
+pub const HEATMAP_NAME_WIDTH: usize = 16;
+/// Width of one week column (two-cell block plus a gap)
+pub const HEATMAP_CELL_WIDTH: usize = 3;
+
+pub struct HeatmapGrid<'a> {
+    heatmap: &'a MoodHeatmap,
+    title: &'a str,
+}
+
+impl<'a> HeatmapGrid<'a> {
+    pub fn new(heatmap: &'a MoodHeatmap, title: &'a str) -> Self {
+        Self { heatmap, title }
+    }
+
+    /// How many week columns fit in an area of the given outer width
+    pub fn weeks_for_width(width: u16) -> usize {
+        let grid_width = (width as usize).saturating_sub(2 + HEATMAP_NAME_WIDTH); // borders + names
+        (grid_width / HEATMAP_CELL_WIDTH).max(1)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = rpg_block(self.title);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let mut lines = vec![self.header_line(), Line::from("")];
+        for row in &self.heatmap.rows {
+            lines.push(cells_line(Span::raw(fit_name(&row.name)), &row.cells));
+        }
+        lines.push(Line::from(""));
+        lines.push(cells_line(
+            Span::styled(fit_name("Team"), style_title()),
+            &self.heatmap.team,
+        ));
+        lines.push(Line::from(""));
+        lines.push(legend_line());
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Week labels over the grid: a date every four columns
+    fn header_line(&self) -> Line<'static> {
+        let mut header = " ".repeat(HEATMAP_NAME_WIDTH);
+        let mut col = 0;
+        while col < self.heatmap.weeks.len() {
+            let label = self.heatmap.weeks[col].format("%b %d").to_string();
+            header.push_str(&format!(
+                "{:<width$}",
+                label,
+                width = HEATMAP_CELL_WIDTH * 4
+            ));
+            col += 4;
+        }
+        Line::from(Span::styled(header.trim_end().to_string(), style_header()))
+    }
+}
+
+fn cells_line(label: Span<'static>, cells: &[Option<f32>]) -> Line<'static> {
+    let mut spans = vec![label];
+    for cell in cells {
+        match cell {
+            Some(avg) => spans.push(Span::styled(
+                "██ ",
+                Style::default().fg(mood_color(avg.round() as u8)),
+            )),
+            None => spans.push(Span::styled("·· ", style_muted())),
+        }
+    }
+    Line::from(spans)
+}
+
+fn legend_line() -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!("{:<width$}", "Mood", width = HEATMAP_NAME_WIDTH),
+        style_muted(),
+    )];
+    for mood in 1..=5 {
+        spans.push(Span::styled("██", Style::default().fg(mood_color(mood))));
+        spans.push(Span::styled(format!(" {}  ", mood), style_muted()));
+    }
+    spans.push(Span::styled("·· no data", style_muted()));
+    Line::from(spans)
+}
+
+/// Pad or truncate a name to the name column
+fn fit_name(name: &str) -> String {
+    let max = HEATMAP_NAME_WIDTH - 1;
+    let name: String = if name.chars().count() > max {
+        name.chars()
+            .take(max - 1)
+            .chain(std::iter::once('…'))
+            .collect()
+    } else {
+        name.to_string()
+    };
+    format!("{:<width$}", name, width = HEATMAP_NAME_WIDTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_name_pads_and_truncates() {
+        assert_eq!(fit_name("Sam"), format!("Sam{}", " ".repeat(13)));
+        assert_eq!(fit_name("Alexandra Montgomery"), "Alexandra Mont… ");
+    }
+
+    #[test]
+    fn test_weeks_for_width() {
+        assert_eq!(HeatmapGrid::weeks_for_width(18 + 36), 12);
+        assert_eq!(HeatmapGrid::weeks_for_width(10), 1);
+    }
+}
@@ -0,0 +1,160 @@
+//! Inline note editor component
+//!
+//! The note's markdown source, word-wrapped, coloured the way the viewer
+//! shows it, with the terminal cursor at the edit point.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::NoteEditorState;
+use crate::model::JournalEntry;
+use crate::theme::{focused_block, style_header, style_warning};
+
+use super::note_viewer::{markdown_source_style, render_note_header};
+
+pub struct NoteEditor<'a> {
+    entry: &'a JournalEntry,
+    state: &'a NoteEditorState,
+    mood: Option<u8>,
+}
+
+impl<'a> NoteEditor<'a> {
+    pub fn new(entry: &'a JournalEntry, state: &'a NoteEditorState, mood: Option<u8>) -> Self {
+        Self { entry, state, mood }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header with date and mood
+                Constraint::Min(10),   // Editor
+                Constraint::Length(2), // Help line
+            ])
+            .split(area);
+
+        render_note_header(frame, chunks[0], self.entry, self.mood);
+        self.render_text(frame, chunks[1]);
+        self.render_help(frame, chunks[2]);
+    }
+
+    fn render_text(&self, frame: &mut Frame, area: Rect) {
+        let title = if self.state.is_modified() {
+            "Editing · modified"
+        } else {
+            "Editing"
+        };
+        let block = focused_block(title);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let width = inner.width as usize;
+        let (cursor_row, cursor_col) = self.state.cursor();
+        let mut lines: Vec<Line> = Vec::new();
+        let mut cursor = (0, 0);
+        for (row, text) in self.state.lines().iter().enumerate() {
+            let chars: Vec<char> = text.chars().collect();
+            let (marker_len, marker_style, rest_style) = markdown_source_style(text);
+            let segments = wrap_line(&chars, width);
+            if row == cursor_row {
+                // A cursor on a wrap point sits at the start of the next segment
+                let (idx, (start, _)) = segments
+                    .iter()
+                    .enumerate()
+                    .find(|(_, (_, end))| cursor_col < *end)
+                    .unwrap_or((segments.len() - 1, &segments[segments.len() - 1]));
+                let x = (cursor_col - start).min(width - 1);
+                cursor = (lines.len() + idx, x);
+            }
+            for (start, end) in segments {
+                let split = marker_len.clamp(start, end);
+                let marker: String = chars[start..split].iter().collect();
+                let rest: String = chars[split..end].iter().collect();
+                lines.push(Line::from(vec![
+                    Span::styled(marker, marker_style),
+                    Span::styled(rest, rest_style),
+                ]));
+            }
+        }
+
+        // Scroll just far enough to keep the cursor in view
+        let height = inner.height as usize;
+        let top = (cursor.0 + 1).saturating_sub(height);
+        let visible: Vec<Line> = lines.into_iter().skip(top).take(height).collect();
+        frame.render_widget(Paragraph::new(visible), inner);
+        frame.set_cursor_position(Position::new(
+            inner.x + cursor.1 as u16,
+            inner.y + (cursor.0 - top) as u16,
+        ));
+    }
+
+    fn render_help(&self, frame: &mut Frame, area: Rect) {
+        let help = if self.state.confirm_discard {
+            Line::from(Span::styled(
+                "Unsaved changes: Ctrl+S to save, Esc again to discard",
+                style_warning(),
+            ))
+        } else {
+            Line::from(vec![
+                Span::styled("Ctrl+S", style_header()),
+                Span::raw(" Save  "),
+                Span::styled("Esc", style_header()),
+                Span::raw(" Close  "),
+                Span::styled("Ctrl+Z/Y", style_header()),
+                Span::raw(" Undo/Redo  "),
+                Span::styled("F1-F5", style_header()),
+                Span::raw(" Mood"),
+            ])
+        };
+        frame.render_widget(Paragraph::new(help), area);
+    }
+}
+
+/// Char ranges a line wraps into at `width`, breaking after the last space
+/// that fits (or mid-word when a word is wider than the line)
+fn wrap_line(chars: &[char], width: usize) -> Vec<(usize, usize)> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    while chars.len() - start > width {
+        let limit = start + width;
+        let end = chars[start..limit]
+            .iter()
+            .rposition(|c| *c == ' ')
+            .map_or(limit, |space| start + space + 1);
+        segments.push((start, end));
+        start = end;
+    }
+    segments.push((start, chars.len()));
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapped(text: &str, width: usize) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        wrap_line(&chars, width)
+            .into_iter()
+            .map(|(start, end)| chars[start..end].iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap_breaks_after_spaces() {
+        assert_eq!(wrapped("", 10), [""]);
+        assert_eq!(wrapped("short", 10), ["short"]);
+        assert_eq!(
+            wrapped("we talked about the roadmap", 10),
+            ["we talked ", "about the ", "roadmap"]
+        );
+        assert_eq!(wrapped("abcdefghijkl", 5), ["abcde", "fghij", "kl"]);
+    }
+}
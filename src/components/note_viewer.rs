@@ -1,6 +1,7 @@
-//! Markdown note viewer component (read-only display with markdown highlighting)
+//! Markdown note viewer component (read-only display of the rendered note)
 
 use std::borrow::Cow;
+use std::rc::Rc;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -10,12 +11,15 @@ use ratatui::{
     Frame,
 };
 
+use crate::app::NoteSearch;
 use crate::model::JournalEntry;
 use crate::theme::{
-    mood_color, mood_gauge, rpg_block, simple_block, style_header, style_muted, COLOR_PRIMARY,
-    COLOR_SECONDARY,
+    mood_color, mood_gauge, rpg_block, simple_block, style_header, style_highlight, style_muted,
+    style_selected, theme,
 };
 
+use super::markdown::{find_matches, highlight_matches, render_markdown};
+
 /// Empty mood display placeholder
 const EMPTY_MOOD_DISPLAY: &str = "─────";
 
@@ -23,6 +27,8 @@ pub struct NoteViewer<'a> {
     entry: &'a JournalEntry,
     content: &'a str,
     mood: Option<u8>,
+    scroll: u16,
+    search: Option<&'a NoteSearch>,
 }
 
 impl<'a> NoteViewer<'a> {
@@ -31,104 +37,160 @@ impl<'a> NoteViewer<'a> {
             entry,
             content,
             mood,
+            scroll: 0,
+            search: None,
         }
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
-        let chunks = Layout::default()
+    /// Start the content this many lines down
+    pub fn with_scroll(mut self, scroll: u16) -> Self {
+        self.scroll = scroll;
+        self
+    }
+
+    /// Highlight a search's matches, or show its prompt while it's typed
+    pub fn with_search(mut self, search: Option<&'a NoteSearch>) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Where the note's text goes when the viewer is drawn in `area`
+    pub fn text_area(area: Rect) -> Rect {
+        rpg_block("Content").inner(Self::layout(area)[1])
+    }
+
+    fn layout(area: Rect) -> Rc<[Rect]> {
+        Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(3), // Header with date and mood
                 Constraint::Min(10),   // Content viewer
                 Constraint::Length(2), // Help line
             ])
-            .split(area);
+            .split(area)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Self::layout(area);
 
-        self.render_header(frame, chunks[0]);
+        render_note_header(frame, chunks[0], self.entry, self.mood);
         self.render_content(frame, chunks[1]);
         self.render_help(frame, chunks[2]);
     }
 
-    fn render_header(&self, frame: &mut Frame, area: Rect) {
-        let date_str = self.entry.date().format("%B %d, %Y").to_string();
-
-        let mood_display: Cow<'static, str> = self.mood.map_or_else(
-            || Cow::Borrowed(EMPTY_MOOD_DISPLAY),
-            |m| Cow::Owned(mood_gauge(m)),
-        );
-        let mood_style = self
-            .mood
-            .map_or(style_muted(), |m| Style::default().fg(mood_color(m)));
-
-        let lines = vec![Line::from(vec![
-            Span::styled("Date: ", style_muted()),
-            Span::styled(date_str, style_header()),
-            Span::raw("    "),
-            Span::styled("Mood: ", style_muted()),
-            Span::styled(mood_display, mood_style),
-        ])];
-
-        let para = Paragraph::new(lines).block(simple_block("Meeting Note"));
-        frame.render_widget(para, area);
-    }
-
     fn render_content(&self, frame: &mut Frame, area: Rect) {
-        let lines: Vec<Line> = self
-            .content
-            .lines()
-            .map(|line_text| self.render_line(line_text))
-            .collect();
+        let width = rpg_block("Content").inner(area).width;
+        let mut lines = render_markdown(self.content, width);
+
+        // Matches of a search that's been entered, the current one brightest
+        let mut title = "Content".to_string();
+        if let Some(search) = self.search.filter(|search| !search.typing) {
+            let matches = find_matches(&lines, &search.query);
+            let current = matches
+                .iter()
+                .position(|line| Some(*line) == search.current);
+            for line in &matches {
+                let style = if Some(*line) == search.current {
+                    style_highlight()
+                } else {
+                    style_selected()
+                };
+                let taken = std::mem::take(&mut lines[*line]);
+                lines[*line] = highlight_matches(taken, &search.query, style);
+            }
+            title = match current {
+                Some(idx) => format!("Content · match {} of {}", idx + 1, matches.len()),
+                None => format!("Content · {} matches", matches.len()),
+            };
+        }
 
-        let para = Paragraph::new(lines).block(rpg_block("Content"));
+        let last = u16::try_from(lines.len().saturating_sub(1)).unwrap_or(u16::MAX);
+        let para = Paragraph::new(lines)
+            .block(rpg_block(&title))
+            .scroll((self.scroll.min(last), 0));
         frame.render_widget(para, area);
     }
 
-    fn render_line<'b>(&self, line: &'b str) -> Line<'b> {
-        // Basic markdown highlighting
-        // Use Cow to avoid allocations when borrowing is sufficient
-        if line.starts_with("# ") {
-            Line::from(Span::styled(Cow::Borrowed(line), style_header()))
-        } else if line.starts_with("## ") {
-            Line::from(Span::styled(
-                Cow::Borrowed(line),
-                Style::default().fg(COLOR_SECONDARY),
-            ))
-        } else if let Some(rest) = line.strip_prefix("- [ ]") {
-            Line::from(vec![
-                Span::styled(Cow::Borrowed("☐ "), style_muted()),
-                Span::raw(Cow::Borrowed(rest)),
-            ])
-        } else if let Some(rest) = line
-            .strip_prefix("- [x]")
-            .or_else(|| line.strip_prefix("- [X]"))
-        {
-            Line::from(vec![
-                Span::styled(Cow::Borrowed("☑ "), Style::default().fg(COLOR_PRIMARY)),
-                Span::raw(Cow::Borrowed(rest)),
-            ])
-        } else if let Some(rest) = line.strip_prefix("- ") {
-            Line::from(vec![
-                Span::styled(Cow::Borrowed("• "), style_muted()),
-                Span::raw(Cow::Borrowed(rest)),
-            ])
-        } else {
-            Line::from(Cow::Borrowed(line))
+    fn render_help(&self, frame: &mut Frame, area: Rect) {
+        if let Some(search) = self.search.filter(|search| search.typing) {
+            let prompt = Line::from(vec![
+                Span::styled("/", style_header()),
+                Span::raw(search.query.as_str()),
+                Span::styled("█", style_muted()),
+                Span::styled("   Enter Search  Esc Cancel", style_muted()),
+            ]);
+            frame.render_widget(Paragraph::new(prompt), area);
+            return;
         }
-    }
 
-    fn render_help(&self, frame: &mut Frame, area: Rect) {
-        let help = Line::from(vec![
+        let mut help = vec![
             Span::styled("e", style_header()),
             Span::raw(" Edit  "),
             Span::styled("Del", style_header()),
             Span::raw(" Delete  "),
             Span::styled("F1-F5", style_header()),
             Span::raw(" Mood  "),
+            Span::styled("/", style_header()),
+            Span::raw(" Search  "),
+        ];
+        if self.search.is_some() {
+            help.extend([Span::styled("n/N", style_header()), Span::raw(" Match  ")]);
+        }
+        help.extend([
+            Span::styled("v", style_header()),
+            Span::raw(" History  "),
             Span::styled("Esc/Bksp", style_header()),
             Span::raw(" Back"),
         ]);
 
-        let para = Paragraph::new(help);
+        let para = Paragraph::new(Line::from(help));
         frame.render_widget(para, area);
     }
 }
+
+/// Date and mood line above a note, shared with the inline editor
+pub(super) fn render_note_header(
+    frame: &mut Frame,
+    area: Rect,
+    entry: &JournalEntry,
+    mood: Option<u8>,
+) {
+    let date_str = entry.date().format("%B %d, %Y").to_string();
+
+    let mood_display: Cow<'static, str> = mood.map_or_else(
+        || Cow::Borrowed(EMPTY_MOOD_DISPLAY),
+        |m| Cow::Owned(mood_gauge(m)),
+    );
+    let mood_style = mood.map_or(style_muted(), |m| Style::default().fg(mood_color(m)));
+
+    let lines = vec![Line::from(vec![
+        Span::styled("Date: ", style_muted()),
+        Span::styled(date_str, style_header()),
+        Span::raw("    "),
+        Span::styled("Mood: ", style_muted()),
+        Span::styled(mood_display, mood_style),
+    ])];
+
+    let para = Paragraph::new(lines).block(simple_block("Meeting Note"));
+    frame.render_widget(para, area);
+}
+
+/// How the editor colours a line of markdown source, matching what the
+/// viewer renders it as: chars of list marker, the marker's style, and the
+/// style of the rest
+pub(super) fn markdown_source_style(line: &str) -> (usize, Style, Style) {
+    if line.starts_with("# ") {
+        (0, style_header(), style_header())
+    } else if line.starts_with("## ") {
+        let style = Style::default().fg(theme().secondary);
+        (0, style, style)
+    } else if line.starts_with("- [ ]") {
+        (5, style_muted(), Style::default())
+    } else if line.starts_with("- [x]") || line.starts_with("- [X]") {
+        (5, Style::default().fg(theme().primary), Style::default())
+    } else if line.starts_with("- ") {
+        (2, style_muted(), Style::default())
+    } else {
+        (0, Style::default(), Style::default())
+    }
+}
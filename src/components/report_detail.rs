@@ -1,8 +1,11 @@
 //! Report detail view component
 
+use std::rc::Rc;
+
+use chrono::Local;
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
+    style::{Modifier, Style, Stylize},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, TableState},
     Frame,
@@ -12,7 +15,7 @@ use crate::model::{JournalEntry, MoodTrend, Report, ReportSummary, ReportType};
 use crate::theme::{
     format_days_ago, format_meeting_frequency, mood_color, mood_gauge, mood_gauge_with_value,
     mood_trend_icon, overdue_color, rpg_block, simple_block, sprites, style_header, style_muted,
-    style_title, COLOR_MUTED,
+    style_report, style_title, theme,
 };
 
 pub struct ReportDetail<'a> {
@@ -38,16 +41,38 @@ impl<'a> ReportDetail<'a> {
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect) {
-        let chunks = Layout::default()
+        let chunks = Self::layout(area);
+
+        self.render_profile(frame, chunks[0]);
+        self.render_entries(frame, chunks[1]);
+    }
+
+    /// Display index (newest first) of the meeting row at `position`
+    pub fn meeting_at(&self, area: Rect, position: Position) -> Option<usize> {
+        let count = self.meeting_count();
+        // Rows sit inside the block, below the header
+        let mut rows = simple_block("").inner(Self::layout(area)[1]);
+        rows.y += 1;
+        rows.height = rows.height.saturating_sub(1);
+        if count == 0 || !rows.contains(position) {
+            return None;
+        }
+
+        // The table scrolls just far enough to keep the selection visible
+        let selected = self.selected_entry.min(count - 1);
+        let offset = (selected + 1).saturating_sub(rows.height as usize);
+        let index = offset + (position.y - rows.y) as usize;
+        (index < count).then_some(index)
+    }
+
+    fn layout(area: Rect) -> Rc<[Rect]> {
+        Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(16), // Profile info with 2-column layout
                 Constraint::Min(8),     // Entry history
             ])
-            .split(area);
-
-        self.render_profile(frame, chunks[0]);
-        self.render_entries(frame, chunks[1]);
+            .split(area)
     }
 
     fn render_profile(&self, frame: &mut Frame, area: Rect) {
@@ -57,7 +82,7 @@ impl<'a> ReportDetail<'a> {
 
         // 2-column layout
         // Left: avatar+name (compact) + mood chart
-        // Right: stats + bio
+        // Right: stats + why now + bio
         let columns = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -100,10 +125,8 @@ impl<'a> ReportDetail<'a> {
             .split(chunks[0]);
 
         // Avatar (3-4 lines depending on report type, centered vertically)
-        let sprite = sprites::FaceSprite::from_summary(
-            self.summary,
-            Style::default().fg(self.summary.color),
-        );
+        let sprite =
+            sprites::FaceSprite::from_summary(self.summary, style_report(self.summary.color));
         let avatar_area = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -136,8 +159,8 @@ impl<'a> ReportDetail<'a> {
         let level_text = profile.level.as_deref().unwrap_or("-");
         let title_level_line = Line::from(vec![
             Span::styled(title_text, style_muted()),
-            Span::styled(" ★ ", Style::default().fg(self.summary.color)),
-            Span::styled(level_text, Style::default().fg(self.summary.color)),
+            Span::styled(" ★ ", style_report(self.summary.color)),
+            Span::styled(level_text, style_report(self.summary.color)),
         ]);
         frame.render_widget(Paragraph::new(title_level_line), name_area[2]);
 
@@ -161,7 +184,13 @@ impl<'a> ReportDetail<'a> {
                 .split(area)
         };
 
-        self.render_stats_panel(frame, rows[0]);
+        // Stats on the left, the urgency explanation beside them
+        let top = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(rows[0]);
+        self.render_stats_panel(frame, top[0]);
+        self.render_why_now_panel(frame, top[1]);
 
         if has_bio {
             self.render_bio_panel(frame, rows[1]);
@@ -174,7 +203,7 @@ impl<'a> ReportDetail<'a> {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(COLOR_MUTED))
+            .border_style(Style::default().fg(theme().muted))
             .title(" ⚔ STATS ")
             .title_style(style_header());
 
@@ -204,13 +233,24 @@ impl<'a> ReportDetail<'a> {
                 .style(last_meeting_style),
         ]));
 
+        // Next 1-on-1 row (planned date from calendar sync, hidden once past)
+        if let Some(next) = profile
+            .next_meeting
+            .filter(|next| *next >= Local::now().naive_local())
+        {
+            rows.push(Row::new(vec![
+                Cell::from("Next 1-on-1"),
+                Cell::from(next.format("%a %b %d, %H:%M").to_string()),
+            ]));
+        }
+
         // Morale row
         if let Some(mood) = self.summary.recent_mood {
             let mood_display = mood_gauge_with_value(mood);
             let trend_icon = mood_trend_icon(self.summary.mood_trend);
             let trend_style = match self.summary.mood_trend {
-                Some(MoodTrend::Rising) => Style::default().fg(Color::Green).bold(),
-                Some(MoodTrend::Falling) => Style::default().fg(Color::Rgb(255, 140, 0)).bold(),
+                Some(MoodTrend::Rising) => Style::default().fg(theme().rising).bold(),
+                Some(MoodTrend::Falling) => Style::default().fg(theme().falling).bold(),
                 _ => Style::default(),
             };
             rows.push(Row::new(vec![
@@ -223,15 +263,68 @@ impl<'a> ReportDetail<'a> {
             ]));
         }
 
+        // Baseline row: their own normal, and how far the latest mood sits from it
+        if let Some(baseline) = self.summary.mood_baseline {
+            let deviation = self
+                .summary
+                .mood_deviation
+                .map(|d| format!(" ({:+.1}σ)", d))
+                .unwrap_or_default();
+            rows.push(Row::new(vec![
+                Cell::from("Baseline"),
+                Cell::from(format!("{:.1}{}", baseline, deviation)).style(style_muted()),
+            ]));
+        }
+
         let table = Table::new(rows, [Constraint::Length(12), Constraint::Min(10)]);
         frame.render_widget(table, inner);
     }
 
+    /// "Why now?" panel: each factor behind the urgency score, with its points
+    fn render_why_now_panel(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme().muted))
+            .title(" ⚡ WHY NOW? ")
+            .title_style(style_header());
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let contributions = &self.summary.urgency_breakdown.contributions;
+        if contributions.is_empty() {
+            let text = Paragraph::new(Span::styled("Nothing pressing", style_muted()))
+                .alignment(Alignment::Center);
+            frame.render_widget(text, inner);
+            return;
+        }
+
+        let mut lines: Vec<Line> = contributions
+            .iter()
+            .map(|(factor, points)| {
+                Line::from(vec![
+                    Span::styled(format!("{:>4} ", format!("+{}", points)), style_title()),
+                    Span::raw(factor.label()),
+                ])
+            })
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:>4} ", self.summary.urgency_score),
+                style_title().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("urgency", style_muted()),
+        ]));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
     fn render_mood_history_panel(&self, frame: &mut Frame, area: Rect) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(COLOR_MUTED))
+            .border_style(Style::default().fg(theme().muted))
             .title(" ♥ MORALE HISTORY ")
             .title_style(style_header());
 
@@ -266,7 +359,7 @@ impl<'a> ReportDetail<'a> {
         for level in (1..=5).rev() {
             let mut spans = vec![Span::styled(
                 format!("{}│", level),
-                Style::default().fg(COLOR_MUTED),
+                Style::default().fg(theme().muted),
             )];
 
             for (_, mood) in data.iter() {
@@ -287,7 +380,7 @@ impl<'a> ReportDetail<'a> {
         let x_axis = format!(" └{}", "─".repeat(axis_width));
         lines.push(Line::from(Span::styled(
             x_axis,
-            Style::default().fg(COLOR_MUTED),
+            Style::default().fg(theme().muted),
         )));
 
         // X-axis labels (first and last dates)
@@ -317,7 +410,7 @@ impl<'a> ReportDetail<'a> {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(COLOR_MUTED))
+            .border_style(Style::default().fg(theme().muted))
             .title(" ♥ BIO ")
             .title_style(style_header());
 
@@ -0,0 +1,397 @@
+//! Roster table component - the dashboard as one line per report
+//!
+//! Design Philosophy:
+//! - Fits teams the avatar cards can't: 15+ people on one screen
+//! - Same `ReportSummary` data as the cards, in sortable columns
+//! - Managers can open a squad line under their row with the doorway card's
+//!   squad summary (size, health, mood, worst outlier)
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::model::{MoodScoring, ReportSummary, ReportType, RosterSort, TeamMetrics};
+use crate::theme::{
+    format_compact_age, format_meeting_frequency, health_bar, mood_color, mood_gauge,
+    mood_trend_icon, style_danger, style_header, style_highlight, style_muted, style_success,
+    style_title, style_warning, theme, ICON_WARNING,
+};
+use crate::utils::abbreviate_name;
+
+/// Columns after the name: heading, width, and the order clicking the
+/// heading sorts by
+const COLUMNS: [(&str, u16, Option<RosterSort>); 7] = [
+    ("Level", 6, Some(RosterSort::Level)),
+    ("Every", 10, None),
+    ("Since", 7, Some(RosterSort::LastMeeting)),
+    ("Mood", 7, Some(RosterSort::Mood)),
+    ("Trend", 6, None),
+    ("Next due", 11, Some(RosterSort::NextDue)),
+    ("", 4, None),
+];
+
+/// The name column never gets narrower than this
+const MIN_NAME_WIDTH: u16 = 12;
+
+/// A line of the table body
+#[derive(Clone, Copy)]
+enum TableRow {
+    /// A report, by summary index
+    Report(usize),
+    /// The squad line under a manager, by the manager's summary index
+    Squad(usize),
+}
+
+impl TableRow {
+    fn index(self) -> usize {
+        match self {
+            TableRow::Report(idx) | TableRow::Squad(idx) => idx,
+        }
+    }
+}
+
+pub struct RosterTable<'a> {
+    summaries: &'a [ReportSummary],
+    selected: usize,
+    /// Per summary: whether a manager's squad line is open
+    expanded: &'a [bool],
+    sort: RosterSort,
+    mood_scoring: MoodScoring,
+}
+
+impl<'a> RosterTable<'a> {
+    pub fn new(
+        summaries: &'a [ReportSummary],
+        selected: usize,
+        expanded: &'a [bool],
+        sort: RosterSort,
+        mood_scoring: MoodScoring,
+    ) -> Self {
+        Self {
+            summaries,
+            selected,
+            expanded,
+            sort,
+            mood_scoring,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+        let name_width = self.name_width(area);
+
+        let mut header = vec![Span::styled(
+            cell(&self.heading("Name", Some(RosterSort::Name)), name_width),
+            self.heading_style(Some(RosterSort::Name)),
+        )];
+        header.extend(COLUMNS.iter().map(|&(heading, width, sort)| {
+            Span::styled(
+                cell(&self.heading(heading, sort), width),
+                self.heading_style(sort),
+            )
+        }));
+        frame.render_widget(
+            Paragraph::new(Line::from(header)),
+            Rect::new(area.x, area.y, area.width, 1),
+        );
+
+        for (row, row_area) in self.visible_rows(area) {
+            let line = match row {
+                TableRow::Report(idx) => self.report_line(idx, name_width),
+                TableRow::Squad(idx) => self.squad_line(idx),
+            };
+            frame.render_widget(Paragraph::new(line), row_area);
+        }
+    }
+
+    /// Where each line of the table goes, by summary index (a squad line
+    /// counts as its manager's)
+    pub fn row_areas(&self, area: Rect) -> Vec<(usize, Rect)> {
+        self.visible_rows(area)
+            .into_iter()
+            .map(|(row, rect)| (row.index(), rect))
+            .collect()
+    }
+
+    /// Where each sortable heading is, with the order it sorts by
+    pub fn column_areas(&self, area: Rect) -> Vec<(RosterSort, Rect)> {
+        if area.height == 0 {
+            return Vec::new();
+        }
+        let mut x = area.x;
+        let mut areas = Vec::new();
+        let name_width = self.name_width(area);
+        let columns = std::iter::once(("Name", name_width, Some(RosterSort::Name)))
+            .chain(COLUMNS.iter().copied());
+        for (_, width, sort) in columns {
+            let width = width.min(area.right().saturating_sub(x));
+            if let Some(sort) = sort {
+                areas.push((sort, Rect::new(x, area.y, width, 1)));
+            }
+            x += width;
+        }
+        areas
+    }
+
+    fn name_width(&self, area: Rect) -> u16 {
+        let fixed: u16 = COLUMNS.iter().map(|(_, width, _)| width).sum();
+        area.width.saturating_sub(fixed).max(MIN_NAME_WIDTH)
+    }
+
+    /// Body lines that fit below the heading, scrolled to keep the selected
+    /// report (and its squad line) in view
+    fn visible_rows(&self, area: Rect) -> Vec<(TableRow, Rect)> {
+        let rows = self.rows();
+        let visible = area.height.saturating_sub(1) as usize;
+        let last_selected = rows
+            .iter()
+            .rposition(|row| row.index() == self.selected)
+            .unwrap_or(0);
+        let skip = (last_selected + 1).saturating_sub(visible);
+        rows.into_iter()
+            .skip(skip)
+            .take(visible)
+            .enumerate()
+            .map(|(line, row)| {
+                let y = area.y + 1 + line as u16;
+                (row, Rect::new(area.x, y, area.width, 1))
+            })
+            .collect()
+    }
+
+    fn rows(&self) -> Vec<TableRow> {
+        let mut rows = Vec::with_capacity(self.summaries.len());
+        for (idx, summary) in self.summaries.iter().enumerate() {
+            rows.push(TableRow::Report(idx));
+            let is_manager = matches!(summary.report_type, ReportType::Manager);
+            if is_manager && self.expanded.get(idx).copied().unwrap_or(false) {
+                rows.push(TableRow::Squad(idx));
+            }
+        }
+        rows
+    }
+
+    /// Column heading, with an arrow on the one the table is sorted by
+    fn heading(&self, heading: &str, sort: Option<RosterSort>) -> String {
+        if sort == Some(self.sort) {
+            format!("{} ▾", heading)
+        } else {
+            heading.to_string()
+        }
+    }
+
+    fn heading_style(&self, sort: Option<RosterSort>) -> Style {
+        if sort == Some(self.sort) {
+            style_title()
+        } else {
+            style_header()
+        }
+    }
+
+    fn report_line(&self, idx: usize, name_width: u16) -> Line<'static> {
+        let summary = &self.summaries[idx];
+        let is_manager = matches!(summary.report_type, ReportType::Manager);
+        let expanded = self.expanded.get(idx).copied().unwrap_or(false);
+
+        let marker = match (is_manager, expanded) {
+            (true, true) => "▾ ",
+            (true, false) => "▸ ",
+            (false, _) => "  ",
+        };
+        let name_style = if summary.active {
+            Style::default().fg(theme().text)
+        } else {
+            style_muted()
+        };
+        let (mood, mood_style) = match summary.recent_mood {
+            Some(mood) => (mood_gauge(mood), Style::default().fg(mood_color(mood))),
+            None => ("—".to_string(), style_muted()),
+        };
+        let due_style = match summary.days_until_due() {
+            Some(days) if days >= 0 => style_muted(),
+            _ => style_warning(),
+        };
+
+        let cells = [
+            (
+                format!("{}{}", marker, summary.name),
+                name_width,
+                name_style,
+            ),
+            (summary.level.clone(), COLUMNS[0].1, style_muted()),
+            (
+                format_meeting_frequency(&summary.meeting_frequency).to_string(),
+                COLUMNS[1].1,
+                style_muted(),
+            ),
+            (
+                format_compact_age(summary.days_since_meeting),
+                COLUMNS[2].1,
+                Style::default().fg(theme().text),
+            ),
+            (mood, COLUMNS[3].1, mood_style),
+            (
+                mood_trend_icon(summary.mood_trend).to_string(),
+                COLUMNS[4].1,
+                Style::default().fg(theme().text),
+            ),
+            (
+                format_due(summary.days_until_due()),
+                COLUMNS[5].1,
+                due_style,
+            ),
+            (self.attention(summary), COLUMNS[6].1, style_danger()),
+        ];
+
+        if idx == self.selected {
+            let text: String = cells
+                .iter()
+                .map(|(text, width, _)| cell(text, *width))
+                .collect();
+            Line::styled(text, style_highlight())
+        } else {
+            Line::from(
+                cells
+                    .into_iter()
+                    .map(|(text, width, style)| Span::styled(cell(&text, width), style))
+                    .collect::<Vec<_>>(),
+            )
+        }
+    }
+
+    /// Outlier marker: ⚠ for someone who needs attention, ⚠ and a count for
+    /// a manager whose squad has outliers
+    fn attention(&self, summary: &ReportSummary) -> String {
+        let squad_outliers = summary
+            .team_metrics
+            .as_ref()
+            .map_or(0, |tm| tm.outliers.len());
+        if squad_outliers > 0 {
+            format!("{}{}", ICON_WARNING, squad_outliers)
+        } else if summary.needs_attention(self.mood_scoring) {
+            ICON_WARNING.to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// The doorway card's squad line, indented under the manager
+    fn squad_line(&self, idx: usize) -> Line<'static> {
+        let indent = Span::styled("  └ ", style_muted());
+        let Some(tm) = self.summaries[idx]
+            .team_metrics
+            .as_ref()
+            .filter(|tm| tm.team_size > 0)
+        else {
+            return Line::from(vec![
+                indent,
+                Span::styled("squad 0 · no members yet", style_muted()),
+            ]);
+        };
+
+        let mut spans = vec![
+            indent,
+            Span::styled(format!("squad {} ", tm.team_size), style_muted()),
+            Span::styled(health_bar(tm.team_health_score, 8), style_header()),
+            Span::styled(
+                format!("{}%", tm.team_health_score),
+                Style::default().fg(theme().text),
+            ),
+            Span::styled(squad_mood(tm), style_muted()),
+        ];
+        if tm.team_overdue_count > 0 {
+            spans.push(Span::styled(
+                format!(" · {} overdue", tm.team_overdue_count),
+                style_warning(),
+            ));
+        }
+        spans.push(Span::raw("  "));
+        match tm.outliers.first() {
+            Some(worst) => {
+                spans.push(Span::styled(
+                    format!(
+                        "{} {}: {}",
+                        ICON_WARNING,
+                        abbreviate_name(&worst.name),
+                        worst.label()
+                    ),
+                    style_danger().add_modifier(Modifier::BOLD),
+                ));
+                if tm.outliers.len() > 1 {
+                    spans.push(Span::styled(
+                        format!(" (+{} more)", tm.outliers.len() - 1),
+                        style_muted(),
+                    ));
+                }
+            }
+            None => spans.push(Span::styled("★ all well", style_success())),
+        }
+        Line::from(spans)
+    }
+}
+
+/// " · mood 3.2 ↘" for a squad with moods recorded
+fn squad_mood(tm: &TeamMetrics) -> String {
+    match tm.team_average_mood {
+        Some(mood) => format!(
+            " · mood {:.1} {}",
+            mood,
+            mood_trend_icon(tm.team_mood_trend)
+        )
+        .trim_end()
+        .to_string(),
+        None => String::new(),
+    }
+}
+
+/// When the next 1-on-1 is due: "in 5d", "today", "3d late", or "now" for
+/// someone never met
+fn format_due(days_until_due: Option<i64>) -> String {
+    match days_until_due {
+        None => "now".to_string(),
+        Some(0) => "today".to_string(),
+        Some(days) if days > 0 => format!("in {}d", days),
+        Some(days) => format!("{}d late", -days),
+    }
+}
+
+/// `text` cut or padded to `width` cells, leaving a gap before the next column
+fn cell(text: &str, width: u16) -> String {
+    let width = width as usize;
+    let room = width.saturating_sub(1);
+    let mut out: String = if text.chars().count() > room {
+        let mut cut: String = text.chars().take(room.saturating_sub(1)).collect();
+        cut.push('…');
+        cut
+    } else {
+        text.to_string()
+    };
+    let len = out.chars().count();
+    out.extend(std::iter::repeat_n(' ', width.saturating_sub(len)));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_due() {
+        assert_eq!(format_due(None), "now");
+        assert_eq!(format_due(Some(0)), "today");
+        assert_eq!(format_due(Some(5)), "in 5d");
+        assert_eq!(format_due(Some(-3)), "3d late");
+    }
+
+    #[test]
+    fn test_cell_pads_and_truncates() {
+        assert_eq!(cell("P3", 6), "P3    ");
+        assert_eq!(cell("Alexandra Chen", 8), "Alexan… ");
+    }
+}
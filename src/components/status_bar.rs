@@ -8,22 +8,30 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::ViewMode;
-use crate::theme::{style_muted, COLOR_PRIMARY, COLOR_SECONDARY};
+use crate::app::keymap::{Action, Scope};
+use crate::app::{Keymap, ViewMode};
+use crate::theme::{style_muted, theme};
 
 pub struct StatusBar<'a> {
     view_mode: ViewMode,
     context: &'a str,
     message: Option<&'a str>,
+    keymap: &'a Keymap,
     in_hall: bool,
 }
 
 impl<'a> StatusBar<'a> {
-    pub fn new(view_mode: ViewMode, context: &'a str, message: Option<&'a str>) -> Self {
+    pub fn new(
+        view_mode: ViewMode,
+        context: &'a str,
+        message: Option<&'a str>,
+        keymap: &'a Keymap,
+    ) -> Self {
         Self {
             view_mode,
             context,
             message,
+            keymap,
             in_hall: false,
         }
     }
@@ -45,28 +53,32 @@ impl<'a> StatusBar<'a> {
             ViewMode::Dashboard => "DASHBOARD",
             ViewMode::ReportDetail | ViewMode::EntryInputModal => "REPORT",
             ViewMode::NoteViewer | ViewMode::DeleteConfirmModal => "NOTE",
+            ViewMode::NoteEditor => "EDIT",
             ViewMode::NewReportModal => "NEW REPORT",
             ViewMode::Help => "HELP",
+            ViewMode::MoodAnalytics => "MOOD",
+            ViewMode::MeetingPrep => "PREP",
+            ViewMode::MoodHeatmap => "HEATMAP",
+            ViewMode::History => "HISTORY",
+            ViewMode::Warnings => "PROBLEMS",
+            ViewMode::MigrateConfirmModal => "MIGRATE",
+            ViewMode::CommandPalette => "COMMAND",
         };
 
         let left_content = if let Some(msg) = self.message {
             Line::from(vec![
                 Span::styled(
                     format!(" {} ", mode_str),
-                    Style::default()
-                        .fg(COLOR_PRIMARY)
-                        .bg(ratatui::style::Color::DarkGray),
+                    Style::default().fg(theme().primary).bg(theme().badge),
                 ),
                 Span::raw(" "),
-                Span::styled(msg, Style::default().fg(COLOR_SECONDARY)),
+                Span::styled(msg, Style::default().fg(theme().secondary)),
             ])
         } else {
             Line::from(vec![
                 Span::styled(
                     format!(" {} ", mode_str),
-                    Style::default()
-                        .fg(COLOR_PRIMARY)
-                        .bg(ratatui::style::Color::DarkGray),
+                    Style::default().fg(theme().primary).bg(theme().badge),
                 ),
                 Span::raw(" "),
                 Span::raw(self.context),
@@ -77,20 +89,89 @@ impl<'a> StatusBar<'a> {
         frame.render_widget(left, chunks[0]);
 
         // Right side: keybindings hint
-        let hints = match self.view_mode {
-            ViewMode::Dashboard if self.in_hall => "h/l:nav  Enter:view  Esc:back  q:quit",
-            ViewMode::Dashboard => "h/l:nav  Enter:view  n:new  ?:help  q:quit",
-            ViewMode::ReportDetail => "e:edit  n:new  m:mood  Del:delete  Enter:view  Bksp:back",
-            ViewMode::NoteViewer | ViewMode::DeleteConfirmModal => {
-                "e:edit  Del:delete  F1-F5:mood  Bksp:back"
-            }
-            ViewMode::EntryInputModal => "1-5:mood  Tab:context  Enter:save  Esc:cancel",
-            ViewMode::Help => "?/Esc:close",
-            _ => "Esc:cancel  Enter:confirm",
-        };
-
+        let hints = self.hints();
         let right = Paragraph::new(Line::from(Span::styled(hints, style_muted())))
             .alignment(ratatui::layout::Alignment::Right);
         frame.render_widget(right, chunks[1]);
     }
+
+    /// Key hints for the current view, read from the keymap so rebound keys
+    /// show as they are; unbound actions are left out
+    fn hints(&self) -> String {
+        use Action::*;
+        let entries: &[(&[Action], &str)] = match self.view_mode {
+            ViewMode::Dashboard if self.in_hall => &[
+                (&[PrevOrAscend, Next], "nav"),
+                (&[Open], "view"),
+                (&[ExitHall], "back"),
+                (&[Quit], "quit"),
+            ],
+            ViewMode::Dashboard => &[
+                (&[PrevOrAscend, Next], "nav"),
+                (&[Open], "view"),
+                (&[NewReport], "new"),
+                (&[Help], "help"),
+                (&[Quit], "quit"),
+            ],
+            ViewMode::ReportDetail => &[
+                (&[Edit], "edit"),
+                (&[NewMeeting], "new"),
+                (&[RecordMood], "mood"),
+                (&[MoodAnalytics], "trends"),
+                (&[Delete], "delete"),
+                (&[Back], "back"),
+            ],
+            ViewMode::NoteViewer | ViewMode::DeleteConfirmModal => &[
+                (&[Edit], "edit"),
+                (&[Delete], "delete"),
+                (&[SearchNote], "search"),
+                (&[Back], "back"),
+            ],
+            ViewMode::EntryInputModal => {
+                return "1-5:mood  Tab:context  Enter:save  Esc:cancel".into()
+            }
+            ViewMode::Help => &[(&[CloseHelp], "close")],
+            ViewMode::MoodAnalytics => &[
+                (&[CycleWindow], "window"),
+                (&[Back], "back"),
+                (&[Quit], "quit"),
+            ],
+            ViewMode::MeetingPrep | ViewMode::MoodHeatmap => {
+                &[(&[Back], "back"), (&[Quit], "quit")]
+            }
+            ViewMode::History => &[
+                (&[Next, Prev], "revision"),
+                (&[Back], "back"),
+                (&[Quit], "quit"),
+            ],
+            ViewMode::Warnings => &[(&[Next, Prev], "scroll"), (&[Back], "close")],
+            ViewMode::MigrateConfirmModal => &[(&[Confirm], "migrate"), (&[Cancel], "cancel")],
+            ViewMode::NewReportModal => return "Esc:cancel  Enter:confirm".into(),
+            ViewMode::NoteEditor => {
+                return "Ctrl+S:save  Esc:close  Ctrl+Z:undo  F1-F5:mood".into()
+            }
+            ViewMode::CommandPalette => return "↑↓:select  Enter:run  Esc:close".into(),
+        };
+        // Delete confirmation sits over the note viewer's keys
+        let scope = match self.view_mode {
+            ViewMode::DeleteConfirmModal => Scope::NoteViewer,
+            view => Scope::for_view(view).unwrap_or(Scope::Global),
+        };
+
+        let mut hints: Vec<String> = entries
+            .iter()
+            .filter_map(|(actions, label)| {
+                let keys = self.keymap.hint(scope, actions)?;
+                Some(format!("{}:{}", keys, label))
+            })
+            .collect();
+        if scope == Scope::NoteViewer {
+            let first = self.keymap.keys(scope, Mood(1)).into_iter().next();
+            let last = self.keymap.keys(scope, Mood(5)).into_iter().next();
+            if let (Some(first), Some(last)) = (first, last) {
+                hints.insert(2, format!("{}-{}:mood", first, last));
+            }
+        }
+        hints.join("  ")
+    }
 }
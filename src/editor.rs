@@ -5,9 +5,9 @@
 
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
 
@@ -64,10 +64,79 @@ fn which_exists(cmd: &str) -> bool {
 ///
 /// Returns information about whether the file was modified.
 pub fn edit_file(path: &Path) -> Result<EditorResult> {
-    let editor = get_editor();
     let mtime_before = get_mtime(path)?;
 
-    // Spawn the editor
+    run_editor(&get_editor(), path)?;
+
+    // Check if file was modified
+    let mtime_after = get_mtime(path)?;
+    let modified = mtime_after != mtime_before;
+
+    let content = if modified {
+        Some(fs::read_to_string(path).context("Failed to read file after editing")?)
+    } else {
+        None
+    };
+
+    Ok(EditorResult { modified, content })
+}
+
+/// Where [`edit_private`] may put its copy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TempLocation {
+    /// Memory-backed filesystems only; for text that must never reach disk
+    /// in plaintext (decrypted notes)
+    MemoryOnly,
+    /// Memory-backed if available, else the system temp directory
+    PreferMemory,
+}
+
+/// Edit a private copy of some text instead of the file it came from
+///
+/// The text is written to a file in a fresh owner-only directory, preferably
+/// on a memory-backed filesystem (`$XDG_RUNTIME_DIR`, else `/dev/shm`). The
+/// directory is removed with everything the editor left in it (swap and
+/// backup files) as soon as the editor exits. With
+/// [`TempLocation::MemoryOnly`], fails rather than fall back to a
+/// disk-backed temp directory. `file_name` keeps the extension for syntax
+/// highlighting.
+pub fn edit_private(
+    content: &str,
+    file_name: &str,
+    location: TempLocation,
+) -> Result<EditorResult> {
+    edit_private_with(&get_editor(), content, file_name, location)
+}
+
+fn edit_private_with(
+    editor: &str,
+    content: &str,
+    file_name: &str,
+    location: TempLocation,
+) -> Result<EditorResult> {
+    let root = match (memory_temp_dir(), location) {
+        (Some(dir), _) => dir,
+        (None, TempLocation::PreferMemory) => env::temp_dir(),
+        (None, TempLocation::MemoryOnly) => anyhow::bail!(
+            "No memory-backed temp directory for decrypted notes (set $XDG_RUNTIME_DIR)"
+        ),
+    };
+    let dir = PrivateDir::create(&root)?;
+    let path = dir.path.join(file_name);
+    write_private(&path, content)?;
+
+    run_editor(editor, &path)?;
+
+    let edited = fs::read_to_string(&path).context("Failed to read file after editing")?;
+    let modified = edited != content;
+    Ok(EditorResult {
+        modified,
+        content: modified.then_some(edited),
+    })
+}
+
+/// Spawn the editor on `path` and wait for it to exit
+fn run_editor(editor: &str, path: &Path) -> Result<()> {
     // Split editor string to handle cases like "code --wait" or "vim -u NONE"
     let mut parts = editor.split_whitespace();
     let program = parts.next().context("Empty editor command")?;
@@ -85,18 +154,61 @@ pub fn edit_file(path: &Path) -> Result<EditorResult> {
     if !status.success() {
         anyhow::bail!("Editor exited with error: {:?}", status.code());
     }
+    Ok(())
+}
+
+/// A memory-backed directory for short-lived private files, if there is one
+fn memory_temp_dir() -> Option<PathBuf> {
+    env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .into_iter()
+        .chain([PathBuf::from("/dev/shm")])
+        .find(|dir| dir.is_dir())
+}
+
+/// Owner-only temp directory, removed with its contents on drop
+struct PrivateDir {
+    path: PathBuf,
+}
+
+impl PrivateDir {
+    fn create(root: &Path) -> Result<Self> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let path = root.join(format!("vibe-manager-{}-{}", std::process::id(), nanos));
+
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        builder
+            .create(&path)
+            .with_context(|| format!("Failed to create {:?}", path))?;
+        Ok(Self { path })
+    }
+}
 
-    // Check if file was modified
-    let mtime_after = get_mtime(path)?;
-    let modified = mtime_after != mtime_before;
+impl Drop for PrivateDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
 
-    let content = if modified {
-        Some(fs::read_to_string(path).context("Failed to read file after editing")?)
-    } else {
-        None
-    };
+/// Create a file readable only by its owner
+fn write_private(path: &Path, content: &str) -> Result<()> {
+    use std::io::Write;
 
-    Ok(EditorResult { modified, content })
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to create {:?}", path))?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
 }
 
 /// Get file modification time
@@ -136,4 +248,39 @@ mod tests {
             None => env::remove_var("VISUAL"),
         }
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_edit_private_leaves_nothing_behind() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let Some(root) = memory_temp_dir() else {
+            return; // no tmpfs on this machine
+        };
+        let scripts = tempfile::TempDir::new().unwrap();
+        // Appends a line and leaves a swap file next to the note, like vim
+        let editor = scripts.path().join("fake-editor");
+        fs::write(
+            &editor,
+            "#!/bin/sh\necho edited >> \"$1\"\ntouch \"$1.swp\"\necho \"$1\" > \"$0.seen\"\n",
+        )
+        .unwrap();
+        fs::set_permissions(&editor, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let result = edit_private_with(
+            editor.to_str().unwrap(),
+            "secret\n",
+            "note.md",
+            TempLocation::MemoryOnly,
+        )
+        .unwrap();
+        assert!(result.modified);
+        assert_eq!(result.content.as_deref(), Some("secret\nedited\n"));
+
+        let seen = fs::read_to_string(scripts.path().join("fake-editor.seen")).unwrap();
+        let edited = PathBuf::from(seen.trim());
+        assert!(edited.starts_with(&root));
+        assert!(!edited.exists());
+        assert!(!edited.parent().unwrap().exists());
+    }
 }
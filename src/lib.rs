@@ -13,6 +13,7 @@
 //! ## Modules
 //!
 //! - [`app`] - Application state and TEA runtime
+//! - [`calendar`] - iCalendar import
 //! - [`components`] - Reusable UI widgets
 //! - [`editor`] - External editor integration
 //! - [`model`] - Data structures (Report, JournalEntry, Workspace)
@@ -20,8 +21,10 @@
 //! - [`theme`] - 8-bit color palette and styling
 //! - [`utils`] - Utility functions
 //! - [`views`] - Full-screen layouts
+//! - [`watch`] - Live reload of files changed outside the app
 
 pub mod app;
+pub mod calendar;
 pub mod components;
 pub mod editor;
 pub mod model;
@@ -29,3 +32,4 @@ pub mod storage;
 pub mod theme;
 pub mod utils;
 pub mod views;
+pub mod watch;
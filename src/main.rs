@@ -4,21 +4,45 @@
 
 use std::io;
 use std::path::PathBuf;
+use std::sync::mpsc;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
+use chrono::{Local, Utc};
 use clap::{Parser, Subcommand};
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, Event},
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture, Event,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Position, Rect, Size},
+    Terminal,
+};
+use zeroize::Zeroizing;
 
-use vibe_manager::app::{self, handle_key_event, poll_event, App, Effect, ViewMode};
-use vibe_manager::editor;
-use vibe_manager::storage::{self, WorkspaceRepository};
-use vibe_manager::views::{render_dashboard_view, render_detail_view, render_viewer_view};
+use vibe_manager::app::{
+    self, handle_key_event, handle_mouse_event, is_content_empty, poll_event, App, Effect, Msg,
+    ViewMode,
+};
+use vibe_manager::calendar::{self, ImportPlan};
+use vibe_manager::editor::{self, TempLocation};
+use vibe_manager::model::{
+    compute_meeting_prep, EditingMode, MeetingPrep, MoodTrend, ReportSummary, UPCOMING_DAYS,
+};
+use vibe_manager::storage::{
+    self, doctor, migrate, Problem, Severity, SyncOutcome, WorkspaceRepository,
+};
+use vibe_manager::theme;
+use vibe_manager::views::{
+    hit_test, render_command_palette, render_dashboard_view, render_detail_view,
+    render_editor_view, render_heatmap_view, render_history_view, render_meeting_prep_view,
+    render_mood_analytics_view, render_viewer_view, underlying_view,
+};
+use vibe_manager::watch::WorkspaceWatcher;
 
 #[derive(Parser)]
 #[command(name = "vibe-manager")]
@@ -41,6 +65,96 @@ enum Commands {
         #[arg(default_value = ".")]
         path: PathBuf,
     },
+    /// Explain why a report has the urgency score it does
+    Explain {
+        /// Slug of the report (their directory name)
+        slug: String,
+
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// Print what to go over in a report's next 1-on-1
+    Prep {
+        /// Slug of the report (their directory name)
+        slug: String,
+
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// Record past 1-on-1s from an iCalendar (.ics) export
+    ImportIcs {
+        /// Calendar file to import
+        file: PathBuf,
+
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Show what would be recorded without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Write upcoming 1-on-1s, skip-levels, birthdays and anniversaries as iCalendar
+    ExportIcs {
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// File to write (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Encrypt all profiles and notes with a passphrase
+    Encrypt {
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// Decrypt all profiles and notes and turn encryption off
+    Decrypt {
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// Check every profile and note for problems the app would hide
+    Doctor {
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Repair what can be repaired safely
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Upgrade the workspace to the current file layout
+    Migrate {
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Show what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the backup copy in <workspace>.vibe-backups/ beside the workspace
+        #[arg(long)]
+        no_backup: bool,
+    },
+    /// Commit, pull and push the workspace's git repository
+    Sync {
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// Pull 1-on-1s from the configured CalDAV calendar
+    #[cfg(feature = "caldav")]
+    SyncCalendar {
+        /// Path to workspace directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
 }
 
 fn main() -> Result<()> {
@@ -50,6 +164,45 @@ fn main() -> Result<()> {
         Some(Commands::Init { path }) => {
             init_workspace(&path)?;
         }
+        Some(Commands::Explain { slug, path }) => {
+            explain_report(&path, &slug)?;
+        }
+        Some(Commands::Prep { slug, path }) => {
+            prep_report(&path, &slug)?;
+        }
+        Some(Commands::ImportIcs {
+            file,
+            path,
+            dry_run,
+        }) => {
+            import_ics(&path, &file, dry_run)?;
+        }
+        Some(Commands::ExportIcs { path, output }) => {
+            export_ics(&path, output.as_ref())?;
+        }
+        Some(Commands::Encrypt { path }) => {
+            encrypt_workspace(&path)?;
+        }
+        Some(Commands::Decrypt { path }) => {
+            decrypt_workspace(&path)?;
+        }
+        Some(Commands::Doctor { path, fix }) => {
+            doctor(&path, fix)?;
+        }
+        Some(Commands::Migrate {
+            path,
+            dry_run,
+            no_backup,
+        }) => {
+            migrate_workspace(&path, dry_run, !no_backup)?;
+        }
+        Some(Commands::Sync { path }) => {
+            sync_workspace(&path)?;
+        }
+        #[cfg(feature = "caldav")]
+        Some(Commands::SyncCalendar { path }) => {
+            sync_calendar(&path)?;
+        }
         None => {
             run_tui(&cli.path)?;
         }
@@ -83,6 +236,594 @@ fn init_workspace(path: &PathBuf) -> Result<()> {
     Ok(())
 }
 
+/// Environment variable that supplies the passphrase non-interactively
+const PASSPHRASE_ENV: &str = "VIBE_MANAGER_PASSPHRASE";
+
+/// Read a passphrase from the environment or, failing that, the terminal
+fn read_passphrase(prompt: &str) -> Result<Zeroizing<String>> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        return Ok(Zeroizing::new(passphrase));
+    }
+    Ok(Zeroizing::new(
+        rpassword::prompt_password(prompt).context("Failed to read passphrase")?,
+    ))
+}
+
+/// Open a workspace, asking for the passphrase if it is encrypted
+fn open_workspace(abs_path: &PathBuf) -> Result<WorkspaceRepository> {
+    let mut repo = WorkspaceRepository::open(abs_path).context("Failed to open workspace")?;
+    if repo.is_locked() {
+        let passphrase = read_passphrase("Workspace passphrase: ")?;
+        repo.unlock(&passphrase)
+            .context("Failed to unlock workspace")?;
+    }
+    recover_interrupted_writes(&repo)?;
+    Ok(repo)
+}
+
+/// Offer to restore or discard saves a crash left half-done
+///
+/// Only asks on an interactive terminal; otherwise the temp files are
+/// listed and left alone for the next interactive start.
+fn recover_interrupted_writes(repo: &WorkspaceRepository) -> Result<()> {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let workspace = repo.load().context("Failed to load workspace")?;
+    if workspace.interrupted_writes.is_empty() {
+        return Ok(());
+    }
+    let interactive = io::stdin().is_terminal();
+    eprintln!(
+        "Found {} save(s) interrupted before they completed:",
+        workspace.interrupted_writes.len()
+    );
+    for temp in &workspace.interrupted_writes {
+        let shown = temp.strip_prefix(repo.path()).unwrap_or(temp);
+        if !interactive {
+            eprintln!("  {}", shown.display());
+            continue;
+        }
+        loop {
+            eprint!(
+                "  {}: [r]estore newer version, [d]iscard it, [s]kip? ",
+                shown.display()
+            );
+            io::stderr().flush()?;
+            let mut answer = String::new();
+            io::stdin().lock().read_line(&mut answer)?;
+            let result = match answer.trim().to_ascii_lowercase().as_str() {
+                "r" => repo.restore_interrupted_write(temp).map(|_| "restored"),
+                "d" => repo.discard_interrupted_write(temp).map(|_| "discarded"),
+                "s" | "" => Ok("skipped"),
+                _ => continue,
+            };
+            match result {
+                Ok(done) => eprintln!("    {}", done),
+                Err(e) => eprintln!("    failed: {}", e),
+            }
+            break;
+        }
+    }
+    if !interactive {
+        eprintln!("Run vibe-manager interactively to restore or discard them.");
+    }
+    Ok(())
+}
+
+fn encrypt_workspace(path: &PathBuf) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let mut repo = WorkspaceRepository::open(&abs_path).context("Failed to open workspace")?;
+    let passphrase = if repo.is_encrypted() {
+        // Resume an interrupted run with the existing key
+        let passphrase = read_passphrase("Workspace passphrase: ")?;
+        repo.unlock(&passphrase)
+            .context("Failed to unlock workspace")?;
+        passphrase
+    } else {
+        let passphrase = read_passphrase("New passphrase: ")?;
+        if passphrase.is_empty() {
+            anyhow::bail!("Passphrase must not be empty");
+        }
+        if std::env::var(PASSPHRASE_ENV).is_err()
+            && *read_passphrase("Repeat passphrase: ")? != *passphrase
+        {
+            anyhow::bail!("Passphrases don't match");
+        }
+        passphrase
+    };
+
+    let converted = repo.encrypt(&passphrase)?;
+    println!("✓ Encrypted {} file(s)", converted);
+    println!("  There is no way to recover the notes without the passphrase.");
+    if storage::GitRepo::discover(&abs_path).is_some() {
+        println!("  Earlier plaintext versions remain in git history.");
+    }
+    for dir in migrate::existing_backups(&abs_path) {
+        println!(
+            "  Migration backups in {} are still plaintext; delete them once you don't need them.",
+            dir.display()
+        );
+    }
+    Ok(())
+}
+
+fn decrypt_workspace(path: &PathBuf) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let mut repo = open_workspace(&abs_path)?;
+    if !repo.is_encrypted() {
+        println!("Workspace is not encrypted");
+        return Ok(());
+    }
+    let converted = repo.decrypt()?;
+    println!("✓ Decrypted {} file(s)", converted);
+    Ok(())
+}
+
+fn explain_report(path: &PathBuf, slug: &str) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    // Load through App so the score matches the dashboard exactly,
+    // including any squad bonus a manager inherits
+    let app = App::from_repo(open_workspace(&abs_path)?).context("Failed to load workspace")?;
+    let Some(idx) = app.reports.iter().position(|r| r.slug == slug) else {
+        eprintln!("Error: No report named '{}'", slug);
+        std::process::exit(1);
+    };
+
+    print!("{}", format_explanation(&app.summaries[idx]));
+    Ok(())
+}
+
+/// Render a report's urgency breakdown as plain text
+fn format_explanation(summary: &ReportSummary) -> String {
+    let mut out = format!("{} — urgency {}\n", summary.name, summary.urgency_score);
+    if summary.urgency_breakdown.contributions.is_empty() {
+        out.push_str("  Nothing pressing\n");
+    }
+    for (factor, points) in &summary.urgency_breakdown.contributions {
+        out.push_str(&format!(
+            "  {:>5}  {}\n",
+            format!("+{}", points),
+            factor.label()
+        ));
+    }
+    out
+}
+
+fn prep_report(path: &PathBuf, slug: &str) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    // Through App, like `explain`, so a manager's squad is summarised
+    let app = App::from_repo(open_workspace(&abs_path)?).context("Failed to load workspace")?;
+    let Some(idx) = app.reports.iter().position(|r| r.slug == slug) else {
+        eprintln!("Error: No report named '{}'", slug);
+        std::process::exit(1);
+    };
+
+    let prep = compute_meeting_prep(
+        &app.reports[idx],
+        &app.entries_by_report[idx],
+        &app.summaries[idx],
+        Local::now().date_naive(),
+    );
+    print!("{}", format_meeting_prep(&prep));
+    Ok(())
+}
+
+/// Render meeting prep as plain text, the notes last since they're longest
+fn format_meeting_prep(prep: &MeetingPrep) -> String {
+    let mut out = match prep.last_meeting {
+        Some(date) => format!("{} — last 1-on-1 {}\n", prep.name, date),
+        None => format!("{} — no 1-on-1s yet\n", prep.name),
+    };
+
+    out.push_str("\nAction items\n");
+    if prep.action_items.is_empty() {
+        out.push_str("  None open\n");
+    }
+    for item in &prep.action_items {
+        out.push_str(&format!("  [ ] {} ({})\n", item.text, item.date));
+    }
+
+    out.push_str("\nMood\n");
+    if prep.moods.is_empty() {
+        out.push_str("  No readings\n");
+    } else {
+        let moods: Vec<String> = prep.moods.iter().map(u8::to_string).collect();
+        let trend = match prep.mood_trend {
+            Some(MoodTrend::Rising) => " (rising)",
+            Some(MoodTrend::Falling) => " (falling)",
+            Some(MoodTrend::Stable) => " (steady)",
+            None => "",
+        };
+        out.push_str(&format!("  {}{}\n", moods.join(" "), trend));
+    }
+
+    out.push_str("\nSince last 1-on-1\n");
+    if prep.observations.is_empty() {
+        out.push_str("  Nothing logged\n");
+    }
+    for observation in &prep.observations {
+        let mut line = format!("  {} {}", observation.date, observation.context.as_str());
+        if let Some(mood) = observation.mood {
+            line.push_str(&format!(" · mood {}", mood));
+        }
+        if let Some(summary) = &observation.summary {
+            line.push_str(&format!(" · {}", summary));
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    out.push_str("\nComing up\n");
+    if prep.upcoming_dates.is_empty() {
+        out.push_str(&format!("  Nothing in the next {} days\n", UPCOMING_DAYS));
+    }
+    for date in &prep.upcoming_dates {
+        out.push_str(&format!(
+            "  {} {} ({})\n",
+            date.date,
+            date.label(),
+            date.when()
+        ));
+    }
+
+    out.push_str("\nGoals\n");
+    if prep.goals.is_empty() {
+        out.push_str("  None under ## Goals in their profile\n");
+    }
+    for goal in &prep.goals {
+        out.push_str(&format!("  - {}\n", goal));
+    }
+
+    if let Some(outliers) = &prep.squad_outliers {
+        out.push_str("\nSquad\n");
+        if outliers.is_empty() {
+            out.push_str("  All well\n");
+        }
+        for outlier in outliers {
+            out.push_str(&format!("  {} — {}\n", outlier.name, outlier.label()));
+        }
+    }
+
+    for note in &prep.recent_notes {
+        out.push_str(&format!("\n── 1-on-1 {}", note.date));
+        if let Some(mood) = note.mood {
+            out.push_str(&format!(" · mood {}", mood));
+        }
+        out.push_str(" ──\n");
+        for line in note.content.lines() {
+            if !line.is_empty() {
+                out.push_str("  ");
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn import_ics(path: &PathBuf, file: &PathBuf, dry_run: bool) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let repo = open_workspace(&abs_path)?;
+    let settings = repo
+        .load()
+        .context("Failed to load workspace")?
+        .config
+        .settings;
+    let contents =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read {:?}", file))?;
+    let parsed = calendar::parse_ics(&contents)?;
+    let plan = calendar::plan_import(
+        &repo,
+        &parsed,
+        &settings.calendar,
+        Local::now().naive_local(),
+    )?;
+
+    print!("{}", format_import_plan(&plan, dry_run));
+    if !dry_run {
+        let written = calendar::apply_import(&plan)?;
+        println!("✓ Recorded {} meeting(s)", written);
+    }
+    Ok(())
+}
+
+fn export_ics(path: &PathBuf, output: Option<&PathBuf>) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let repo = open_workspace(&abs_path)?;
+    let settings = repo
+        .load()
+        .context("Failed to load workspace")?
+        .config
+        .settings;
+    let events = calendar::build_schedule(&repo, &settings, Local::now().date_naive())?;
+    let ics = calendar::write_ics(&events, Utc::now().naive_utc());
+
+    match output {
+        Some(file) => {
+            std::fs::write(file, ics).with_context(|| format!("Failed to write {:?}", file))?;
+            println!("✓ Wrote {} event(s) to {:?}", events.len(), file);
+        }
+        None => print!("{}", ics),
+    }
+    Ok(())
+}
+
+fn doctor(path: &PathBuf, fix: bool) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let repo = open_workspace(&abs_path)?;
+    let shown = |path: &std::path::Path| {
+        path.strip_prefix(&abs_path)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    if fix {
+        let mut fixable: Vec<_> = doctor::diagnose(&repo)?
+            .into_iter()
+            .filter(|issue| issue.problem.is_fixable())
+            .collect();
+        // Moving a note invalidates other issues' paths, so move last
+        fixable.sort_by_key(|issue| issue.problem == Problem::LegacyEntry);
+        for issue in &fixable {
+            match doctor::repair(&repo, issue) {
+                Ok(_) => println!("✓ Fixed {}: {}", shown(&issue.path), issue.problem),
+                Err(e) => eprintln!("✗ Couldn't fix {}: {}", shown(&issue.path), e),
+            }
+        }
+        if !fixable.is_empty() {
+            println!();
+        }
+    }
+
+    let issues = doctor::diagnose(&repo)?;
+    for issue in &issues {
+        let mark = match issue.problem.severity() {
+            Severity::Error => "✗",
+            Severity::Warning => "⚠",
+        };
+        let hint = if issue.problem.is_fixable() {
+            " (--fix)"
+        } else {
+            ""
+        };
+        println!("{} {}: {}{}", mark, shown(&issue.path), issue.problem, hint);
+    }
+
+    let errors = issues
+        .iter()
+        .filter(|issue| issue.problem.severity() == Severity::Error)
+        .count();
+    if issues.is_empty() {
+        println!("✓ No problems found");
+    } else {
+        println!();
+        println!("{} error(s), {} warning(s)", errors, issues.len() - errors);
+    }
+    if errors > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn migrate_workspace(path: &PathBuf, dry_run: bool, backup: bool) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let repo = open_workspace(&abs_path)?;
+    let shown = |change: &migrate::Change| {
+        change
+            .to_string()
+            .replace(&format!("{}/", abs_path.display()), "")
+    };
+
+    if dry_run {
+        let steps = migrate::pending(&repo)?;
+        if steps.is_empty() {
+            println!("✓ Workspace is up to date");
+        }
+        for step in &steps {
+            println!("Version {}: {}", step.to, step.description);
+            for change in &step.changes {
+                println!("  {}", shown(change));
+            }
+            for conflict in &step.conflicts {
+                println!("  ✗ {}", conflict);
+            }
+        }
+        return Ok(());
+    }
+
+    let report = migrate::migrate(&repo, backup).context("Migration failed")?;
+    if let Some(backup) = &report.backup {
+        println!("✓ Backed up to {}", backup.display());
+    }
+    if report.steps.is_empty() {
+        println!("✓ Workspace is up to date");
+    }
+    for step in &report.steps {
+        println!(
+            "✓ Migrated to version {} ({} change(s)): {}",
+            step.to,
+            step.changes.len(),
+            step.description
+        );
+    }
+    Ok(())
+}
+
+fn sync_workspace(path: &PathBuf) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let repo = open_workspace(&abs_path)?;
+    let settings = repo
+        .load()
+        .context("Failed to load workspace")?
+        .config
+        .settings;
+    match repo.sync(&settings.git)? {
+        SyncOutcome::Synced { committed: true } => {
+            println!("✓ Committed local changes, pulled and pushed")
+        }
+        SyncOutcome::Synced { committed: false } => println!("✓ Pulled and pushed"),
+        SyncOutcome::Conflicts(files) => {
+            eprintln!("Sync stopped: these files changed on both sides:");
+            for file in &files {
+                eprintln!("  {}", file);
+            }
+            eprintln!();
+            eprintln!("Nothing was overwritten. Merge with git, then sync again.");
+            std::process::exit(1);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "caldav")]
+fn sync_calendar(path: &PathBuf) -> Result<()> {
+    let abs_path = if path.is_absolute() {
+        path.clone()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let repo = open_workspace(&abs_path)?;
+    let settings = repo
+        .load()
+        .context("Failed to load workspace")?
+        .config
+        .settings;
+    let summary = calendar::caldav::sync_workspace(&repo, &settings, Local::now().naive_local())?;
+    println!("✓ {}", summary.message());
+    Ok(())
+}
+
+/// Start a CalDAV sync on a background thread if the workspace opted into
+/// `auto_sync`
+///
+/// The TUI picks up the outcome from the channel between frames, so a slow
+/// or unreachable server never blocks input.
+#[cfg(feature = "caldav")]
+fn start_calendar_sync(app: &App, tx: mpsc::Sender<Msg>) {
+    let settings = app.workspace.config.settings.clone();
+    if !settings
+        .calendar
+        .caldav
+        .as_ref()
+        .is_some_and(|c| c.auto_sync)
+    {
+        return;
+    }
+    let repo = app.repo.clone();
+    std::thread::spawn(move || {
+        let outcome =
+            match calendar::caldav::sync_workspace(&repo, &settings, Local::now().naive_local()) {
+                Ok(summary) => summary.message(),
+                Err(e) => format!("Calendar sync failed: {}", e),
+            };
+        let _ = tx.send(Msg::CalendarSynced(outcome));
+    });
+}
+
+#[cfg(not(feature = "caldav"))]
+fn start_calendar_sync(_app: &App, _tx: mpsc::Sender<Msg>) {}
+
+/// Run a git sync on a background thread, answering with `Msg::WorkspaceSynced`
+fn start_workspace_sync(app: &App, tx: mpsc::Sender<Msg>) {
+    let settings = app.workspace.config.settings.git.clone();
+    let repo = app.repo.clone();
+    std::thread::spawn(move || {
+        let result = repo.sync(&settings).map_err(|e| e.to_string());
+        let _ = tx.send(Msg::WorkspaceSynced(result));
+    });
+}
+
+/// Describe an import plan as plain text
+fn format_import_plan(plan: &ImportPlan, dry_run: bool) -> String {
+    let verb = if dry_run { "Would record" } else { "Recording" };
+    let mut out = String::new();
+    for meeting in &plan.meetings {
+        out.push_str(&format!(
+            "  {} {}  {}  ({})\n",
+            verb,
+            meeting.start.format("%Y-%m-%d %H:%M"),
+            meeting.name,
+            meeting.summary
+        ));
+    }
+    if plan.meetings.is_empty() {
+        out.push_str("  No new meetings to record\n");
+    }
+    for planned in &plan.planned {
+        let next = planned
+            .next
+            .map(|next| next.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "none".to_string());
+        out.push_str(&format!("  Next 1-on-1 with {}: {}\n", planned.name, next));
+    }
+    if plan.already_recorded > 0 {
+        out.push_str(&format!(
+            "  {} occurrence(s) already in the journal\n",
+            plan.already_recorded
+        ));
+    }
+    for title in &plan.ambiguous {
+        out.push_str(&format!(
+            "  Skipped '{}': matches multiple reports\n",
+            title
+        ));
+    }
+    for title in &plan.unmatched {
+        out.push_str(&format!("  Skipped '{}': no matching report\n", title));
+    }
+    for reason in &plan.skipped {
+        out.push_str(&format!("  Skipped unreadable event {}\n", reason));
+    }
+    out
+}
+
 fn run_tui(path: &PathBuf) -> Result<()> {
     let abs_path = if path.is_absolute() {
         path.clone()
@@ -101,17 +842,33 @@ fn run_tui(path: &PathBuf) -> Result<()> {
         std::process::exit(1);
     }
 
+    // Unlock before taking over the terminal, so the prompt is a plain line
+    let repo = open_workspace(&abs_path)?;
+    let user_theme = theme::load_user_theme()?;
+    let keymap = app::keymap::load_user_keymap()?;
+
     // Setup terminal
     enable_raw_mode().context("Failed to enable raw mode")?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
-        .context("Failed to enter alternate screen")?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )
+    .context("Failed to enter alternate screen")?;
 
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).context("Failed to create terminal")?;
 
     // Create app
-    let mut app = App::new(abs_path).context("Failed to load workspace")?;
+    // A view file that can't be read just means the default view
+    let roster_view = repo.load_roster_view().unwrap_or_default();
+    let mut app = App::from_repo(repo)
+        .and_then(|app| app.with_roster_view(roster_view))
+        .context("Failed to load workspace")?
+        .with_user_theme(user_theme)
+        .with_keymap(keymap);
 
     // Main loop
     let result = run_app(&mut terminal, &mut app);
@@ -121,7 +878,8 @@ fn run_tui(path: &PathBuf) -> Result<()> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )
     .context("Failed to leave alternate screen")?;
     terminal.show_cursor().context("Failed to show cursor")?;
@@ -130,49 +888,89 @@ fn run_tui(path: &PathBuf) -> Result<()> {
 }
 
 fn run_app(terminal: &mut app::Term, app: &mut App) -> Result<()> {
+    // Background work (calendar and git sync) reports back as messages
+    let (tx, rx) = mpsc::channel();
+    start_calendar_sync(app, tx.clone());
+    let _watcher = start_file_watcher(app, tx.clone());
+    app.update(Msg::Resize(terminal.size()?))?;
+
     loop {
         // Render
-        terminal.draw(|frame| match app.view_mode {
-            ViewMode::Dashboard | ViewMode::Help | ViewMode::NewReportModal => {
-                render_dashboard_view(app, frame);
-            }
-            ViewMode::ReportDetail | ViewMode::EntryInputModal => {
-                render_detail_view(app, frame);
-            }
-            ViewMode::NoteViewer => {
-                render_viewer_view(app, frame);
-            }
-            ViewMode::DeleteConfirmModal => {
-                // Render the appropriate view based on where delete was triggered
-                if app.delete_from_list {
+        terminal.draw(|frame| {
+            match underlying_view(app) {
+                ViewMode::Dashboard
+                | ViewMode::Help
+                | ViewMode::NewReportModal
+                | ViewMode::Warnings
+                | ViewMode::MigrateConfirmModal => {
+                    render_dashboard_view(app, frame);
+                }
+                ViewMode::ReportDetail | ViewMode::EntryInputModal => {
                     render_detail_view(app, frame);
-                } else {
+                }
+                ViewMode::NoteViewer => {
                     render_viewer_view(app, frame);
                 }
+                ViewMode::NoteEditor => {
+                    render_editor_view(app, frame);
+                }
+                ViewMode::MoodAnalytics => {
+                    render_mood_analytics_view(app, frame);
+                }
+                ViewMode::MeetingPrep => {
+                    render_meeting_prep_view(app, frame);
+                }
+                ViewMode::MoodHeatmap => {
+                    render_heatmap_view(app, frame);
+                }
+                ViewMode::History => {
+                    render_history_view(app, frame);
+                }
+                ViewMode::DeleteConfirmModal => {
+                    // Render the appropriate view based on where delete was triggered
+                    if app.delete_from_list {
+                        render_detail_view(app, frame);
+                    } else {
+                        render_viewer_view(app, frame);
+                    }
+                }
+                ViewMode::CommandPalette => {}
             }
+            render_command_palette(app, frame);
         })?;
 
         // Handle events
         if let Some(event) = poll_event(Duration::from_millis(100))? {
-            match event {
-                Event::Key(key) => {
-                    if let Some(msg) = handle_key_event(app, key) {
-                        // Process the returned effect from update
-                        match app.update(msg)? {
-                            Effect::None => {}
-                            Effect::SpawnEditor { is_new } => {
-                                suspend_and_edit(terminal, app, is_new)?;
-                            }
-                        }
-                    }
+            let msg = match event {
+                Event::Key(key) => handle_key_event(app, key),
+                Event::Mouse(mouse) => {
+                    let screen = Rect::from((Position::ORIGIN, terminal.size()?));
+                    let target = hit_test(app, screen, Position::new(mouse.column, mouse.row));
+                    handle_mouse_event(app, mouse, target)
                 }
-                Event::Resize(_, _) => {
-                    // Terminal will redraw automatically
+                Event::Paste(text) => Some(Msg::Paste(text)),
+                Event::Resize(width, height) => Some(Msg::Resize(Size::new(width, height))),
+                _ => None,
+            };
+            if let Some(msg) = msg {
+                // Process the returned effect from update
+                match app.update(msg)? {
+                    Effect::None => {}
+                    Effect::SpawnEditor { is_new } => {
+                        suspend_and_edit(terminal, app, is_new)?;
+                    }
+                    Effect::SyncWorkspace => {
+                        start_workspace_sync(app, tx.clone());
+                    }
                 }
-                _ => {}
             }
         }
 
+        // Pick up finished background work
+        while let Ok(msg) = rx.try_recv() {
+            app.update(msg)?;
+        }
+
         // Clear expired status messages
         app.clear_expired_status();
 
@@ -184,6 +982,23 @@ fn run_app(terminal: &mut app::Term, app: &mut App) -> Result<()> {
     Ok(())
 }
 
+/// Reload notes and profiles changed outside the app as they change
+///
+/// Live reload is a convenience: if the watcher can't start, say so and
+/// carry on with Ctrl+R.
+fn start_file_watcher(app: &mut App, tx: mpsc::Sender<Msg>) -> Option<WorkspaceWatcher> {
+    let on_change = move |paths| {
+        let _ = tx.send(Msg::FilesChanged(paths));
+    };
+    match WorkspaceWatcher::start(app.repo.path(), on_change) {
+        Ok(watcher) => Some(watcher),
+        Err(e) => {
+            app.set_status(format!("Live reload off: {:#}", e));
+            None
+        }
+    }
+}
+
 /// Suspend the TUI and spawn an external editor for the current entry note
 ///
 /// If `is_new` is true, this is a newly created entry. If the user saves an empty
@@ -201,23 +1016,54 @@ fn suspend_and_edit(terminal: &mut app::Term, app: &mut App, is_new: bool) -> Re
     let entry = &app.entries_by_report[report_idx][entry_idx];
     let file_path = entry.path.clone();
 
+    // Private editing works on a copy and writes it back through the
+    // repository; encrypted notes must be edited that way
+    let location = if app.repo.is_encrypted() {
+        Some(TempLocation::MemoryOnly)
+    } else if app.workspace.config.settings.editing == EditingMode::Private {
+        Some(TempLocation::PreferMemory)
+    } else {
+        None
+    };
+    let original = match location {
+        Some(_) => match app.repo.read_text(&file_path) {
+            Ok(text) => Some(text),
+            Err(e) => {
+                app.set_status(format!("Error: {}", e));
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+
     // Leave alternate screen and disable raw mode
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     disable_raw_mode()?;
 
     // Spawn external editor
-    let result = editor::edit_file(&file_path);
+    let result = match (&original, location) {
+        (Some(text), Some(location)) => {
+            let file_name = file_path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "note.md".to_string());
+            edit_copy_until_valid(text, &file_name, location)
+        }
+        _ => editor::edit_file(&file_path).map(Some),
+    };
 
     // Re-enable raw mode and enter alternate screen
     enable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         EnterAlternateScreen,
-        EnableMouseCapture
+        EnableMouseCapture,
+        EnableBracketedPaste
     )?;
 
     // Clear screen for clean redraw
@@ -225,10 +1071,14 @@ fn suspend_and_edit(terminal: &mut app::Term, app: &mut App, is_new: bool) -> Re
 
     // Handle editor result
     match result {
-        Ok(editor_result) => {
+        Ok(None) => {
+            app.set_status("Changes discarded");
+        }
+        Ok(Some(editor_result)) => {
             // Read the current content (may have been modified)
             let raw_content = editor_result
                 .content
+                .or_else(|| original.clone())
                 .or_else(|| std::fs::read_to_string(&file_path).ok())
                 .unwrap_or_default();
 
@@ -266,7 +1116,16 @@ fn suspend_and_edit(terminal: &mut app::Term, app: &mut App, is_new: bool) -> Re
                 let body_content = body.to_string();
                 app.editor_content = body_content.clone();
                 app.entries_by_report[report_idx][entry_idx].content = body_content;
-                app.set_status("Note updated");
+
+                // In-place editing already wrote the file itself
+                if original.is_some() {
+                    match app.save_entry(report_idx, entry_idx) {
+                        Ok(()) => app.set_status("Note updated"),
+                        Err(e) => app.set_status(format!("Error saving note: {}", e)),
+                    }
+                } else {
+                    app.set_status("Note updated");
+                }
             } else {
                 app.set_status("No changes");
             }
@@ -284,28 +1143,47 @@ fn suspend_and_edit(terminal: &mut app::Term, app: &mut App, is_new: bool) -> Re
     Ok(())
 }
 
-/// Check if meeting note content is effectively empty
-/// (only contains frontmatter and whitespace/template headers with no actual content)
-fn is_content_empty(content: &str) -> bool {
-    // Remove YAML frontmatter
-    let body = if content.starts_with("---") {
-        content.splitn(3, "---").nth(2).unwrap_or("")
-    } else {
-        content
-    };
+/// Edit a private copy of a note, re-opening it until its frontmatter is valid
+///
+/// Runs while the TUI is suspended, so problems are reported on the plain
+/// terminal. Returns `None` if the user discards their changes.
+fn edit_copy_until_valid(
+    original: &str,
+    file_name: &str,
+    location: TempLocation,
+) -> Result<Option<editor::EditorResult>> {
+    let mut text = original.to_string();
+    loop {
+        let result = editor::edit_private(&text, file_name, location)?;
+        let Some(edited) = result.content else {
+            // Unchanged since the last round: changed overall if it differs from the file
+            let modified = text != original;
+            return Ok(Some(editor::EditorResult {
+                modified,
+                content: modified.then_some(text),
+            }));
+        };
+
+        let problem = match storage::parse_entry(&edited) {
+            Ok((frontmatter, _)) => frontmatter.validate().err(),
+            Err(e) => Some(e.to_string()),
+        };
+        let Some(problem) = problem else {
+            return Ok(Some(editor::EditorResult {
+                modified: true,
+                content: Some(edited),
+            }));
+        };
 
-    // Check if remaining content is just whitespace and empty markdown structure
-    body.lines()
-        .filter(|line| {
-            let trimmed = line.trim();
-            // Skip empty lines, headers, and common template markers
-            !trimmed.is_empty()
-                && !trimmed.starts_with('#')
-                && !trimmed.starts_with("- [ ]")
-                && trimmed != "-"
-        })
-        .count()
-        == 0
+        eprintln!("\nThe note's frontmatter is invalid: {}", problem);
+        eprint!("[e]dit again or [d]iscard changes? ");
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        if answer.trim().eq_ignore_ascii_case("d") {
+            return Ok(None);
+        }
+        text = edited;
+    }
 }
 
 #[cfg(test)]
@@ -313,69 +1191,60 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_is_content_empty_with_only_whitespace() {
-        assert!(is_content_empty(""));
-        assert!(is_content_empty("   "));
-        assert!(is_content_empty("\n\n\n"));
-    }
-
-    #[test]
-    fn test_is_content_empty_with_frontmatter_only() {
-        let content = "---\nmood: 3\n---\n\n";
-        assert!(is_content_empty(content));
-    }
-
-    #[test]
-    fn test_is_content_empty_with_headers_only() {
-        let content = "# Title\n## Section\n### Subsection\n";
-        assert!(is_content_empty(content));
-    }
-
-    #[test]
-    fn test_is_content_empty_with_template_structure() {
-        let content = "---\n---\n\n# 1-on-1 - January 22, 2026\n\n## Discussion\n\n## Notes\n\n## Action Items\n- [ ] \n";
-        assert!(is_content_empty(content));
-    }
+    fn test_format_explanation_lists_squad_bonus() {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let app = App::new(fixtures).unwrap();
+        let idx = app
+            .reports
+            .iter()
+            .position(|r| r.slug == "chris-wong")
+            .unwrap();
 
-    #[test]
-    fn test_is_content_empty_with_empty_checkbox() {
-        let content = "- [ ] \n- [ ] \n";
-        assert!(is_content_empty(content));
+        let text = format_explanation(&app.summaries[idx]);
+        assert!(text.starts_with(&format!(
+            "Chris Wong — urgency {}\n",
+            app.summaries[idx].urgency_score
+        )));
+        assert!(text.contains("squad: "));
     }
 
     #[test]
-    fn test_is_content_empty_with_bare_dash() {
-        let content = "# Header\n-\n";
-        assert!(is_content_empty(content));
-    }
-
-    #[test]
-    fn test_is_content_empty_false_with_actual_content() {
-        let content = "Some actual notes here";
-        assert!(!is_content_empty(content));
-    }
+    fn test_format_meeting_prep_has_squad_for_managers_only() {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let app = App::new(fixtures).unwrap();
+        let today = Local::now().date_naive();
+        let prep_for = |idx: usize| {
+            compute_meeting_prep(
+                &app.reports[idx],
+                &app.entries_by_report[idx],
+                &app.summaries[idx],
+                today,
+            )
+        };
 
-    #[test]
-    fn test_is_content_empty_false_with_content_after_frontmatter() {
-        let content = "---\nmood: 3\n---\n\nDiscussed project timeline.";
-        assert!(!is_content_empty(content));
-    }
+        let manager = app.reports.iter().position(|r| r.is_manager()).unwrap();
+        let text = format_meeting_prep(&prep_for(manager));
+        assert!(text.starts_with(&format!("{} — ", app.reports[manager].profile.name)));
+        assert!(text.contains("\nAction items\n"));
+        assert!(text.contains("\nSquad\n"));
 
-    #[test]
-    fn test_is_content_empty_false_with_filled_checkbox() {
-        let content = "- [x] Completed task\n";
-        assert!(!is_content_empty(content));
+        let ic = app.reports.iter().position(|r| !r.is_manager()).unwrap();
+        assert!(!format_meeting_prep(&prep_for(ic)).contains("\nSquad\n"));
     }
 
     #[test]
-    fn test_is_content_empty_false_with_list_items() {
-        let content = "# Notes\n- First item\n- Second item\n";
-        assert!(!is_content_empty(content));
-    }
+    fn test_format_import_plan_lists_skipped_events() {
+        let mut plan = ImportPlan {
+            already_recorded: 2,
+            ..ImportPlan::default()
+        };
+        plan.unmatched.insert("Dentist".to_string());
+        plan.ambiguous.insert("Team sync".to_string());
 
-    #[test]
-    fn test_is_content_empty_false_with_content_under_headers() {
-        let content = "---\n---\n\n# 1-on-1\n\n## Discussion\n\nWe talked about the roadmap.\n";
-        assert!(!is_content_empty(content));
+        let text = format_import_plan(&plan, true);
+        assert!(text.contains("No new meetings to record"));
+        assert!(text.contains("2 occurrence(s) already in the journal"));
+        assert!(text.contains("Skipped 'Team sync': matches multiple reports"));
+        assert!(text.contains("Skipped 'Dentist': no matching report"));
     }
 }
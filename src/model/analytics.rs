@@ -0,0 +1,402 @@
+//! Time-windowed mood analytics
+//!
+//! Aggregates a report's mood readings over a selectable window into summary
+//! statistics, weekly and monthly averages, and a per-context breakdown
+//! (1-on-1 vs standup vs Slack moods). Also builds the team-wide heatmap of
+//! weekly averages across a roster.
+
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, Weekday};
+
+use super::meeting::{Context, JournalEntry};
+
+/// How far back the analytics look
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MoodWindow {
+    /// Last 30 days
+    #[default]
+    Month,
+    /// Last 90 days
+    Quarter,
+    /// Last 365 days
+    Year,
+}
+
+impl MoodWindow {
+    /// Window length in days
+    pub fn days(&self) -> u64 {
+        match self {
+            MoodWindow::Month => 30,
+            MoodWindow::Quarter => 90,
+            MoodWindow::Year => 365,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MoodWindow::Month => "30 days",
+            MoodWindow::Quarter => "90 days",
+            MoodWindow::Year => "365 days",
+        }
+    }
+
+    /// Cycle to the next (longer) window, wrapping back to 30 days
+    pub fn next(&self) -> Self {
+        match self {
+            MoodWindow::Month => MoodWindow::Quarter,
+            MoodWindow::Quarter => MoodWindow::Year,
+            MoodWindow::Year => MoodWindow::Month,
+        }
+    }
+}
+
+/// Summary statistics over a set of mood readings
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoodStats {
+    /// Number of readings
+    pub count: usize,
+    pub average: f32,
+    pub min: u8,
+    pub max: u8,
+    /// Population variance of the readings
+    pub variance: f32,
+}
+
+impl MoodStats {
+    /// Statistics for the given readings, or None if there are none
+    pub fn from_moods(moods: &[u8]) -> Option<Self> {
+        if moods.is_empty() {
+            return None;
+        }
+        let count = moods.len();
+        let average = moods.iter().map(|&m| m as f32).sum::<f32>() / count as f32;
+        let variance = moods
+            .iter()
+            .map(|&m| (m as f32 - average).powi(2))
+            .sum::<f32>()
+            / count as f32;
+        Some(Self {
+            count,
+            average,
+            min: *moods.iter().min()?,
+            max: *moods.iter().max()?,
+            variance,
+        })
+    }
+}
+
+/// Average mood over one calendar period (a week or a month)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodAverage {
+    /// First day of the period (Monday for weeks, the 1st for months)
+    pub start: NaiveDate,
+    pub average: f32,
+    /// Number of readings in the period
+    pub count: usize,
+}
+
+/// Mood analytics for one report over one window
+#[derive(Debug, Clone)]
+pub struct MoodAnalytics {
+    pub window: MoodWindow,
+    /// First day included in the window
+    pub start: NaiveDate,
+    /// Last day included in the window (today)
+    pub end: NaiveDate,
+    /// Readings inside the window, oldest first (drives the time-axis chart)
+    pub readings: Vec<(NaiveDateTime, u8)>,
+    /// Statistics across all readings in the window
+    pub overall: Option<MoodStats>,
+    /// Statistics per context, in `Context::all()` order, skipping empty ones
+    pub by_context: Vec<(Context, MoodStats)>,
+    /// Weekly averages, oldest first, skipping weeks without readings
+    pub weekly: Vec<PeriodAverage>,
+    /// Monthly averages, oldest first, skipping months without readings
+    pub monthly: Vec<PeriodAverage>,
+}
+
+/// Compute mood analytics for the window ending `today`
+///
+/// Entries without an explicit context and without notes are quick mood
+/// observations; they're counted under `Context::Other`.
+pub fn compute_mood_analytics(
+    entries: &[JournalEntry],
+    window: MoodWindow,
+    today: NaiveDate,
+) -> MoodAnalytics {
+    let start = today
+        .checked_sub_days(Days::new(window.days() - 1))
+        .unwrap_or(NaiveDate::MIN);
+
+    let mut readings: Vec<(NaiveDateTime, u8, Context)> = entries
+        .iter()
+        .filter(|e| (start..=today).contains(&e.date()))
+        .filter_map(|e| {
+            e.mood()
+                .map(|m| (e.timestamp, m, e.context().unwrap_or(Context::Other)))
+        })
+        .collect();
+    readings.sort_by_key(|r| r.0);
+
+    let moods: Vec<u8> = readings.iter().map(|r| r.1).collect();
+    let by_context = Context::all()
+        .iter()
+        .filter_map(|&context| {
+            let moods: Vec<u8> = readings
+                .iter()
+                .filter(|r| r.2 == context)
+                .map(|r| r.1)
+                .collect();
+            MoodStats::from_moods(&moods).map(|stats| (context, stats))
+        })
+        .collect();
+
+    MoodAnalytics {
+        window,
+        start,
+        end: today,
+        overall: MoodStats::from_moods(&moods),
+        by_context,
+        weekly: period_averages(&readings, |d| d.week(Weekday::Mon).first_day()),
+        monthly: period_averages(&readings, |d| d.with_day(1).unwrap_or(d)),
+        readings: readings.into_iter().map(|(ts, m, _)| (ts, m)).collect(),
+    }
+}
+
+/// Group chronologically sorted readings by period start and average each group
+fn period_averages(
+    readings: &[(NaiveDateTime, u8, Context)],
+    period_start: impl Fn(NaiveDate) -> NaiveDate,
+) -> Vec<PeriodAverage> {
+    let mut periods: Vec<(NaiveDate, u32, usize)> = Vec::new();
+    for (timestamp, mood, _) in readings {
+        let start = period_start(timestamp.date());
+        match periods.last_mut() {
+            Some((s, sum, count)) if *s == start => {
+                *sum += *mood as u32;
+                *count += 1;
+            }
+            _ => periods.push((start, *mood as u32, 1)),
+        }
+    }
+    periods
+        .into_iter()
+        .map(|(start, sum, count)| PeriodAverage {
+            start,
+            average: sum as f32 / count as f32,
+            count,
+        })
+        .collect()
+}
+
+/// Weekly average mood for every report in a roster
+#[derive(Debug, Clone)]
+pub struct MoodHeatmap {
+    /// Monday of each week column, oldest first
+    pub weeks: Vec<NaiveDate>,
+    /// One row per report, in roster order
+    pub rows: Vec<HeatmapRow>,
+    /// Average of every reading in each week across the whole roster
+    pub team: Vec<Option<f32>>,
+}
+
+/// One report's row in the heatmap
+#[derive(Debug, Clone)]
+pub struct HeatmapRow {
+    pub name: String,
+    /// Average mood per week column (None when there are no readings)
+    pub cells: Vec<Option<f32>>,
+}
+
+/// Build a heatmap of the last `weeks` weeks, ending with the week of `today`
+pub fn compute_mood_heatmap<'a>(
+    reports: impl IntoIterator<Item = (&'a str, &'a [JournalEntry])>,
+    weeks: usize,
+    today: NaiveDate,
+) -> MoodHeatmap {
+    let this_week = today.week(Weekday::Mon).first_day();
+    let week_starts: Vec<NaiveDate> = (0..weeks as u64)
+        .rev()
+        .filter_map(|back| this_week.checked_sub_days(Days::new(back * 7)))
+        .collect();
+    let first = week_starts.first().copied().unwrap_or(this_week);
+
+    let mut team_totals = vec![(0u32, 0u32); week_starts.len()];
+    let rows = reports
+        .into_iter()
+        .map(|(name, entries)| {
+            let mut totals = vec![(0u32, 0u32); week_starts.len()];
+            for entry in entries.iter().filter(|e| e.date() <= today) {
+                let Some(mood) = entry.mood() else { continue };
+                let days = (entry.date() - first).num_days();
+                if days < 0 {
+                    continue;
+                }
+                let col = (days / 7) as usize;
+                if col < totals.len() {
+                    totals[col].0 += mood as u32;
+                    totals[col].1 += 1;
+                    team_totals[col].0 += mood as u32;
+                    team_totals[col].1 += 1;
+                }
+            }
+            HeatmapRow {
+                name: name.to_string(),
+                cells: totals.into_iter().map(average).collect(),
+            }
+        })
+        .collect();
+
+    MoodHeatmap {
+        weeks: week_starts,
+        rows,
+        team: team_totals.into_iter().map(average).collect(),
+    }
+}
+
+fn average((sum, count): (u32, u32)) -> Option<f32> {
+    (count > 0).then(|| sum as f32 / count as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::JournalEntryFrontmatter;
+    use std::path::PathBuf;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn entry(day: NaiveDate, mood: u8, context: Option<Context>) -> JournalEntry {
+        JournalEntry::new(
+            day.and_hms_opt(10, 0, 0).unwrap(),
+            PathBuf::new(),
+            JournalEntryFrontmatter {
+                mood: Some(mood),
+                context,
+            },
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_mood_stats() {
+        let stats = MoodStats::from_moods(&[2, 4, 4, 2]).unwrap();
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.average, 3.0);
+        assert_eq!(stats.min, 2);
+        assert_eq!(stats.max, 4);
+        assert_eq!(stats.variance, 1.0);
+        assert!(MoodStats::from_moods(&[]).is_none());
+    }
+
+    #[test]
+    fn test_window_excludes_older_entries() {
+        let today = date(2026, 3, 31);
+        let entries = vec![
+            entry(date(2026, 3, 1), 2, Some(Context::Meeting)), // 30 days back, outside
+            entry(date(2026, 3, 2), 4, Some(Context::Meeting)),
+            entry(date(2026, 3, 31), 5, Some(Context::Meeting)),
+        ];
+
+        let month = compute_mood_analytics(&entries, MoodWindow::Month, today);
+        assert_eq!(month.start, date(2026, 3, 2));
+        assert_eq!(month.readings.len(), 2);
+        assert_eq!(month.overall.unwrap().min, 4);
+
+        let quarter = compute_mood_analytics(&entries, MoodWindow::Quarter, today);
+        assert_eq!(quarter.readings.len(), 3);
+    }
+
+    #[test]
+    fn test_by_context_breakdown() {
+        let today = date(2026, 3, 31);
+        let entries = vec![
+            entry(date(2026, 3, 10), 4, Some(Context::Meeting)),
+            entry(date(2026, 3, 11), 2, Some(Context::Standup)),
+            entry(date(2026, 3, 12), 3, Some(Context::Standup)),
+            entry(date(2026, 3, 13), 1, None), // bare observation counts as Other
+        ];
+
+        let analytics = compute_mood_analytics(&entries, MoodWindow::Month, today);
+        let contexts: Vec<_> = analytics
+            .by_context
+            .iter()
+            .map(|(c, s)| (*c, s.count))
+            .collect();
+        assert_eq!(
+            contexts,
+            vec![
+                (Context::Meeting, 1),
+                (Context::Standup, 2),
+                (Context::Other, 1)
+            ]
+        );
+        assert_eq!(analytics.by_context[1].1.average, 2.5);
+    }
+
+    #[test]
+    fn test_weekly_and_monthly_averages() {
+        let today = date(2026, 3, 31);
+        let entries = vec![
+            entry(date(2026, 2, 23), 2, None), // Monday
+            entry(date(2026, 3, 1), 4, None),  // Sunday, same week
+            entry(date(2026, 3, 2), 5, None),  // next Monday
+        ];
+
+        let analytics = compute_mood_analytics(&entries, MoodWindow::Quarter, today);
+        assert_eq!(
+            analytics.weekly,
+            vec![
+                PeriodAverage {
+                    start: date(2026, 2, 23),
+                    average: 3.0,
+                    count: 2
+                },
+                PeriodAverage {
+                    start: date(2026, 3, 2),
+                    average: 5.0,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(analytics.monthly.len(), 2);
+        assert_eq!(analytics.monthly[0].start, date(2026, 2, 1));
+        assert_eq!(analytics.monthly[1].average, 4.5);
+    }
+
+    #[test]
+    fn test_window_cycle() {
+        assert_eq!(MoodWindow::Month.next(), MoodWindow::Quarter);
+        assert_eq!(MoodWindow::Quarter.next(), MoodWindow::Year);
+        assert_eq!(MoodWindow::Year.next(), MoodWindow::Month);
+    }
+
+    #[test]
+    fn test_heatmap_weekly_cells_and_team_row() {
+        let today = date(2026, 3, 18); // Wednesday
+        let alex = vec![
+            entry(date(2026, 3, 2), 4, None),  // Monday, two weeks back
+            entry(date(2026, 3, 17), 2, None), // this week
+            entry(date(2026, 3, 19), 1, None), // future, ignored
+        ];
+        let sam = vec![
+            entry(date(2026, 3, 16), 4, None), // this week
+            entry(date(2026, 2, 20), 1, None), // before the window
+        ];
+
+        let heatmap = compute_mood_heatmap(
+            [("Alex", alex.as_slice()), ("Sam", sam.as_slice())],
+            3,
+            today,
+        );
+        assert_eq!(
+            heatmap.weeks,
+            vec![date(2026, 3, 2), date(2026, 3, 9), date(2026, 3, 16)]
+        );
+        assert_eq!(heatmap.rows[0].name, "Alex");
+        assert_eq!(heatmap.rows[0].cells, vec![Some(4.0), None, Some(2.0)]);
+        assert_eq!(heatmap.rows[1].cells, vec![None, None, Some(4.0)]);
+        assert_eq!(heatmap.team, vec![Some(4.0), None, Some(3.0)]);
+    }
+}
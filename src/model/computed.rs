@@ -6,9 +6,24 @@
 use chrono::Local;
 use ratatui::style::Color;
 
-use super::{JournalEntry, Report, ReportType};
+use super::{
+    HealthWeights, JournalEntry, MoodScoring, Report, ReportType, UrgencyWeights, WorkspaceSettings,
+};
 use crate::utils::report_color;
 
+/// Number of past mood readings the rolling baseline is computed from
+const BASELINE_WINDOW: usize = 20;
+
+/// Minimum past readings before a baseline is trusted
+const BASELINE_MIN_SAMPLES: usize = 3;
+
+/// Spread floor, so a report who always rates the same value still yields a
+/// finite deviation (a 3 from an always-5 rater scores -4.0, not infinity)
+const MIN_BASELINE_SPREAD: f32 = 0.5;
+
+/// Deviation at or below which a mood counts as low in baseline scoring
+const LOW_MOOD_DEVIATION: f32 = -1.5;
+
 /// Computed summary for a single report
 ///
 /// Contains derived metrics like days since last meeting, mood trends,
@@ -23,6 +38,9 @@ pub struct ReportSummary {
     pub level: String,
     /// Meeting frequency (weekly, biweekly, monthly)
     pub meeting_frequency: String,
+    /// Days between 1-on-1s the overdue check uses (a 2nd-level report's
+    /// may come from the workspace default rather than `meeting_frequency`)
+    pub frequency_days: u32,
     /// Whether the report is currently active
     pub active: bool,
     /// Days since the last formal meeting (None if never met)
@@ -33,10 +51,17 @@ pub struct ReportSummary {
     pub mood_trend: Option<MoodTrend>,
     /// Most recent mood rating (1-5)
     pub recent_mood: Option<u8>,
+    /// Rolling mean of the report's past moods (None until enough history)
+    pub mood_baseline: Option<f32>,
+    /// Recent mood's deviation from the baseline, in baseline spreads
+    /// (negative = below their normal)
+    pub mood_deviation: Option<f32>,
     /// Display color (derived from name hash or explicit)
     pub color: Color,
     /// Urgency score for sorting (higher = needs more attention)
     pub urgency_score: i32,
+    /// Per-factor contributions to the report's own urgency score
+    pub urgency_breakdown: UrgencyBreakdown,
     /// Report type (IC or Manager)
     pub report_type: ReportType,
     /// For managers: aggregated team metrics
@@ -77,12 +102,128 @@ pub struct OutlierInfo {
     pub mood_trend: Option<MoodTrend>,
     /// Most recent mood rating (drives the "mood 2" label)
     pub recent_mood: Option<u8>,
+    /// Whether the recent mood counts as low under the workspace's scoring
+    pub low_mood: bool,
     /// Whether a skip-level meeting is overdue
     pub is_overdue: bool,
     /// Days since the member's last meeting (drives the "· 6w" suffix)
     pub days_since_meeting: Option<i64>,
 }
 
+impl OutlierInfo {
+    /// What to name them for, by severity of signal:
+    /// falling trend > low mood value > overdue
+    pub fn label(&self) -> String {
+        if self.mood_trend == Some(MoodTrend::Falling) {
+            "mood ↘".to_string()
+        } else if let Some(mood) = self.recent_mood.filter(|_| self.low_mood) {
+            format!("mood {}", mood)
+        } else {
+            "overdue".to_string()
+        }
+    }
+}
+
+/// A factor that raises a report's urgency score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrgencyFactor {
+    /// No meeting on record
+    NeverMet,
+    /// Past due by more than the overdue threshold
+    Overdue { days: i64 },
+    /// Due within the next couple of days
+    ApproachingDue { days_until_due: i64 },
+    /// Recent mood is low (absolutely, or against their baseline)
+    LowMood,
+    /// No mood data to go on
+    UnknownMood,
+    /// Mood trend is falling
+    FallingTrend,
+    /// Inherited from the worst outlier in a manager's squad
+    SquadOutlier { name: String },
+}
+
+impl UrgencyFactor {
+    /// Short human-readable description, e.g. "5 days overdue"
+    pub fn label(&self) -> String {
+        match self {
+            UrgencyFactor::NeverMet => "never met".to_string(),
+            UrgencyFactor::Overdue { days: 1 } => "1 day overdue".to_string(),
+            UrgencyFactor::Overdue { days } => format!("{} days overdue", days),
+            UrgencyFactor::ApproachingDue { days_until_due } if *days_until_due <= 0 => {
+                "due now".to_string()
+            }
+            UrgencyFactor::ApproachingDue { days_until_due: 1 } => "due in 1 day".to_string(),
+            UrgencyFactor::ApproachingDue { days_until_due } => {
+                format!("due in {} days", days_until_due)
+            }
+            UrgencyFactor::LowMood => "low mood".to_string(),
+            UrgencyFactor::UnknownMood => "no mood data".to_string(),
+            UrgencyFactor::FallingTrend => "mood falling".to_string(),
+            UrgencyFactor::SquadOutlier { name } => format!("squad: {}", name),
+        }
+    }
+}
+
+/// Why a report scored what it did: each factor and the points it added
+///
+/// Factors with zero weight are left out, so the contributions always sum
+/// to the score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UrgencyBreakdown {
+    /// Contributing factors in scoring order, with their points
+    pub contributions: Vec<(UrgencyFactor, i32)>,
+}
+
+impl UrgencyBreakdown {
+    fn add(&mut self, factor: UrgencyFactor, points: u32) {
+        if points > 0 {
+            self.contributions.push((factor, points as i32));
+        }
+    }
+
+    /// Total urgency score (sum of all contributions)
+    pub fn total(&self) -> i32 {
+        self.contributions.iter().map(|(_, points)| points).sum()
+    }
+}
+
+impl ReportSummary {
+    /// Whether the latest mood counts as low under the given scoring mode
+    pub fn has_low_mood(&self, mood_scoring: MoodScoring) -> bool {
+        is_low_mood(self.recent_mood, self.mood_deviation, mood_scoring)
+    }
+
+    /// Whether they'd count as an outlier in their manager's squad: overdue,
+    /// low mood or mood falling
+    pub fn needs_attention(&self, mood_scoring: MoodScoring) -> bool {
+        is_outlier(self, mood_scoring)
+    }
+
+    /// Days until the next 1-on-1 is due, negative once past it (`None` if
+    /// they've never met, which makes one due now)
+    pub fn days_until_due(&self) -> Option<i64> {
+        self.days_since_meeting
+            .map(|days| self.frequency_days as i64 - days)
+    }
+
+    /// Attach a manager's squad metrics, folding the bonus inherited from
+    /// their worst outlier into the urgency score and its breakdown
+    pub fn attach_team_metrics(&mut self, metrics: TeamMetrics) {
+        let bonus = manager_urgency_bonus(&metrics);
+        if let Some(worst) = metrics.outliers.first().filter(|_| bonus > 0) {
+            self.urgency_breakdown.contributions.push((
+                UrgencyFactor::SquadOutlier {
+                    name: worst.name.clone(),
+                },
+                bonus,
+            ));
+        }
+        self.urgency_score = self.urgency_breakdown.total();
+        self.team_metrics = Some(metrics);
+    }
+}
+
 /// Direction of mood change over recent entries
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MoodTrend {
@@ -107,13 +248,13 @@ impl MoodTrend {
 pub fn compute_report_summary(
     report: &Report,
     entries: &[JournalEntry],
-    overdue_threshold: u32,
+    settings: &WorkspaceSettings,
 ) -> ReportSummary {
     compute_report_summary_with_frequency(
         report,
         entries,
         report.meeting_frequency_days(),
-        overdue_threshold,
+        settings,
     )
 }
 
@@ -126,9 +267,10 @@ pub fn compute_report_summary_with_frequency(
     report: &Report,
     entries: &[JournalEntry],
     frequency_days: u32,
-    overdue_threshold: u32,
+    settings: &WorkspaceSettings,
 ) -> ReportSummary {
     let today = Local::now().date_naive();
+    let overdue_threshold = settings.overdue_threshold_days;
 
     // For overdue calculation, only count "meetings" (entries with content)
     let meetings: Vec<&JournalEntry> = entries.iter().filter(|e| e.is_meeting()).collect();
@@ -156,14 +298,31 @@ pub fn compute_report_summary_with_frequency(
     let recent_mood = recent_moods.first().copied();
     let mood_trend = calculate_mood_trend(&recent_moods);
 
+    // Baseline from the readings before the most recent one, so a sudden
+    // dip is measured against what was normal until now
+    let past_moods: Vec<u8> = entries
+        .iter()
+        .rev()
+        .filter_map(|e| e.mood())
+        .skip(1)
+        .take(BASELINE_WINDOW)
+        .collect();
+    let baseline = calculate_mood_baseline(&past_moods);
+    let mood_baseline = baseline.map(|(mean, _)| mean);
+    let mood_deviation = recent_mood
+        .zip(baseline)
+        .map(|(mood, (mean, spread))| (mood as f32 - mean) / spread);
+
     let color = report_color(report.profile.color.as_deref(), &report.profile.name);
 
-    let urgency_score = calculate_urgency_score(
+    let urgency_breakdown = calculate_urgency_score(
         days_since_meeting,
         frequency_days,
         overdue_threshold as i64,
         recent_mood,
+        is_low_mood(recent_mood, mood_deviation, settings.mood_scoring),
         mood_trend,
+        &settings.scoring.urgency,
     );
 
     ReportSummary {
@@ -175,13 +334,17 @@ pub fn compute_report_summary_with_frequency(
             .clone()
             .unwrap_or_else(|| "-".to_string()),
         meeting_frequency: report.profile.meeting_frequency.clone(),
+        frequency_days: frequency_days as u32,
         active: report.profile.active,
         days_since_meeting,
         is_overdue,
         mood_trend,
         recent_mood,
+        mood_baseline,
+        mood_deviation,
         color,
-        urgency_score,
+        urgency_score: urgency_breakdown.total(),
+        urgency_breakdown,
         report_type: report.profile.report_type,
         team_metrics: None, // Set separately for managers
     }
@@ -189,6 +352,15 @@ pub fn compute_report_summary_with_frequency(
 
 /// Compute team metrics for a manager from their 2nd-level report summaries
 pub fn compute_team_metrics(team_summaries: &[ReportSummary]) -> TeamMetrics {
+    compute_team_metrics_with_settings(team_summaries, &WorkspaceSettings::default())
+}
+
+/// Compute team metrics under the workspace's mood scoring and health weights
+pub fn compute_team_metrics_with_settings(
+    team_summaries: &[ReportSummary],
+    settings: &WorkspaceSettings,
+) -> TeamMetrics {
+    let mood_scoring = settings.mood_scoring;
     let team_size = team_summaries.len();
     let active_summaries: Vec<_> = team_summaries.iter().filter(|s| s.active).collect();
 
@@ -217,17 +389,20 @@ pub fn compute_team_metrics(team_summaries: &[ReportSummary]) -> TeamMetrics {
         team_average_mood,
         team_overdue_count,
         team_summaries,
+        mood_scoring,
+        &settings.scoring.health,
     );
 
     // Members needing attention, worst-first by urgency
     let mut outliers: Vec<OutlierInfo> = active_summaries
         .iter()
-        .filter(|s| is_outlier(s))
+        .filter(|s| is_outlier(s, mood_scoring))
         .map(|s| OutlierInfo {
             name: s.name.clone(),
             urgency_score: s.urgency_score,
             mood_trend: s.mood_trend,
             recent_mood: s.recent_mood,
+            low_mood: is_low_mood(s.recent_mood, s.mood_deviation, mood_scoring),
             is_overdue: s.is_overdue,
             days_since_meeting: s.days_since_meeting,
         })
@@ -252,12 +427,23 @@ pub fn compute_team_metrics(team_summaries: &[ReportSummary]) -> TeamMetrics {
 }
 
 /// A member needs attention when overdue, low mood, or mood is falling
-fn is_outlier(summary: &ReportSummary) -> bool {
+fn is_outlier(summary: &ReportSummary, mood_scoring: MoodScoring) -> bool {
     summary.is_overdue
-        || summary.recent_mood.is_some_and(|m| m <= 2)
+        || is_low_mood(summary.recent_mood, summary.mood_deviation, mood_scoring)
         || summary.mood_trend == Some(MoodTrend::Falling)
 }
 
+/// Whether a mood reading counts as low under the given scoring mode
+///
+/// Baseline scoring falls back to the absolute threshold while a report
+/// has too little history for a baseline.
+fn is_low_mood(mood: Option<u8>, deviation: Option<f32>, mood_scoring: MoodScoring) -> bool {
+    match (mood_scoring, deviation) {
+        (MoodScoring::Baseline, Some(deviation)) => deviation <= LOW_MOOD_DEVIATION,
+        _ => mood.is_some_and(|m| m <= 2),
+    }
+}
+
 /// Urgency bonus a manager inherits from their worst squad outlier
 ///
 /// Half the worst outlier's urgency, capped at 50 so the manager's own
@@ -302,6 +488,8 @@ fn calculate_team_health_score(
     avg_mood: Option<f32>,
     overdue_count: usize,
     summaries: &[ReportSummary],
+    mood_scoring: MoodScoring,
+    weights: &HealthWeights,
 ) -> u8 {
     if team_size == 0 {
         return 0;
@@ -309,27 +497,40 @@ fn calculate_team_health_score(
 
     let mut score: f32 = 100.0;
 
-    // Deduct for low average mood (max -30 points)
-    if let Some(mood) = avg_mood {
-        // mood is 1-5, ideal is 4+
-        let mood_penalty = (4.0 - mood).max(0.0) * 10.0;
-        score -= mood_penalty;
+    // Under baseline scoring, judge the team by how far members sit below
+    // their own normal rather than by the raw average
+    let deviations: Vec<f32> = summaries
+        .iter()
+        .filter(|s| s.active)
+        .filter_map(|s| s.mood_deviation)
+        .collect();
+
+    // Deduct for low average mood (max -`low_mood` points). The shortfall
+    // runs 0-3: points below 4 on average, or spreads below baseline
+    let mood_shortfall = if mood_scoring == MoodScoring::Baseline && !deviations.is_empty() {
+        let avg_deviation = deviations.iter().sum::<f32>() / deviations.len() as f32;
+        Some((-avg_deviation).clamp(0.0, 3.0))
     } else {
+        // mood is 1-5, ideal is 4+
+        avg_mood.map(|mood| (4.0 - mood).max(0.0))
+    };
+    match mood_shortfall {
+        Some(shortfall) => score -= shortfall / 3.0 * weights.low_mood as f32,
         // No mood data - slight penalty
-        score -= 10.0;
+        None => score -= weights.no_mood_data as f32,
     }
 
-    // Deduct for overdue meetings (max -40 points)
+    // Deduct for overdue meetings (max -`overdue` points)
     let overdue_ratio = overdue_count as f32 / team_size as f32;
-    score -= overdue_ratio * 40.0;
+    score -= overdue_ratio * weights.overdue as f32;
 
-    // Deduct for falling moods (max -20 points)
+    // Deduct for falling moods (max -`falling_trend` points)
     let falling_count = summaries
         .iter()
         .filter(|s| s.active && s.mood_trend == Some(MoodTrend::Falling))
         .count();
     let falling_ratio = falling_count as f32 / team_size as f32;
-    score -= falling_ratio * 20.0;
+    score -= falling_ratio * weights.falling_trend as f32;
 
     // Ensure bounds
     score.clamp(0.0, 100.0) as u8
@@ -338,11 +539,11 @@ fn calculate_team_health_score(
 /// Calculate urgency score for sorting reports by attention needed.
 /// Higher score = more urgent attention required.
 ///
-/// Scoring factors:
+/// Scoring factors (default weights, configurable under `scoring.urgency`):
 /// - Never had a meeting: +100 (highest priority)
-/// - Days overdue past meeting frequency: +10 per day overdue
+/// - Days overdue past meeting frequency: +10 per day overdue, capped at 80
 /// - Approaching due date: +5 if within 2 days of frequency
-/// - Low mood (1-2): +20
+/// - Low mood (1-2, or well below baseline): +20
 /// - Falling mood trend: +15
 /// - Unknown mood (no recent data): +10
 fn calculate_urgency_score(
@@ -350,49 +551,72 @@ fn calculate_urgency_score(
     frequency_days: i64,
     overdue_threshold: i64,
     mood: Option<u8>,
+    low_mood: bool,
     trend: Option<MoodTrend>,
-) -> i32 {
-    let mut score: i32 = 0;
+    weights: &UrgencyWeights,
+) -> UrgencyBreakdown {
+    let mut breakdown = UrgencyBreakdown::default();
 
     // Meeting urgency
     match days_since {
         None => {
             // Never had a meeting - highest priority
-            score += 100;
+            breakdown.add(UrgencyFactor::NeverMet, weights.never_met);
         }
         Some(days) => {
             let days_until_due = frequency_days - days;
             let days_overdue = days - frequency_days - overdue_threshold;
 
             if days_overdue > 0 {
-                // Overdue: +10 per day, capped at 80
-                score += (days_overdue * 10).min(80) as i32;
+                // Overdue: per-day points, capped
+                let points = (days_overdue as u64 * weights.overdue_per_day as u64)
+                    .min(weights.overdue_cap as u64) as u32;
+                breakdown.add(UrgencyFactor::Overdue { days: days_overdue }, points);
             } else if days_until_due <= 2 {
                 // Approaching due date
-                score += 5;
+                breakdown.add(
+                    UrgencyFactor::ApproachingDue { days_until_due },
+                    weights.approaching_due,
+                );
             }
         }
     }
 
     // Mood urgency
-    match mood {
-        None => {
-            // No mood data - needs check-in
-            score += 10;
-        }
-        Some(m) if m <= 2 => {
-            // Low mood - concerning
-            score += 20;
-        }
-        _ => {}
+    if mood.is_none() {
+        // No mood data - needs check-in
+        breakdown.add(UrgencyFactor::UnknownMood, weights.unknown_mood);
+    } else if low_mood {
+        // Low mood - concerning
+        breakdown.add(UrgencyFactor::LowMood, weights.low_mood);
     }
 
     // Trend urgency
     if trend == Some(MoodTrend::Falling) {
-        score += 15;
+        breakdown.add(UrgencyFactor::FallingTrend, weights.falling_trend);
     }
 
-    score
+    breakdown
+}
+
+/// Rolling baseline as (mean, spread) from past moods
+///
+/// Spread is the standard deviation, floored at `MIN_BASELINE_SPREAD`.
+/// None until `BASELINE_MIN_SAMPLES` readings exist.
+fn calculate_mood_baseline(moods: &[u8]) -> Option<(f32, f32)> {
+    if moods.len() < BASELINE_MIN_SAMPLES {
+        return None;
+    }
+
+    let count = moods.len() as f32;
+    let mean = moods.iter().map(|&m| m as f32).sum::<f32>() / count;
+    let variance = moods
+        .iter()
+        .map(|&m| (m as f32 - mean).powi(2))
+        .sum::<f32>()
+        / count;
+
+    Some((mean, variance.sqrt().max(MIN_BASELINE_SPREAD)))
 }
 
 fn calculate_mood_trend(moods: &[u8]) -> Option<MoodTrend> {
@@ -489,10 +713,52 @@ mod tests {
         assert_eq!(calculate_mood_trend(&moods), None);
     }
 
+    #[test]
+    fn test_mood_baseline_needs_history() {
+        assert_eq!(calculate_mood_baseline(&[4, 4]), None);
+    }
+
+    #[test]
+    fn test_mood_baseline_mean_and_spread() {
+        let (mean, spread) = calculate_mood_baseline(&[2, 4, 3, 3]).unwrap();
+        assert!((mean - 3.0).abs() < f32::EPSILON);
+        assert!((spread - 0.5_f32.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mood_baseline_constant_rater_spread_floored() {
+        let (mean, spread) = calculate_mood_baseline(&[5, 5, 5, 5]).unwrap();
+        assert!((mean - 5.0).abs() < f32::EPSILON);
+        assert!((spread - MIN_BASELINE_SPREAD).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_low_mood_absolute_ignores_baseline() {
+        // A 3 from an always-5 rater is fine under absolute scoring...
+        assert!(!is_low_mood(Some(3), Some(-4.0), MoodScoring::Absolute));
+        // ...but alarming under baseline scoring
+        assert!(is_low_mood(Some(3), Some(-4.0), MoodScoring::Baseline));
+    }
+
+    #[test]
+    fn test_low_mood_baseline_tolerates_habitual_low_rater() {
+        // Always rates 2: a 2 sits right on their baseline
+        assert!(!is_low_mood(Some(2), Some(0.0), MoodScoring::Baseline));
+        assert!(is_low_mood(Some(2), Some(0.0), MoodScoring::Absolute));
+    }
+
+    #[test]
+    fn test_low_mood_baseline_falls_back_without_history() {
+        assert!(is_low_mood(Some(2), None, MoodScoring::Baseline));
+        assert!(!is_low_mood(Some(3), None, MoodScoring::Baseline));
+    }
+
     #[test]
     fn test_urgency_never_met() {
         // Never had a meeting = highest urgency
-        let score = calculate_urgency_score(None, 14, 3, None, None);
+        let score =
+            calculate_urgency_score(None, 14, 3, None, false, None, &UrgencyWeights::default())
+                .total();
         assert_eq!(score, 110); // 100 (never met) + 10 (no mood data)
     }
 
@@ -500,31 +766,147 @@ mod tests {
     fn test_urgency_overdue() {
         // 5 days overdue (20 days since, 14 day frequency, 3 day threshold)
         // days_overdue = 20 - 14 - 3 = 3
-        let score = calculate_urgency_score(Some(20), 14, 3, Some(3), Some(MoodTrend::Stable));
+        let score = calculate_urgency_score(
+            Some(20),
+            14,
+            3,
+            Some(3),
+            false,
+            Some(MoodTrend::Stable),
+            &UrgencyWeights::default(),
+        )
+        .total();
         assert_eq!(score, 30); // 3 days * 10 = 30
     }
 
     #[test]
     fn test_urgency_low_mood_falling() {
         // On schedule but low mood and falling
-        let score = calculate_urgency_score(Some(7), 14, 3, Some(2), Some(MoodTrend::Falling));
+        let score = calculate_urgency_score(
+            Some(7),
+            14,
+            3,
+            Some(2),
+            true,
+            Some(MoodTrend::Falling),
+            &UrgencyWeights::default(),
+        )
+        .total();
         assert_eq!(score, 35); // 20 (low mood) + 15 (falling)
     }
 
     #[test]
     fn test_urgency_all_good() {
         // Recently met, good mood, stable
-        let score = calculate_urgency_score(Some(3), 14, 3, Some(4), Some(MoodTrend::Stable));
+        let score = calculate_urgency_score(
+            Some(3),
+            14,
+            3,
+            Some(4),
+            false,
+            Some(MoodTrend::Stable),
+            &UrgencyWeights::default(),
+        )
+        .total();
         assert_eq!(score, 0);
     }
 
     #[test]
     fn test_urgency_approaching_due() {
         // 12 days since meeting, 14 day frequency = 2 days until due
-        let score = calculate_urgency_score(Some(12), 14, 3, Some(3), Some(MoodTrend::Stable));
+        let score = calculate_urgency_score(
+            Some(12),
+            14,
+            3,
+            Some(3),
+            false,
+            Some(MoodTrend::Stable),
+            &UrgencyWeights::default(),
+        )
+        .total();
         assert_eq!(score, 5); // approaching due date
     }
 
+    #[test]
+    fn test_urgency_breakdown_factors() {
+        let breakdown = calculate_urgency_score(
+            Some(20),
+            14,
+            3,
+            Some(2),
+            true,
+            Some(MoodTrend::Falling),
+            &UrgencyWeights::default(),
+        );
+        assert_eq!(
+            breakdown.contributions,
+            vec![
+                (UrgencyFactor::Overdue { days: 3 }, 30),
+                (UrgencyFactor::LowMood, 20),
+                (UrgencyFactor::FallingTrend, 15),
+            ]
+        );
+        assert_eq!(breakdown.total(), 65);
+    }
+
+    #[test]
+    fn test_urgency_custom_weights() {
+        let weights = UrgencyWeights {
+            low_mood: 60,
+            falling_trend: 0,
+            ..UrgencyWeights::default()
+        };
+        let breakdown = calculate_urgency_score(
+            Some(7),
+            14,
+            3,
+            Some(2),
+            true,
+            Some(MoodTrend::Falling),
+            &weights,
+        );
+        // Zero-weight factors drop out of the breakdown entirely
+        assert_eq!(breakdown.contributions, vec![(UrgencyFactor::LowMood, 60)]);
+    }
+
+    #[test]
+    fn test_urgency_custom_overdue_cap() {
+        let weights = UrgencyWeights {
+            overdue_per_day: 5,
+            overdue_cap: 25,
+            ..UrgencyWeights::default()
+        };
+        // 40 days since, 14 frequency, 3 threshold = 23 days overdue
+        let score = calculate_urgency_score(Some(40), 14, 3, Some(4), false, None, &weights);
+        assert_eq!(score.total(), 25);
+    }
+
+    #[test]
+    fn test_urgency_factor_labels() {
+        assert_eq!(UrgencyFactor::Overdue { days: 1 }.label(), "1 day overdue");
+        assert_eq!(UrgencyFactor::Overdue { days: 5 }.label(), "5 days overdue");
+        assert_eq!(
+            UrgencyFactor::ApproachingDue { days_until_due: 0 }.label(),
+            "due now"
+        );
+        assert_eq!(
+            UrgencyFactor::ApproachingDue { days_until_due: 2 }.label(),
+            "due in 2 days"
+        );
+    }
+
+    #[test]
+    fn test_team_health_custom_weights() {
+        let summaries = vec![
+            create_test_summary(Some(4), None, true),
+            create_test_summary(Some(4), None, false),
+        ];
+        let mut settings = WorkspaceSettings::default();
+        settings.scoring.health.overdue = 100;
+        let metrics = compute_team_metrics_with_settings(&summaries, &settings);
+        assert_eq!(metrics.team_health_score, 50); // half the squad overdue
+    }
+
     #[test]
     fn test_team_health_score_healthy() {
         let summaries = vec![
@@ -557,18 +939,69 @@ mod tests {
             title: None,
             level: "P3".to_string(),
             meeting_frequency: "biweekly".to_string(),
+            frequency_days: 14,
             active: true,
             days_since_meeting: Some(7),
             is_overdue: overdue,
             mood_trend: trend,
             recent_mood: mood,
+            mood_baseline: None,
+            mood_deviation: None,
             color: Color::White,
             urgency_score: 0,
+            urgency_breakdown: UrgencyBreakdown::default(),
             report_type: ReportType::Individual,
             team_metrics: None,
         }
     }
 
+    #[test]
+    fn test_team_health_baseline_uses_deviation() {
+        // Everyone at a mood of 3 — habitual 3-raters under baseline scoring
+        let mut summaries = vec![
+            create_test_summary(Some(3), Some(MoodTrend::Stable), false),
+            create_test_summary(Some(3), Some(MoodTrend::Stable), false),
+        ];
+        for summary in &mut summaries {
+            summary.mood_deviation = Some(0.0);
+        }
+        let absolute =
+            compute_team_metrics_with_settings(&summaries, &scoring(MoodScoring::Absolute));
+        let baseline =
+            compute_team_metrics_with_settings(&summaries, &scoring(MoodScoring::Baseline));
+        assert_eq!(absolute.team_health_score, 90); // (4.0 - 3.0) * 10
+        assert_eq!(baseline.team_health_score, 100);
+
+        // Same raw mood, but far below their usual 5
+        for summary in &mut summaries {
+            summary.mood_deviation = Some(-4.0);
+        }
+        let baseline =
+            compute_team_metrics_with_settings(&summaries, &scoring(MoodScoring::Baseline));
+        assert_eq!(baseline.team_health_score, 70); // capped at -30
+    }
+
+    #[test]
+    fn test_outliers_baseline_flags_drop_from_normal() {
+        let mut summary = named_summary("Usually Five", 0, Some(3), false);
+        summary.mood_deviation = Some(-4.0);
+        let absolute =
+            compute_team_metrics_with_settings(&[summary.clone()], &scoring(MoodScoring::Absolute));
+        assert!(absolute.outliers.is_empty());
+
+        let baseline =
+            compute_team_metrics_with_settings(&[summary], &scoring(MoodScoring::Baseline));
+        assert_eq!(baseline.outliers.len(), 1);
+        assert!(baseline.outliers[0].low_mood);
+    }
+
+    fn scoring(mood_scoring: MoodScoring) -> WorkspaceSettings {
+        WorkspaceSettings {
+            mood_scoring,
+            ..WorkspaceSettings::default()
+        }
+    }
+
     fn named_summary(name: &str, urgency: i32, mood: Option<u8>, overdue: bool) -> ReportSummary {
         let mut summary = create_test_summary(mood, None, overdue);
         summary.name = name.to_string();
@@ -672,4 +1105,36 @@ mod tests {
         let healthy_manager = base_score + manager_urgency_bonus(&healthy);
         assert!(troubled_manager > healthy_manager);
     }
+
+    #[test]
+    fn test_attach_team_metrics_adds_squad_factor() {
+        let metrics = compute_team_metrics(&[named_summary("Sam", 60, Some(2), true)]);
+        let mut manager = create_test_summary(Some(4), None, false);
+        manager
+            .urgency_breakdown
+            .add(UrgencyFactor::FallingTrend, 15);
+
+        manager.attach_team_metrics(metrics);
+        assert_eq!(
+            manager.urgency_breakdown.contributions.last(),
+            Some(&(
+                UrgencyFactor::SquadOutlier {
+                    name: "Sam".to_string()
+                },
+                30
+            ))
+        );
+        assert_eq!(manager.urgency_score, 45);
+        assert!(manager.team_metrics.is_some());
+    }
+
+    #[test]
+    fn test_attach_team_metrics_healthy_squad_adds_nothing() {
+        let metrics = compute_team_metrics(&[named_summary("Ana", 0, Some(4), false)]);
+        let mut manager = create_test_summary(Some(4), None, false);
+
+        manager.attach_team_metrics(metrics);
+        assert!(manager.urgency_breakdown.contributions.is_empty());
+        assert_eq!(manager.urgency_score, 0);
+    }
 }
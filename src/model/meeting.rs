@@ -98,6 +98,20 @@ pub struct JournalEntryFrontmatter {
     pub context: Option<Context>,
 }
 
+impl JournalEntryFrontmatter {
+    /// Reject values the app would silently ignore, e.g. a mood of 7
+    ///
+    /// Loading stays lenient; this is for checking a note the user just edited.
+    pub fn validate(&self) -> Result<(), String> {
+        match self.mood {
+            Some(mood) if !(1..=5).contains(&mood) => {
+                Err(format!("mood must be between 1 and 5, got {}", mood))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// A journal entry (meeting or mood observation)
 ///
 /// Loaded from a markdown file in the report's directory or `journal/` subdirectory.
@@ -220,6 +234,16 @@ mod tests {
         assert_eq!(parse_entry_timestamp("_profile.md"), None);
     }
 
+    #[test]
+    fn test_frontmatter_validate_mood() {
+        let mut fm = JournalEntryFrontmatter::default();
+        assert!(fm.validate().is_ok());
+        fm.mood = Some(5);
+        assert!(fm.validate().is_ok());
+        fm.mood = Some(7);
+        assert!(fm.validate().is_err());
+    }
+
     #[test]
     fn test_format_entry_filename() {
         let ts = NaiveDate::from_ymd_opt(2026, 1, 20)
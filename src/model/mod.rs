@@ -3,19 +3,37 @@
 //! This module contains the core data structures for reports, meetings,
 //! workspaces, and computed summaries.
 
+pub mod analytics;
 pub mod computed;
 pub mod meeting;
+pub mod prep;
 pub mod report;
+pub mod roster_view;
 pub mod workspace;
 
 // Re-export types
+pub use analytics::{
+    compute_mood_analytics, compute_mood_heatmap, HeatmapRow, MoodAnalytics, MoodHeatmap,
+    MoodStats, MoodWindow, PeriodAverage,
+};
 pub use computed::{
     compute_extended_workspace_summary, compute_report_summary,
-    compute_report_summary_with_frequency, compute_team_metrics, compute_workspace_summary,
-    manager_urgency_bonus, MoodTrend, OutlierInfo, ReportSummary, TeamMetrics, WorkspaceSummary,
+    compute_report_summary_with_frequency, compute_team_metrics,
+    compute_team_metrics_with_settings, compute_workspace_summary, manager_urgency_bonus,
+    MoodTrend, OutlierInfo, ReportSummary, TeamMetrics, UrgencyBreakdown, UrgencyFactor,
+    WorkspaceSummary,
 };
 pub use meeting::{
     format_entry_filename, parse_entry_timestamp, Context, JournalEntry, JournalEntryFrontmatter,
 };
+pub use prep::{
+    compute_meeting_prep, ActionItem, MeetingPrep, Observation, PersonalDate, PersonalDateKind,
+    PrepNote, UPCOMING_DAYS,
+};
 pub use report::{Level, ManagerInfo, MeetingFrequency, Report, ReportProfile, ReportType, Skills};
-pub use workspace::{Workspace, WorkspaceConfig, WorkspaceSettings};
+pub use roster_view::{RosterFilter, RosterLayout, RosterSort, RosterView};
+pub use workspace::{
+    CalDavSettings, CalendarSettings, EditingMode, EditorKind, GitSettings, HealthWeights,
+    MoodScoring, ScoringWeights, ThemePreset, ThemeSettings, UrgencyWeights, Workspace,
+    WorkspaceConfig, WorkspaceSettings, CURRENT_VERSION, THEME_ROLES,
+};
@@ -0,0 +1,387 @@
+//! Meeting prep
+//!
+//! Everything worth having in front of you before a 1-on-1, gathered from a
+//! report's profile, notes and summary: the last two notes, action items
+//! still open, where their mood is heading, what was observed since the last
+//! 1-on-1, birthdays and anniversaries coming up, goals in progress, and for
+//! managers the squad members who need attention.
+
+use std::cmp::Reverse;
+
+use chrono::{Datelike, Days, NaiveDate};
+
+use super::computed::{MoodTrend, OutlierInfo, ReportSummary};
+use super::meeting::{Context, JournalEntry};
+use super::report::Report;
+
+/// How many of the latest 1-on-1 notes prep shows in full
+const RECENT_NOTES: usize = 2;
+
+/// How many of the latest mood readings the trend is drawn from
+const MOOD_READINGS: usize = 8;
+
+/// How far ahead birthdays and anniversaries are looked for
+pub const UPCOMING_DAYS: u64 = 30;
+
+/// One of the latest 1-on-1 notes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrepNote {
+    pub date: NaiveDate,
+    pub mood: Option<u8>,
+    pub content: String,
+}
+
+/// An unticked `- [ ]` item in a 1-on-1 note
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionItem {
+    /// Date of the note it's in
+    pub date: NaiveDate,
+    pub text: String,
+}
+
+/// A standup, Slack or other entry since the last 1-on-1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Observation {
+    pub date: NaiveDate,
+    pub context: Context,
+    pub mood: Option<u8>,
+    /// First line of its notes, if it has any
+    pub summary: Option<String>,
+}
+
+/// A birthday or work anniversary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonalDateKind {
+    Birthday,
+    /// The number of years they'll have been at the company
+    Anniversary(i32),
+}
+
+/// A birthday or anniversary within [`UPCOMING_DAYS`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PersonalDate {
+    pub kind: PersonalDateKind,
+    pub date: NaiveDate,
+    /// Days from today (0 is today)
+    pub days_away: i64,
+}
+
+impl PersonalDate {
+    pub fn label(&self) -> String {
+        match self.kind {
+            PersonalDateKind::Birthday => "Birthday".to_string(),
+            PersonalDateKind::Anniversary(1) => "1 year at the company".to_string(),
+            PersonalDateKind::Anniversary(years) => format!("{} years at the company", years),
+        }
+    }
+
+    /// How far off it is: "today", "tomorrow", "in 8 days"
+    pub fn when(&self) -> String {
+        match self.days_away {
+            0 => "today".to_string(),
+            1 => "tomorrow".to_string(),
+            days => format!("in {} days", days),
+        }
+    }
+}
+
+/// Everything prep shows for one report
+#[derive(Debug, Clone)]
+pub struct MeetingPrep {
+    pub name: String,
+    pub title: Option<String>,
+    /// Date of the last 1-on-1
+    pub last_meeting: Option<NaiveDate>,
+    /// The latest 1-on-1 notes, newest first
+    pub recent_notes: Vec<PrepNote>,
+    /// Unticked task items across the 1-on-1 notes, newest note first
+    pub action_items: Vec<ActionItem>,
+    /// The latest mood readings, oldest first
+    pub moods: Vec<u8>,
+    pub mood_trend: Option<MoodTrend>,
+    /// Entries since the last 1-on-1 that weren't 1-on-1s, oldest first
+    pub observations: Vec<Observation>,
+    /// Birthdays and anniversaries coming up, soonest first
+    pub upcoming_dates: Vec<PersonalDate>,
+    /// Items under the profile's `## Goals` heading that aren't ticked off
+    pub goals: Vec<String>,
+    /// For managers: squad members who need attention, worst first (`None`
+    /// for ICs)
+    pub squad_outliers: Option<Vec<OutlierInfo>>,
+}
+
+/// Gather prep for a report's next 1-on-1, as of `today`
+pub fn compute_meeting_prep(
+    report: &Report,
+    entries: &[JournalEntry],
+    summary: &ReportSummary,
+    today: NaiveDate,
+) -> MeetingPrep {
+    let mut newest_first: Vec<&JournalEntry> = entries.iter().collect();
+    newest_first.sort_by_key(|e| Reverse(e.timestamp));
+    let meetings: Vec<&JournalEntry> = newest_first
+        .iter()
+        .copied()
+        .filter(|e| e.is_meeting())
+        .collect();
+    let last_meeting = meetings.first().map(|e| e.timestamp);
+
+    let recent_notes = meetings
+        .iter()
+        .take(RECENT_NOTES)
+        .map(|e| PrepNote {
+            date: e.date(),
+            mood: e.mood(),
+            content: e.content.trim().to_string(),
+        })
+        .collect();
+    let action_items = meetings
+        .iter()
+        .flat_map(|e| {
+            open_tasks(&e.content).map(|text| ActionItem {
+                date: e.date(),
+                text,
+            })
+        })
+        .collect();
+
+    let mut moods: Vec<u8> = newest_first
+        .iter()
+        .filter_map(|e| e.mood())
+        .take(MOOD_READINGS)
+        .collect();
+    moods.reverse();
+
+    let mut observations: Vec<Observation> = newest_first
+        .iter()
+        .filter(|e| !e.is_meeting())
+        .filter(|e| last_meeting.is_none_or(|last| e.timestamp > last))
+        .map(|e| Observation {
+            date: e.date(),
+            context: e.context().unwrap_or(Context::Other),
+            mood: e.mood(),
+            summary: first_line(&e.content),
+        })
+        .collect();
+    observations.reverse();
+
+    MeetingPrep {
+        name: report.profile.name.clone(),
+        title: report.profile.title.clone(),
+        last_meeting: last_meeting.map(|ts| ts.date()),
+        recent_notes,
+        action_items,
+        moods,
+        mood_trend: summary.mood_trend,
+        observations,
+        upcoming_dates: upcoming_dates(report, today),
+        goals: goals(&report.notes_content),
+        squad_outliers: summary.team_metrics.as_ref().map(|tm| tm.outliers.clone()),
+    }
+}
+
+/// Text of each unticked `- [ ]` item in a note
+fn open_tasks(content: &str) -> impl Iterator<Item = String> + '_ {
+    content.lines().filter_map(|line| {
+        let line = line.trim_start();
+        let rest = line
+            .strip_prefix("- [ ]")
+            .or_else(|| line.strip_prefix("* [ ]"))?;
+        Some(rest.trim().to_string()).filter(|text| !text.is_empty())
+    })
+}
+
+/// The first line of a note that isn't blank or a heading
+fn first_line(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.trim_start_matches(['-', '*', ' ']).to_string())
+}
+
+/// List items under a `## Goals` heading, leaving out ticked ones
+fn goals(profile_notes: &str) -> Vec<String> {
+    let mut in_goals = false;
+    let mut goals = Vec::new();
+    for line in profile_notes.lines() {
+        let line = line.trim();
+        if let Some(heading) = line.strip_prefix('#') {
+            in_goals = heading
+                .trim_start_matches('#')
+                .trim()
+                .eq_ignore_ascii_case("goals");
+            continue;
+        }
+        if !in_goals {
+            continue;
+        }
+        let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) else {
+            continue;
+        };
+        if item.starts_with("[x]") || item.starts_with("[X]") {
+            continue;
+        }
+        let item = item.strip_prefix("[ ]").unwrap_or(item).trim();
+        if !item.is_empty() {
+            goals.push(item.to_string());
+        }
+    }
+    goals
+}
+
+/// Birthday and work anniversary, if either falls in the next
+/// [`UPCOMING_DAYS`]
+fn upcoming_dates(report: &Report, today: NaiveDate) -> Vec<PersonalDate> {
+    let horizon = today + Days::new(UPCOMING_DAYS);
+    let mut dates = Vec::new();
+    if let Some(birthday) = report.profile.birthday {
+        let date = next_occurrence(birthday, today);
+        if date <= horizon {
+            dates.push(PersonalDate {
+                kind: PersonalDateKind::Birthday,
+                date,
+                days_away: (date - today).num_days(),
+            });
+        }
+    }
+    if let Some(start) = report.profile.start_date {
+        let date = next_occurrence(start, today);
+        let years = date.year() - start.year();
+        if years > 0 && date <= horizon {
+            dates.push(PersonalDate {
+                kind: PersonalDateKind::Anniversary(years),
+                date,
+                days_away: (date - today).num_days(),
+            });
+        }
+    }
+    dates.sort_by_key(|d| d.date);
+    dates
+}
+
+/// The next time `date`'s day and month come round, today included; Feb 29
+/// falls on Feb 28 in common years
+fn next_occurrence(date: NaiveDate, today: NaiveDate) -> NaiveDate {
+    let in_year = |year: i32| {
+        NaiveDate::from_ymd_opt(year, date.month(), date.day())
+            .or_else(|| NaiveDate::from_ymd_opt(year, date.month(), date.day() - 1))
+            .unwrap_or(today)
+    };
+    let this_year = in_year(today.year());
+    if this_year >= today {
+        this_year
+    } else {
+        in_year(today.year() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::meeting::JournalEntryFrontmatter;
+    use crate::model::{compute_report_summary, ReportProfile, WorkspaceSettings};
+    use std::path::PathBuf;
+
+    fn entry(day: u32, mood: Option<u8>, context: Option<Context>, content: &str) -> JournalEntry {
+        JournalEntry::new(
+            NaiveDate::from_ymd_opt(2026, 3, day)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap(),
+            PathBuf::new(),
+            JournalEntryFrontmatter { mood, context },
+            content.to_string(),
+        )
+    }
+
+    fn report(notes: &str) -> Report {
+        let profile: ReportProfile =
+            serde_yaml::from_str("name: Alex Chen\nbirthday: 1992-03-28\nstart_date: 2023-04-10\n")
+                .unwrap();
+        Report::new(
+            "alex-chen".to_string(),
+            PathBuf::new(),
+            profile,
+            notes.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_prep_gathers_notes_tasks_and_observations() {
+        let entries = vec![
+            entry(
+                2,
+                Some(4),
+                None,
+                "## Action Items\n- [ ] Share the RFC\n- [x] Book the offsite",
+            ),
+            entry(9, Some(3), Some(Context::Standup), ""),
+            entry(16, Some(2), None, "Tired.\n- [ ] Look at on-call load"),
+            entry(
+                18,
+                Some(3),
+                Some(Context::Slack),
+                "# Slack\n- asked about the rotation",
+            ),
+            entry(19, None, Some(Context::Other), ""),
+        ];
+        let report = report("");
+        let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+        let summary = compute_report_summary(&report, &entries, &WorkspaceSettings::default());
+        let prep = compute_meeting_prep(&report, &entries, &summary, today);
+
+        assert_eq!(prep.last_meeting, NaiveDate::from_ymd_opt(2026, 3, 16));
+        let dates: Vec<u32> = prep.recent_notes.iter().map(|n| n.date.day()).collect();
+        assert_eq!(dates, [16, 2]);
+        let tasks: Vec<&str> = prep.action_items.iter().map(|a| a.text.as_str()).collect();
+        assert_eq!(tasks, ["Look at on-call load", "Share the RFC"]);
+        assert_eq!(prep.moods, [4, 3, 2, 3]);
+
+        // Only what came after the last 1-on-1
+        assert_eq!(prep.observations.len(), 2);
+        assert_eq!(prep.observations[0].context, Context::Slack);
+        assert_eq!(
+            prep.observations[0].summary.as_deref(),
+            Some("asked about the rotation")
+        );
+        assert_eq!(prep.observations[1].summary, None);
+        assert!(prep.squad_outliers.is_none());
+    }
+
+    #[test]
+    fn test_goals_skip_ticked_items_and_other_sections() {
+        let notes = "# Alex\n\n## Goals\n- [ ] Lead the payments migration\n- [x] Give a tech talk\n- Mentor a new hire\n\n## Notes\n- not a goal\n";
+        assert_eq!(
+            goals(notes),
+            ["Lead the payments migration", "Mentor a new hire"]
+        );
+        assert!(goals("## Background\n- Seattle\n").is_empty());
+    }
+
+    #[test]
+    fn test_upcoming_dates_within_the_window() {
+        let report = report("");
+        let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+        let dates = upcoming_dates(&report, today);
+        assert_eq!(dates.len(), 2);
+        assert_eq!(dates[0].kind, PersonalDateKind::Birthday);
+        assert_eq!(dates[0].days_away, 8);
+        assert_eq!(dates[1].kind, PersonalDateKind::Anniversary(3));
+        assert_eq!(dates[1].label(), "3 years at the company");
+
+        // A birthday just gone is next year's, well outside the window
+        let later = NaiveDate::from_ymd_opt(2026, 3, 29).unwrap();
+        assert_eq!(upcoming_dates(&report, later).len(), 1);
+    }
+
+    #[test]
+    fn test_leap_day_falls_on_the_28th() {
+        let leap = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        assert_eq!(
+            next_occurrence(leap, today),
+            NaiveDate::from_ymd_opt(2026, 2, 28).unwrap()
+        );
+    }
+}
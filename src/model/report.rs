@@ -3,7 +3,7 @@
 //! A Report represents a direct report (team member) with their profile,
 //! career information, and for managers, their team hierarchy.
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -65,6 +65,15 @@ pub struct ReportProfile {
     /// Whether the report is currently active
     #[serde(default = "default_active")]
     pub active: bool,
+    /// Free-form labels the dashboard can filter by (e.g. "payments")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Work email, used to match calendar attendees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Next 1-on-1 on the calendar, kept up to date by calendar sync
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_meeting: Option<NaiveDateTime>,
 
     /// Type of report - IC or Manager (default: IC)
     #[serde(default)]
@@ -203,7 +212,7 @@ impl Report {
 ///
 /// In the RPG theme, ICs are "Adventurers" and Managers are "Lieutenants".
 /// Each track has 5 levels representing career progression.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Level {
     // IC Track (Adventurers)
     /// Junior Engineer
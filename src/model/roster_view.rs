@@ -0,0 +1,404 @@
+//! Dashboard filters and sort orders
+//!
+//! The dashboard lists reports most urgent first. A [`RosterView`] narrows
+//! the roster (overdue only, one level, one tag, ...) and/or orders it by
+//! something else, and shows it as cards or as a table. It applies inside
+//! halls too, and is saved per workspace so the dashboard opens the way it
+//! was left.
+
+use std::cmp::{Ordering, Reverse};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Level, MoodScoring, Report, ReportSummary};
+
+/// Which reports the dashboard shows
+///
+/// Written as one word in `.vibe-view`: `overdue`, `low_mood`, `managers`,
+/// `inactive`, `level:P3` or `tag:payments` (`all` for no filter).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum RosterFilter {
+    #[default]
+    All,
+    /// Overdue for a 1-on-1
+    Overdue,
+    /// Latest mood is low (per the workspace's mood scoring)
+    LowMood,
+    Managers,
+    /// One career level, e.g. `P3`
+    Level(String),
+    /// Reports with a profile tag
+    Tag(String),
+    /// Reports marked `active: false`
+    Inactive,
+}
+
+impl RosterFilter {
+    /// Whether a report passes the filter
+    pub fn matches(&self, report: &Report, summary: &ReportSummary, scoring: MoodScoring) -> bool {
+        match self {
+            RosterFilter::All => true,
+            RosterFilter::Overdue => summary.is_overdue,
+            RosterFilter::LowMood => summary.has_low_mood(scoring),
+            RosterFilter::Managers => report.is_manager(),
+            RosterFilter::Level(level) => summary.level.eq_ignore_ascii_case(level),
+            RosterFilter::Tag(tag) => report
+                .profile
+                .tags
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(tag)),
+            RosterFilter::Inactive => !summary.active,
+        }
+    }
+
+    /// Filters worth offering for a roster, in the order `f` cycles them
+    ///
+    /// The fixed filters, with one per level and per tag someone on the
+    /// roster has slotted in before "inactive".
+    pub fn choices<'a>(reports: impl IntoIterator<Item = &'a Report>) -> Vec<RosterFilter> {
+        let mut levels: Vec<String> = Vec::new();
+        let mut tags: Vec<String> = Vec::new();
+        for report in reports {
+            if let Some(level) = &report.profile.level {
+                if !levels.iter().any(|l| l.eq_ignore_ascii_case(level)) {
+                    levels.push(level.to_uppercase());
+                }
+            }
+            for tag in &report.profile.tags {
+                if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                    tags.push(tag.clone());
+                }
+            }
+        }
+        levels.sort_by_key(|level| level_rank(level));
+        tags.sort_by_key(|tag| tag.to_lowercase());
+
+        let mut choices = vec![
+            RosterFilter::All,
+            RosterFilter::Overdue,
+            RosterFilter::LowMood,
+            RosterFilter::Managers,
+        ];
+        choices.extend(levels.into_iter().map(RosterFilter::Level));
+        choices.extend(tags.into_iter().map(RosterFilter::Tag));
+        choices.push(RosterFilter::Inactive);
+        choices
+    }
+
+    /// The filter after this one in `choices`, wrapping back to everyone
+    pub fn next_in(&self, choices: &[RosterFilter]) -> RosterFilter {
+        let next = choices
+            .iter()
+            .position(|choice| choice == self)
+            .map_or(1, |idx| idx + 1);
+        choices.get(next).cloned().unwrap_or_default()
+    }
+
+    /// Short description, e.g. "overdue" or "level P3"
+    pub fn label(&self) -> String {
+        match self {
+            RosterFilter::All => "everyone".to_string(),
+            RosterFilter::Overdue => "overdue".to_string(),
+            RosterFilter::LowMood => "low mood".to_string(),
+            RosterFilter::Managers => "managers".to_string(),
+            RosterFilter::Level(level) => format!("level {}", level),
+            RosterFilter::Tag(tag) => format!("#{}", tag),
+            RosterFilter::Inactive => "inactive".to_string(),
+        }
+    }
+}
+
+impl TryFrom<String> for RosterFilter {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Ok(match value.split_once(':') {
+            Some(("level", level)) => RosterFilter::Level(level.trim().to_string()),
+            Some(("tag", tag)) => RosterFilter::Tag(tag.trim().to_string()),
+            _ => match value.as_str() {
+                "all" => RosterFilter::All,
+                "overdue" => RosterFilter::Overdue,
+                "low_mood" => RosterFilter::LowMood,
+                "managers" => RosterFilter::Managers,
+                "inactive" => RosterFilter::Inactive,
+                _ => return Err(format!("unknown filter {:?}", value)),
+            },
+        })
+    }
+}
+
+impl From<RosterFilter> for String {
+    fn from(filter: RosterFilter) -> Self {
+        match filter {
+            RosterFilter::All => "all".to_string(),
+            RosterFilter::Overdue => "overdue".to_string(),
+            RosterFilter::LowMood => "low_mood".to_string(),
+            RosterFilter::Managers => "managers".to_string(),
+            RosterFilter::Level(level) => format!("level:{}", level),
+            RosterFilter::Tag(tag) => format!("tag:{}", tag),
+            RosterFilter::Inactive => "inactive".to_string(),
+        }
+    }
+}
+
+/// Order of the dashboard's cards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RosterSort {
+    /// Most urgent first
+    #[default]
+    Urgency,
+    /// Alphabetical
+    Name,
+    /// Longest since the last 1-on-1 first, never-met before everyone
+    LastMeeting,
+    /// Furthest past due first, never-met before everyone
+    NextDue,
+    /// Lowest latest mood first, no mood last
+    Mood,
+    /// Most senior first, managers before ICs
+    Level,
+    /// Longest tenure first
+    StartDate,
+}
+
+impl RosterSort {
+    /// Every order, in the order `o` cycles them
+    pub const ALL: [RosterSort; 7] = [
+        RosterSort::Urgency,
+        RosterSort::Name,
+        RosterSort::LastMeeting,
+        RosterSort::NextDue,
+        RosterSort::Mood,
+        RosterSort::Level,
+        RosterSort::StartDate,
+    ];
+
+    pub fn next(self) -> RosterSort {
+        let idx = Self::ALL.iter().position(|&sort| sort == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RosterSort::Urgency => "urgency",
+            RosterSort::Name => "name",
+            RosterSort::LastMeeting => "last meeting",
+            RosterSort::NextDue => "next due",
+            RosterSort::Mood => "mood",
+            RosterSort::Level => "level",
+            RosterSort::StartDate => "start date",
+        }
+    }
+
+    /// Order two reports; ties fall back to urgency, then name
+    pub fn compare(self, a: (&Report, &ReportSummary), b: (&Report, &ReportSummary)) -> Ordering {
+        let ((a_report, a), (b_report, b)) = (a, b);
+        let primary = match self {
+            RosterSort::Urgency => Ordering::Equal,
+            RosterSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            RosterSort::LastMeeting => {
+                let days = |s: &ReportSummary| Reverse(s.days_since_meeting.unwrap_or(i64::MAX));
+                days(a).cmp(&days(b))
+            }
+            RosterSort::NextDue => {
+                let due = |s: &ReportSummary| s.days_until_due().unwrap_or(i64::MIN);
+                due(a).cmp(&due(b))
+            }
+            RosterSort::Mood => {
+                let mood = |s: &ReportSummary| (s.recent_mood.is_none(), s.recent_mood);
+                mood(a).cmp(&mood(b))
+            }
+            RosterSort::Level => level_rank(&a.level).cmp(&level_rank(&b.level)),
+            RosterSort::StartDate => {
+                let start = |r: &Report| (r.profile.start_date.is_none(), r.profile.start_date);
+                start(a_report).cmp(&start(b_report))
+            }
+        };
+        primary
+            .then_with(|| b.urgency_score.cmp(&a.urgency_score))
+            .then_with(|| a.name.cmp(&b.name))
+    }
+}
+
+/// Sort key putting the most senior level first and unknown levels last
+fn level_rank(level: &str) -> (bool, Reverse<Option<Level>>) {
+    let level = Level::parse(level);
+    (level.is_none(), Reverse(level))
+}
+
+/// How the dashboard lays out the roster
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RosterLayout {
+    /// Avatar cards, with a doorway row per manager
+    #[default]
+    Grid,
+    /// One line per report, for teams too big for the cards
+    Table,
+}
+
+impl RosterLayout {
+    pub fn toggle(self) -> RosterLayout {
+        match self {
+            RosterLayout::Grid => RosterLayout::Table,
+            RosterLayout::Table => RosterLayout::Grid,
+        }
+    }
+}
+
+/// How the dashboard is filtered, sorted and laid out (`.vibe-view`)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RosterView {
+    pub filter: RosterFilter,
+    pub sort: RosterSort,
+    pub layout: RosterLayout,
+}
+
+impl RosterView {
+    /// Status bar indicator, e.g. "Filter: overdue • Sort: name"; `None`
+    /// for the default view
+    pub fn indicator(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if self.filter != RosterFilter::All {
+            parts.push(format!("Filter: {}", self.filter.label()));
+        }
+        if self.sort != RosterSort::Urgency {
+            parts.push(format!("Sort: {}", self.sort.label()));
+        }
+        (!parts.is_empty()).then(|| parts.join(" • "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{compute_report_summary, WorkspaceSettings};
+    use std::path::PathBuf;
+
+    fn report(yaml: &str) -> (Report, ReportSummary) {
+        let profile: crate::model::ReportProfile = serde_yaml::from_str(yaml).unwrap();
+        let report = Report::new(
+            String::new(),
+            PathBuf::from("/tmp/x"),
+            profile,
+            String::new(),
+        );
+        let summary = compute_report_summary(&report, &[], &WorkspaceSettings::default());
+        (report, summary)
+    }
+
+    #[test]
+    fn test_choices_cover_levels_and_tags_on_the_roster() {
+        let (ana, _) = report("name: Ana\nlevel: p2\ntags: [Payments]");
+        let (bo, _) = report("name: Bo\nlevel: M1\ntags: [oncall, payments]");
+        let choices = RosterFilter::choices([&ana, &bo]);
+
+        assert_eq!(
+            choices,
+            [
+                RosterFilter::All,
+                RosterFilter::Overdue,
+                RosterFilter::LowMood,
+                RosterFilter::Managers,
+                RosterFilter::Level("M1".to_string()),
+                RosterFilter::Level("P2".to_string()),
+                RosterFilter::Tag("oncall".to_string()),
+                RosterFilter::Tag("Payments".to_string()),
+                RosterFilter::Inactive,
+            ]
+        );
+        assert_eq!(
+            RosterFilter::Inactive.next_in(&choices),
+            RosterFilter::All,
+            "cycling wraps"
+        );
+        // A saved filter nobody on this roster matches starts the cycle over
+        assert_eq!(
+            RosterFilter::Tag("gone".to_string()).next_in(&choices),
+            RosterFilter::Overdue
+        );
+    }
+
+    #[test]
+    fn test_filters_match() {
+        let scoring = MoodScoring::default();
+        let (ana, ana_summary) = report("name: Ana\nlevel: P2\ntags: [Payments]");
+        let (bo, bo_summary) = report("name: Bo\nreport_type: manager\nactive: false");
+
+        let tag = RosterFilter::Tag("payments".to_string());
+        assert!(tag.matches(&ana, &ana_summary, scoring));
+        assert!(!tag.matches(&bo, &bo_summary, scoring));
+        assert!(RosterFilter::Level("p2".to_string()).matches(&ana, &ana_summary, scoring));
+        assert!(RosterFilter::Managers.matches(&bo, &bo_summary, scoring));
+        assert!(RosterFilter::Inactive.matches(&bo, &bo_summary, scoring));
+        assert!(!RosterFilter::Inactive.matches(&ana, &ana_summary, scoring));
+    }
+
+    #[test]
+    fn test_sorts() {
+        let rows = [
+            report("name: Cy\nlevel: P3\nstart_date: 2020-01-01"),
+            report("name: ana\nlevel: M2"),
+            report("name: Bo\nlevel: P5\nstart_date: 2018-06-01"),
+        ];
+        let order = |sort: RosterSort| -> Vec<String> {
+            let mut rows: Vec<_> = rows.iter().collect();
+            rows.sort_by(|a, b| sort.compare((&a.0, &a.1), (&b.0, &b.1)));
+            rows.iter().map(|(r, _)| r.profile.name.clone()).collect()
+        };
+
+        assert_eq!(order(RosterSort::Name), ["ana", "Bo", "Cy"]);
+        assert_eq!(order(RosterSort::Level), ["ana", "Bo", "Cy"]);
+        assert_eq!(order(RosterSort::StartDate), ["Bo", "Cy", "ana"]);
+    }
+
+    #[test]
+    fn test_next_due_puts_never_met_then_most_overdue_first() {
+        let (_, mut weekly) = report("name: Weekly\nmeeting_frequency: weekly");
+        weekly.days_since_meeting = Some(10);
+        let (_, mut monthly) = report("name: Monthly\nmeeting_frequency: monthly");
+        monthly.days_since_meeting = Some(20);
+        let (_, never) = report("name: Never");
+
+        assert_eq!(weekly.days_until_due(), Some(-3));
+        assert_eq!(monthly.days_until_due(), Some(10));
+        assert_eq!(never.days_until_due(), None);
+
+        let (dummy, _) = report("name: Dummy");
+        let mut rows = [&monthly, &weekly, &never];
+        rows.sort_by(|a, b| RosterSort::NextDue.compare((&dummy, a), (&dummy, b)));
+        let names: Vec<&str> = rows.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Never", "Weekly", "Monthly"]);
+    }
+
+    #[test]
+    fn test_view_round_trips_as_yaml() {
+        let view = RosterView {
+            filter: RosterFilter::Level("P3".to_string()),
+            sort: RosterSort::LastMeeting,
+            layout: RosterLayout::Table,
+        };
+        let yaml = serde_yaml::to_string(&view).unwrap();
+        assert_eq!(
+            yaml,
+            "filter: level:P3\nsort: last_meeting\nlayout: table\n"
+        );
+        // Views saved before the table layout existed still load
+        assert_eq!(
+            serde_yaml::from_str::<RosterView>("sort: name\n").unwrap(),
+            RosterView {
+                sort: RosterSort::Name,
+                ..RosterView::default()
+            }
+        );
+        assert_eq!(serde_yaml::from_str::<RosterView>(&yaml).unwrap(), view);
+        assert_eq!(RosterView::default().indicator(), None);
+        assert_eq!(
+            view.indicator().as_deref(),
+            Some("Filter: level P3 • Sort: last meeting")
+        );
+    }
+}
@@ -3,8 +3,12 @@
 //! A workspace is a directory containing team data, marked by a `.vibe-manager` config file.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
+/// Workspace format version this build writes (see `storage::migrate`)
+pub const CURRENT_VERSION: u32 = 2;
+
 /// Workspace configuration from `.vibe-manager` file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceConfig {
@@ -16,7 +20,7 @@ pub struct WorkspaceConfig {
 }
 
 /// Configurable workspace settings
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceSettings {
     /// Default meeting frequency for new reports
     #[serde(default = "default_meeting_frequency", alias = "default_cadence")]
@@ -27,6 +31,378 @@ pub struct WorkspaceSettings {
     /// Default frequency for skip-level (2nd-level) meetings
     #[serde(default = "default_2nd_level_frequency")]
     pub default_2nd_level_frequency: String,
+    /// How mood readings are judged for urgency and team health
+    #[serde(default)]
+    pub mood_scoring: MoodScoring,
+    /// Weights of the urgency and team health scoring models
+    #[serde(default)]
+    pub scoring: ScoringWeights,
+    /// Calendar import and sync settings
+    #[serde(default)]
+    pub calendar: CalendarSettings,
+    /// Git history and sync settings
+    #[serde(default)]
+    pub git: GitSettings,
+    /// Where notes are edited: `$EDITOR` or the built-in editor
+    #[serde(default)]
+    pub editor: EditorKind,
+    /// How notes are handed to `$EDITOR`
+    #[serde(default)]
+    pub editing: EditingMode,
+    /// Colour theme of the TUI
+    #[serde(default)]
+    pub theme: ThemeSettings,
+}
+
+impl Default for WorkspaceSettings {
+    fn default() -> Self {
+        Self {
+            default_meeting_frequency: default_meeting_frequency(),
+            overdue_threshold_days: default_overdue_threshold(),
+            default_2nd_level_frequency: default_2nd_level_frequency(),
+            mood_scoring: MoodScoring::default(),
+            scoring: ScoringWeights::default(),
+            calendar: CalendarSettings::default(),
+            git: GitSettings::default(),
+            editor: EditorKind::default(),
+            editing: EditingMode::default(),
+            theme: ThemeSettings::default(),
+        }
+    }
+}
+
+impl WorkspaceSettings {
+    /// Skip-level meeting cadence in days (unknown values fall back to monthly)
+    pub fn second_level_frequency_days(&self) -> u32 {
+        match self.default_2nd_level_frequency.as_str() {
+            "weekly" => 7,
+            "biweekly" => 14,
+            "monthly" => 30,
+            _ => 30,
+        }
+    }
+}
+
+/// Which editor `e` opens notes in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EditorKind {
+    /// `$VISUAL` / `$EDITOR`, with the TUI suspended
+    #[default]
+    External,
+    /// The built-in editor; the note never leaves memory until it's saved
+    Inline,
+}
+
+/// How notes are handed to the external editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EditingMode {
+    /// Edit a copy in a private temp directory (memory-backed when
+    /// available), validate it, then write it back atomically; editor swap
+    /// and backup files never reach the workspace
+    Private,
+    /// Open the workspace file itself (encrypted workspaces always use `private`)
+    #[default]
+    InPlace,
+}
+
+/// Colour theme, from the `theme:` settings section or the user's
+/// `~/.config/vibe-manager/theme.yaml` (see `theme::Theme`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ThemeSettings {
+    /// Built-in palette to start from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<ThemePreset>,
+    /// Per-role overrides, keyed by one of [`THEME_ROLES`]
+    ///
+    /// Values are colour names (`cyan`, `light_magenta`), 256-colour
+    /// palette indices (`"208"`) or quoted hex (`"#005f87"`).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub colors: BTreeMap<String, String>,
+}
+
+/// Colour roles a theme can override
+pub const THEME_ROLES: &[&str] = &[
+    "primary",
+    "secondary",
+    "accent",
+    "success",
+    "warning",
+    "danger",
+    "muted",
+    "text",
+    "text_dim",
+    "on_primary",
+    "badge",
+    "mood_1",
+    "mood_2",
+    "mood_3",
+    "mood_4",
+    "mood_5",
+    "rising",
+    "falling",
+];
+
+impl ThemeSettings {
+    /// Check every override names a known role and a parseable colour
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        for (role, value) in &self.colors {
+            if !THEME_ROLES.contains(&role.as_str()) {
+                return Err(format!(
+                    "theme.colors.{} is not a colour role (expected one of {})",
+                    role,
+                    THEME_ROLES.join(", ")
+                ));
+            }
+            if value.parse::<ratatui::style::Color>().is_err() {
+                return Err(format!(
+                    "theme.colors.{} is {:?}, not a colour name, palette index or \"#rrggbb\"",
+                    role, value
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Built-in palettes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePreset {
+    /// Cyan, yellow and magenta on a dark terminal
+    #[default]
+    Rpg,
+    /// Bright colours only, no greys
+    HighContrast,
+    /// No colours at all; emphasis by bold and reverse video
+    Monochrome,
+    /// Darker colours that stay readable on a light background
+    Light,
+}
+
+/// How mood readings are judged when scoring urgency and team health
+///
+/// Some people always rate 3, others always 5 — under `baseline`, a 3 from
+/// the latter is flagged while the former's 3 is business as usual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum MoodScoring {
+    /// Fixed thresholds: mood 1-2 is low for everyone
+    #[default]
+    Absolute,
+    /// Deviation from each report's own rolling mood baseline
+    Baseline,
+}
+
+/// Calendar settings, from the `calendar:` settings section
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct CalendarSettings {
+    /// Explicit event-to-report mapping for calendar import
+    ///
+    /// Keys are attendee emails, attendee names, or event titles (matched
+    /// case-insensitively); values are report slugs. Checked before any
+    /// automatic matching.
+    pub mappings: BTreeMap<String, String>,
+    /// CalDAV calendar to sync 1-on-1s from (needs the `caldav` build feature)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caldav: Option<CalDavSettings>,
+}
+
+/// Git settings, from the `git:` settings section
+///
+/// Only take effect when the workspace lives inside a git repository.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitSettings {
+    /// Commit each note or profile as soon as it is saved
+    pub auto_commit: bool,
+    /// Remote that `vibe-manager sync` pulls from and pushes to
+    pub remote: String,
+    /// Branch to sync (defaults to the current branch's upstream)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+impl Default for GitSettings {
+    fn default() -> Self {
+        Self {
+            auto_commit: true,
+            remote: "origin".to_string(),
+            branch: None,
+        }
+    }
+}
+
+/// Connection details for a CalDAV calendar collection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalDavSettings {
+    /// URL of the calendar collection (e.g. `https://host/dav/calendars/me/work/`)
+    pub url: String,
+    /// Username for HTTP basic auth
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Name of the environment variable holding the password
+    ///
+    /// Kept out of `.vibe-manager` so the workspace can be committed safely.
+    #[serde(default)]
+    pub password_env: Option<String>,
+    /// How many days back and ahead of today to read events (at most
+    /// [`MAX_CALDAV_WINDOW_DAYS`])
+    #[serde(default = "default_caldav_window_days")]
+    pub window_days: u32,
+    /// Sync in the background whenever the TUI starts (otherwise only
+    /// `sync-calendar` writes to the journal)
+    #[serde(default)]
+    pub auto_sync: bool,
+}
+
+impl CalDavSettings {
+    /// Check the sync window is one a calendar server can answer
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=MAX_CALDAV_WINDOW_DAYS).contains(&self.window_days) {
+            return Err(format!(
+                "calendar.caldav.window_days is {}, expected 1 to {}",
+                self.window_days, MAX_CALDAV_WINDOW_DAYS
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Weights of the scoring models, from the `scoring:` settings section
+///
+/// Every field is optional in `.vibe-manager`; omitted weights keep their
+/// defaults, so a team can override just the factors it cares about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ScoringWeights {
+    /// Points added to a report's urgency (higher = needs attention sooner)
+    pub urgency: UrgencyWeights,
+    /// Maximum points deducted from a squad's 100-point health score
+    pub health: HealthWeights,
+}
+
+/// Urgency points per factor (see `calculate_urgency_score`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrgencyWeights {
+    /// Never had a meeting
+    pub never_met: u32,
+    /// Each day past the frequency plus overdue threshold
+    pub overdue_per_day: u32,
+    /// Ceiling on the accumulated overdue points
+    pub overdue_cap: u32,
+    /// Within 2 days of the meeting being due
+    pub approaching_due: u32,
+    /// Recent mood is low
+    pub low_mood: u32,
+    /// Mood trend is falling
+    pub falling_trend: u32,
+    /// No mood data recorded
+    pub unknown_mood: u32,
+}
+
+impl Default for UrgencyWeights {
+    fn default() -> Self {
+        Self {
+            never_met: 100,
+            overdue_per_day: 10,
+            overdue_cap: 80,
+            approaching_due: 5,
+            low_mood: 20,
+            falling_trend: 15,
+            unknown_mood: 10,
+        }
+    }
+}
+
+/// Maximum health deductions per factor (see `calculate_team_health_score`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthWeights {
+    /// Average mood at 1 (or 3 spreads below baseline); scales down to 0 at mood 4
+    pub low_mood: u32,
+    /// No mood data for anyone on the squad
+    pub no_mood_data: u32,
+    /// Every member overdue; scales with the overdue ratio
+    pub overdue: u32,
+    /// Every member's mood falling; scales with the falling ratio
+    pub falling_trend: u32,
+}
+
+impl Default for HealthWeights {
+    fn default() -> Self {
+        Self {
+            low_mood: 30,
+            no_mood_data: 10,
+            overdue: 40,
+            falling_trend: 20,
+        }
+    }
+}
+
+impl ScoringWeights {
+    /// Check the weights form a usable scoring model
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        let u = &self.urgency;
+        let urgency = [
+            ("never_met", u.never_met),
+            ("overdue_per_day", u.overdue_per_day),
+            ("overdue_cap", u.overdue_cap),
+            ("approaching_due", u.approaching_due),
+            ("low_mood", u.low_mood),
+            ("falling_trend", u.falling_trend),
+            ("unknown_mood", u.unknown_mood),
+        ];
+        if let Some((name, value)) = urgency.iter().find(|(_, v)| *v > MAX_URGENCY_WEIGHT) {
+            return Err(format!(
+                "scoring.urgency.{} is {}, above the maximum of {}",
+                name, value, MAX_URGENCY_WEIGHT
+            ));
+        }
+        if urgency.iter().all(|(_, v)| *v == 0) {
+            return Err("scoring.urgency weights are all 0 — nothing would rank".to_string());
+        }
+        if u.overdue_cap < u.overdue_per_day {
+            return Err(format!(
+                "scoring.urgency.overdue_cap ({}) is below overdue_per_day ({})",
+                u.overdue_cap, u.overdue_per_day
+            ));
+        }
+
+        let h = &self.health;
+        let health = [
+            ("low_mood", h.low_mood),
+            ("no_mood_data", h.no_mood_data),
+            ("overdue", h.overdue),
+            ("falling_trend", h.falling_trend),
+        ];
+        if let Some((name, value)) = health.iter().find(|(_, v)| *v > 100) {
+            return Err(format!(
+                "scoring.health.{} is {}, but health is scored out of 100",
+                name, value
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Upper bound for any single urgency weight (guards against typos like 1000)
+const MAX_URGENCY_WEIGHT: u32 = 500;
+
+/// Upper bound for the CalDAV sync window, either side of today (ten years)
+pub const MAX_CALDAV_WINDOW_DAYS: u32 = 3650;
+
+fn default_caldav_window_days() -> u32 {
+    90
 }
 
 fn default_2nd_level_frequency() -> String {
@@ -44,7 +420,7 @@ fn default_overdue_threshold() -> u32 {
 impl Default for WorkspaceConfig {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: CURRENT_VERSION,
             settings: WorkspaceSettings::default(),
         }
     }
@@ -57,11 +433,115 @@ pub struct Workspace {
     pub path: PathBuf,
     /// Parsed configuration from `.vibe-manager`
     pub config: WorkspaceConfig,
+    /// Temp files of saves that crashed before replacing their file
+    pub interrupted_writes: Vec<PathBuf>,
 }
 
 impl Workspace {
     /// Create a new workspace with the given path and config
     pub fn new(path: PathBuf, config: WorkspaceConfig) -> Self {
-        Self { path, config }
+        Self {
+            path,
+            config,
+            interrupted_writes: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_match_serde_defaults() {
+        let parsed: WorkspaceSettings = serde_yaml::from_str("{}").unwrap();
+        let default = WorkspaceSettings::default();
+        assert_eq!(
+            parsed.default_meeting_frequency,
+            default.default_meeting_frequency
+        );
+        assert_eq!(
+            parsed.overdue_threshold_days,
+            default.overdue_threshold_days
+        );
+        assert_eq!(parsed.scoring, default.scoring);
+    }
+
+    #[test]
+    fn test_partial_scoring_override_keeps_defaults() {
+        let yaml = "scoring:\n  urgency:\n    low_mood: 50\n";
+        let settings: WorkspaceSettings = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(settings.scoring.urgency.low_mood, 50);
+        assert_eq!(settings.scoring.urgency.never_met, 100);
+        assert_eq!(settings.scoring.health, HealthWeights::default());
+    }
+
+    #[test]
+    fn test_default_weights_are_valid() {
+        assert!(ScoringWeights::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_cap_below_per_day() {
+        let mut weights = ScoringWeights::default();
+        weights.urgency.overdue_per_day = 30;
+        weights.urgency.overdue_cap = 20;
+        let err = weights.validate().unwrap_err();
+        assert!(err.contains("overdue_cap"));
+    }
+
+    #[test]
+    fn test_validate_rejects_health_above_100() {
+        let mut weights = ScoringWeights::default();
+        weights.health.overdue = 150;
+        let err = weights.validate().unwrap_err();
+        assert!(err.contains("scoring.health.overdue"));
+    }
+
+    #[test]
+    fn test_validate_rejects_all_zero_urgency() {
+        let weights = ScoringWeights {
+            urgency: UrgencyWeights {
+                never_met: 0,
+                overdue_per_day: 0,
+                overdue_cap: 0,
+                approaching_due: 0,
+                low_mood: 0,
+                falling_trend: 0,
+                unknown_mood: 0,
+            },
+            health: HealthWeights::default(),
+        };
+        assert!(weights.validate().is_err());
+    }
+
+    #[test]
+    fn test_editing_defaults_to_in_place() {
+        let settings: WorkspaceSettings = serde_yaml::from_str("{}").unwrap();
+        assert_eq!(settings.editing, EditingMode::InPlace);
+        let settings: WorkspaceSettings = serde_yaml::from_str("editing: private").unwrap();
+        assert_eq!(settings.editing, EditingMode::Private);
+    }
+
+    #[test]
+    fn test_caldav_window_is_bounded() {
+        let yaml = "url: https://dav.example.com/cal/\nwindow_days: 4294967295\n";
+        let mut caldav: CalDavSettings = serde_yaml::from_str(yaml).unwrap();
+        // Writing to the journal on every start is opt-in
+        assert!(!caldav.auto_sync);
+        assert!(caldav
+            .validate()
+            .unwrap_err()
+            .contains("calendar.caldav.window_days"));
+        caldav.window_days = 0;
+        assert!(caldav.validate().is_err());
+        caldav.window_days = 90;
+        assert!(caldav.validate().is_ok());
+    }
+
+    #[test]
+    fn test_negative_weight_rejected_by_parser() {
+        let yaml = "scoring:\n  urgency:\n    low_mood: -5\n";
+        assert!(serde_yaml::from_str::<WorkspaceSettings>(yaml).is_err());
     }
 }
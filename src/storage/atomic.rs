@@ -0,0 +1,201 @@
+//! Crash-safe file writes
+//!
+//! Writing a file in place leaves it truncated if the process dies half-way.
+//! Instead, write a sibling temp file, flush it to disk, and rename it over
+//! the target: readers see either the old contents or the new, never a mix.
+//!
+//! A crash between the flush and the rename leaves the temp file behind.
+//! [`interrupted_writes`] finds those so the newer contents can be restored
+//! (or thrown away) instead of silently lost.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMP_SUFFIX: &str = ".tmp";
+
+/// Workspace config files saved through [`write_atomic`]
+const CONFIG_FILES: &[&str] = &[".vibe-manager", ".vibe-encryption", ".vibe-view"];
+
+/// Temp file a write to `path` goes through: `.{name}.tmp` in the same
+/// directory (renames are only atomic within one filesystem)
+pub fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}{}", name, TEMP_SUFFIX))
+}
+
+/// File a temp file from [`temp_path`] was meant to replace, if it is one
+pub fn target_path(temp: &Path) -> Option<PathBuf> {
+    let name = temp.file_name()?.to_str()?;
+    let target = name.strip_prefix('.')?.strip_suffix(TEMP_SUFFIX)?;
+    (!target.is_empty()).then(|| temp.with_file_name(target))
+}
+
+/// Replace `path` with `contents` atomically
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let temp = temp_path(path);
+    let result = (|| {
+        let mut file = File::create(&temp)?;
+        // Keep the target's mode (e.g. 0600 on a private note) across the rename
+        if let Ok(meta) = fs::metadata(path) {
+            file.set_permissions(meta.permissions())?;
+        }
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        fs::rename(&temp, path)?;
+        sync_parent(path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Temp files left by interrupted writes of profiles, entries and workspace
+/// config under `root` (hidden directories such as `.git` and backups are
+/// skipped)
+pub fn interrupted_writes(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    for entry in walkdir::WalkDir::new(root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| {
+            !(e.file_type().is_dir() && e.file_name().to_string_lossy().starts_with('.'))
+        })
+    {
+        let entry = entry?;
+        if entry.file_type().is_file() && is_workspace_temp(entry.path(), entry.depth()) {
+            found.push(entry.into_path());
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+/// Whether `temp` would replace a profile, an entry, or (at the workspace
+/// root, `depth` 1) a config file
+fn is_workspace_temp(temp: &Path, depth: usize) -> bool {
+    let Some(target) = target_path(temp) else {
+        return false;
+    };
+    let name = target.file_name().unwrap_or_default().to_string_lossy();
+    if depth == 1 {
+        return CONFIG_FILES.contains(&name.as_ref());
+    }
+    name == "_profile.md" || crate::model::parse_entry_timestamp(&name).is_some()
+}
+
+/// Finish an interrupted write by moving the temp file over its target
+///
+/// Returns the restored file's path.
+pub fn restore(temp: &Path) -> io::Result<PathBuf> {
+    let target = target_path(temp).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{:?} is not a temp file", temp),
+        )
+    })?;
+    fs::rename(temp, &target)?;
+    sync_parent(&target)?;
+    Ok(target)
+}
+
+/// Make a rename in `path`'s directory durable
+#[cfg(unix)]
+fn sync_parent(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => File::open(dir)?.sync_all(),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_parent(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_write_atomic_replaces_and_cleans_up() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("_profile.md");
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(!temp_path(&path).exists());
+        assert_eq!(temp_path(&path).file_name().unwrap(), "._profile.md.tmp");
+    }
+
+    #[test]
+    fn test_interrupted_write_is_found_and_restored() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("alex-chen");
+        fs::create_dir_all(&dir).unwrap();
+        fs::create_dir_all(temp.path().join(".git")).unwrap();
+        let path = dir.join("_profile.md");
+        fs::write(&path, "old").unwrap();
+        fs::write(temp_path(&path), "new").unwrap();
+        fs::write(temp.path().join(".git").join(".index.tmp"), "").unwrap();
+        fs::write(dir.join(".tmp"), "").unwrap();
+        // Backups and other tools' temp files are not ours to restore
+        let backup = temp.path().join(".vibe-backups").join("alex-chen");
+        fs::create_dir_all(&backup).unwrap();
+        fs::write(temp_path(&backup.join("_profile.md")), "").unwrap();
+        fs::write(dir.join(".draft.txt.tmp"), "").unwrap();
+        fs::write(temp_path(&dir.join(".vibe-view")), "").unwrap();
+
+        let found = interrupted_writes(temp.path()).unwrap();
+        assert_eq!(found, vec![temp_path(&path)]);
+
+        assert_eq!(restore(&found[0]).unwrap(), path);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(interrupted_writes(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_interrupted_config_and_entry_writes_are_found() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("alex-chen");
+        fs::create_dir_all(&dir).unwrap();
+        let config = temp_path(&temp.path().join(".vibe-manager"));
+        let entry = temp_path(&dir.join("2024-03-01T100000.md"));
+        fs::write(&config, "").unwrap();
+        fs::write(&entry, "").unwrap();
+
+        let found = interrupted_writes(temp.path()).unwrap();
+        assert_eq!(found, vec![config, entry]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_atomic_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("_profile.md");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        write_atomic(&path, "new").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_target_path() {
+        assert_eq!(
+            target_path(Path::new("/w/.notes.md.tmp")),
+            Some(PathBuf::from("/w/notes.md"))
+        );
+        assert_eq!(target_path(Path::new("/w/notes.md")), None);
+        assert_eq!(target_path(Path::new("/w/.tmp")), None);
+    }
+}
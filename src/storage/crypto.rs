@@ -0,0 +1,330 @@
+//! At-rest encryption
+//!
+//! Profiles and journal entries can be stored encrypted with
+//! XChaCha20-Poly1305 under a key derived from a passphrase with Argon2id.
+//! The salt, KDF parameters and a check value (to tell a wrong passphrase
+//! from a corrupted file) live in `.vibe-encryption` at the workspace root;
+//! the key itself is never written anywhere.
+//!
+//! An encrypted file is `MAGIC || nonce || ciphertext`. Files without the
+//! magic prefix are read as plaintext, so a workspace stays readable while
+//! `vibe-manager encrypt` is part-way through converting it.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::storage::{write_atomic, StorageError, StorageResult};
+
+/// Name of the encryption header file
+pub const ENCRYPTION_FILE: &str = ".vibe-encryption";
+
+/// Prefix marking an encrypted file
+const MAGIC: &[u8] = b"VIBEENC1";
+const NONCE_LEN: usize = 24;
+const SALT_LEN: usize = 16;
+/// Known plaintext encrypted into the header's `check`
+const CHECK_PLAINTEXT: &str = "vibe-manager";
+
+/// Key derivation parameters and passphrase check, stored in `.vibe-encryption`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptionHeader {
+    pub version: u32,
+    /// Argon2id memory cost in KiB
+    pub memory_kib: u32,
+    /// Argon2id time cost
+    pub iterations: u32,
+    /// Argon2id lanes
+    pub parallelism: u32,
+    /// Hex-encoded salt
+    pub salt: String,
+    /// Hex-encoded encryption of a known string under the derived key
+    pub check: String,
+}
+
+impl EncryptionHeader {
+    /// Read the header of the workspace at `root`, if it is encrypted
+    pub fn read(root: &Path) -> StorageResult<Option<Self>> {
+        let path = root.join(ENCRYPTION_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(serde_yaml::from_str(&fs::read_to_string(path)?)?))
+    }
+
+    pub(crate) fn write(&self, root: &Path) -> StorageResult<()> {
+        write_atomic(&root.join(ENCRYPTION_FILE), serde_yaml::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// Key for reading and writing encrypted files
+#[derive(Clone)]
+pub struct Cipher {
+    aead: XChaCha20Poly1305,
+}
+
+impl fmt::Debug for Cipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Cipher(..)")
+    }
+}
+
+impl Cipher {
+    /// Derive a fresh key from `passphrase` with a new random salt
+    pub fn create(passphrase: &str) -> StorageResult<(Self, EncryptionHeader)> {
+        let params = Params::default();
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        Self::create_with(passphrase, &salt, params)
+    }
+
+    fn create_with(
+        passphrase: &str,
+        salt: &[u8],
+        params: Params,
+    ) -> StorageResult<(Self, EncryptionHeader)> {
+        let mut header = EncryptionHeader {
+            version: 1,
+            memory_kib: params.m_cost(),
+            iterations: params.t_cost(),
+            parallelism: params.p_cost(),
+            salt: to_hex(salt),
+            check: String::new(),
+        };
+        let cipher = Self::derive(passphrase, &header)?;
+        header.check = to_hex(&cipher.encrypt(CHECK_PLAINTEXT)?);
+        Ok((cipher, header))
+    }
+
+    /// Derive the key for an existing workspace, rejecting a wrong passphrase
+    pub fn unlock(passphrase: &str, header: &EncryptionHeader) -> StorageResult<Self> {
+        let cipher = Self::derive(passphrase, header)?;
+        let check = from_hex(&header.check)?;
+        match cipher.decrypt(&check) {
+            Ok(text) if text == CHECK_PLAINTEXT => Ok(cipher),
+            _ => Err(StorageError::Crypto("Wrong passphrase".to_string())),
+        }
+    }
+
+    fn derive(passphrase: &str, header: &EncryptionHeader) -> StorageResult<Self> {
+        if header.version != 1 {
+            return Err(StorageError::Crypto(format!(
+                "Unsupported encryption version {}",
+                header.version
+            )));
+        }
+        let params = Params::new(
+            header.memory_kib,
+            header.iterations,
+            header.parallelism,
+            Some(32),
+        )
+        .map_err(|e| StorageError::Crypto(format!("Invalid key parameters: {}", e)))?;
+        let salt = from_hex(&header.salt)?;
+
+        let mut key = Zeroizing::new([0u8; 32]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), &salt, key.as_mut())
+            .map_err(|e| StorageError::Crypto(format!("Key derivation failed: {}", e)))?;
+        Ok(Self {
+            aead: XChaCha20Poly1305::new(key.as_ref().into()),
+        })
+    }
+
+    /// Encrypt text into the on-disk format
+    pub fn encrypt(&self, plaintext: &str) -> StorageResult<Vec<u8>> {
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .aead
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| StorageError::Crypto("Encryption failed".to_string()))?;
+
+        let mut out = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// Decrypt a file written by [`Self::encrypt`]
+    pub fn decrypt(&self, data: &[u8]) -> StorageResult<String> {
+        let body = data
+            .strip_prefix(MAGIC)
+            .filter(|body| body.len() >= NONCE_LEN)
+            .ok_or_else(|| StorageError::Crypto("Not an encrypted file".to_string()))?;
+        let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+        let plaintext = self
+            .aead
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                StorageError::Crypto(
+                    "File is corrupted or was encrypted with another key".to_string(),
+                )
+            })?;
+        String::from_utf8(plaintext)
+            .map_err(|_| StorageError::Crypto("Decrypted file is not UTF-8".to_string()))
+    }
+}
+
+/// Whether file contents are in the encrypted format
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+/// How a repository reads and writes profile and entry files
+#[derive(Debug, Clone, Default)]
+pub enum Encryption {
+    /// Plaintext workspace
+    #[default]
+    Off,
+    /// Encrypted workspace whose passphrase hasn't been entered:
+    /// encrypted files can't be read and nothing may be written
+    Locked,
+    Unlocked(Cipher),
+}
+
+impl Encryption {
+    /// Decode file contents, decrypting if needed
+    pub(crate) fn decode(&self, data: Vec<u8>, path: &Path) -> StorageResult<String> {
+        if is_encrypted(&data) {
+            match self {
+                Encryption::Unlocked(cipher) => cipher.decrypt(&data),
+                _ => Err(StorageError::Locked(path.display().to_string())),
+            }
+        } else {
+            String::from_utf8(data).map_err(|e| {
+                StorageError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+            })
+        }
+    }
+
+    /// Read a profile or entry file
+    pub(crate) fn read(&self, path: &Path) -> StorageResult<String> {
+        self.decode(fs::read(path)?, path)
+    }
+
+    /// Write a profile or entry file atomically, encrypted if the workspace is
+    pub(crate) fn write(&self, path: &Path, content: &str) -> StorageResult<()> {
+        match self {
+            Encryption::Off => write_atomic(path, content)?,
+            Encryption::Unlocked(cipher) => write_atomic(path, cipher.encrypt(content)?)?,
+            Encryption::Locked => return Err(StorageError::Locked(path.display().to_string())),
+        }
+        Ok(())
+    }
+}
+
+/// Profile and entry files under a report directory, including nested teams
+///
+/// Used to convert a whole workspace; walks the directory rather than
+/// parsing profiles so unreadable files are converted too.
+pub(crate) fn workspace_files(root: &Path) -> StorageResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| !e.file_name().to_string_lossy().starts_with('.'))
+    {
+        let entry = entry.map_err(|e| StorageError::Io(e.into()))?;
+        let name = entry.file_name().to_string_lossy();
+        if entry.file_type().is_file()
+            && (name == "_profile.md"
+                || crate::model::parse_entry_timestamp(&name).is_some() && name.ends_with(".md"))
+        {
+            files.push(entry.into_path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> StorageResult<Vec<u8>> {
+    let invalid = || StorageError::Crypto(format!("Invalid hex in {}", ENCRYPTION_FILE));
+    if !hex.len().is_multiple_of(2) {
+        return Err(invalid());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Cheap key derivation so tests stay fast
+    pub(crate) fn test_cipher(passphrase: &str) -> (Cipher, EncryptionHeader) {
+        let params = Params::new(64, 1, 1, Some(32)).unwrap();
+        Cipher::create_with(passphrase, &[7u8; SALT_LEN], params).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_and_wrong_passphrase() {
+        let (cipher, header) = test_cipher("correct horse");
+        let data = cipher.encrypt("---\nname: Alex\n---\n").unwrap();
+        assert!(is_encrypted(&data));
+        assert!(!String::from_utf8_lossy(&data).contains("Alex"));
+
+        let unlocked = Cipher::unlock("correct horse", &header).unwrap();
+        assert_eq!(unlocked.decrypt(&data).unwrap(), "---\nname: Alex\n---\n");
+        assert!(matches!(
+            Cipher::unlock("wrong", &header),
+            Err(StorageError::Crypto(_))
+        ));
+    }
+
+    #[test]
+    fn test_tampered_file_is_rejected() {
+        let (cipher, _) = test_cipher("pass");
+        let mut data = cipher.encrypt("secret").unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 1;
+        assert!(cipher.decrypt(&data).is_err());
+    }
+
+    #[test]
+    fn test_encryption_modes() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("_profile.md");
+        let (cipher, _) = test_cipher("pass");
+
+        Encryption::Unlocked(cipher.clone())
+            .write(&path, "secret")
+            .unwrap();
+        assert!(is_encrypted(&fs::read(&path).unwrap()));
+        assert!(matches!(
+            Encryption::Locked.read(&path),
+            Err(StorageError::Locked(_))
+        ));
+        assert!(Encryption::Locked.write(&path, "x").is_err());
+
+        // Plaintext files stay readable in an encrypted workspace
+        fs::write(&path, "plain").unwrap();
+        assert_eq!(Encryption::Unlocked(cipher).read(&path).unwrap(), "plain");
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        assert_eq!(from_hex(&to_hex(&[0, 15, 255])).unwrap(), vec![0, 15, 255]);
+        assert!(from_hex("abc").is_err());
+        assert!(from_hex("zz").is_err());
+    }
+}
@@ -0,0 +1,422 @@
+//! Workspace checks (`vibe-manager doctor`)
+//!
+//! Loading is lenient: a profile that fails to parse drops the person from
+//! the dashboard, a broken note is skipped, and unknown levels or
+//! frequencies quietly fall back to defaults. [`diagnose`] finds every such
+//! file so it can be fixed instead of going unnoticed; [`repair`] fixes the
+//! problems that have an unambiguous fix.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::model::{
+    parse_entry_timestamp, JournalEntry, Level, MeetingFrequency, ReportProfile, CURRENT_VERSION,
+};
+use crate::storage::{
+    parse_entry, parse_frontmatter, StorageError, StorageResult, WorkspaceRepository,
+};
+
+/// How much a problem hides from the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Shown, but not quite as written (a fallback value, a legacy layout)
+    Warning,
+    /// Not shown at all
+    Error,
+}
+
+/// Something wrong with one file or directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// Profile can't be read, so the report is missing from the dashboard
+    UnreadableProfile(String),
+    /// Note can't be read, so it is missing from the report
+    UnreadableEntry(String),
+    /// Directory with notes (or in a `team/`) but no `_profile.md`
+    MissingProfile,
+    /// Markdown file whose name isn't an entry timestamp; ignored
+    UnrecognizedFile,
+    /// Level that isn't P1-P5 or M1-M5
+    UnknownLevel(String),
+    /// Valid level in the wrong case, e.g. `p3`
+    NonCanonicalLevel(String),
+    /// Meeting frequency the app doesn't know; treated as biweekly
+    UnknownFrequency(String),
+    /// Workspace skip-level frequency the app doesn't know; treated as monthly
+    UnknownSkipLevelFrequency(String),
+    /// Workspace in an older format version; `vibe-manager migrate` upgrades it
+    OutdatedFormat(u32),
+    /// Another note has the same timestamp
+    DuplicateTimestamp(PathBuf),
+    /// Note in the report directory rather than its `journal/`
+    LegacyEntry,
+    /// Mood outside 1-5; ignored by every chart and score
+    MoodOutOfRange(u8),
+    /// `team/` directory of a report who isn't a manager, so the members
+    /// can't be reached; holds this many member directories
+    OrphanedTeamDir(usize),
+}
+
+impl Problem {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Problem::UnreadableProfile(_)
+            | Problem::UnreadableEntry(_)
+            | Problem::MissingProfile
+            | Problem::OrphanedTeamDir(_) => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+
+    /// Whether [`repair`] can fix it
+    pub fn is_fixable(&self) -> bool {
+        matches!(
+            self,
+            Problem::NonCanonicalLevel(_)
+                | Problem::UnknownFrequency(_)
+                | Problem::LegacyEntry
+                | Problem::MoodOutOfRange(_)
+                // Only an empty one: with members in it, whether the report
+                // is a manager or the directory is stale is for a person to say
+                | Problem::OrphanedTeamDir(0)
+        )
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::UnreadableProfile(e) => {
+                write!(f, "profile can't be read ({}); report hidden", e)
+            }
+            Problem::UnreadableEntry(e) => write!(f, "note can't be read ({}); note hidden", e),
+            Problem::MissingProfile => write!(f, "no _profile.md; directory ignored"),
+            Problem::UnrecognizedFile => {
+                write!(
+                    f,
+                    "name isn't an entry timestamp (YYYY-MM-DDTHHMMSS.md); ignored"
+                )
+            }
+            Problem::UnknownLevel(level) => write!(f, "unknown level {:?}", level),
+            Problem::NonCanonicalLevel(level) => {
+                write!(f, "level {:?} should be {:?}", level, level.to_uppercase())
+            }
+            Problem::UnknownFrequency(freq) => {
+                write!(
+                    f,
+                    "unknown meeting frequency {:?}; treated as biweekly",
+                    freq
+                )
+            }
+            Problem::OutdatedFormat(version) => write!(
+                f,
+                "workspace format version {} (current is {}); run vibe-manager migrate",
+                version, CURRENT_VERSION
+            ),
+            Problem::UnknownSkipLevelFrequency(freq) => write!(
+                f,
+                "unknown default_2nd_level_frequency {:?}; treated as monthly",
+                freq
+            ),
+            Problem::DuplicateTimestamp(other) => write!(
+                f,
+                "same timestamp as {}",
+                other.file_name().unwrap_or_default().to_string_lossy()
+            ),
+            Problem::LegacyEntry => write!(f, "note outside journal/ (legacy layout)"),
+            Problem::MoodOutOfRange(mood) => write!(f, "mood {} is outside 1-5; ignored", mood),
+            Problem::OrphanedTeamDir(members) => write!(
+                f,
+                "team/ with {} member(s) but the report isn't a manager; members unreachable \
+                 (set report_type: manager, or move them)",
+                members
+            ),
+        }
+    }
+}
+
+/// A problem and where it is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub path: PathBuf,
+    pub problem: Problem,
+}
+
+/// Check every profile and note in the workspace
+///
+/// Issues come in walk order: workspace config first, then each report
+/// (profile, notes, team) before its team members.
+pub fn diagnose(repo: &WorkspaceRepository) -> StorageResult<Vec<Issue>> {
+    let mut issues = diagnose_config(repo)?;
+    for dir in subdirs(repo.path())? {
+        check_report_dir(repo, &dir, false, &mut issues)?;
+    }
+    Ok(issues)
+}
+
+/// Check just the workspace config (the start of [`diagnose`])
+pub fn diagnose_config(repo: &WorkspaceRepository) -> StorageResult<Vec<Issue>> {
+    let mut issues = Vec::new();
+    let config = repo.load()?.config;
+    if config.version < CURRENT_VERSION {
+        issues.push(Issue {
+            path: repo.path().join(".vibe-manager"),
+            problem: Problem::OutdatedFormat(config.version),
+        });
+    }
+    let settings = config.settings;
+    if MeetingFrequency::parse(&settings.default_2nd_level_frequency).is_none() {
+        issues.push(Issue {
+            path: repo.path().join(".vibe-manager"),
+            problem: Problem::UnknownSkipLevelFrequency(settings.default_2nd_level_frequency),
+        });
+    }
+    Ok(issues)
+}
+
+/// Check one directory at the workspace root and everyone in its team, as
+/// [`diagnose`] would
+pub fn diagnose_report(repo: &WorkspaceRepository, dir: &Path) -> StorageResult<Vec<Issue>> {
+    let mut issues = Vec::new();
+    if dir.is_dir() {
+        check_report_dir(repo, dir, false, &mut issues)?;
+    }
+    Ok(issues)
+}
+
+/// Fix one issue, if it is fixable; returns whether anything changed
+///
+/// Profiles and notes are rewritten through the repository, so encryption
+/// and auto-commit apply as for any other save.
+pub fn repair(repo: &WorkspaceRepository, issue: &Issue) -> StorageResult<bool> {
+    match &issue.problem {
+        Problem::NonCanonicalLevel(level) => {
+            let level = level.to_uppercase();
+            update_profile(repo, &issue.path, |profile| profile.level = Some(level))?;
+        }
+        Problem::UnknownFrequency(_) => {
+            update_profile(repo, &issue.path, |profile| {
+                profile.meeting_frequency = MeetingFrequency::Biweekly.as_str().to_string()
+            })?;
+        }
+        Problem::MoodOutOfRange(_) => {
+            let (dir, timestamp) = entry_location(&issue.path)?;
+            let text = repo.read_text(&issue.path)?;
+            let (mut frontmatter, body) = parse_entry(&text)?;
+            frontmatter.mood = None;
+            let entry =
+                JournalEntry::new(timestamp, issue.path.clone(), frontmatter, body.to_string());
+            repo.report_at(dir, None).entries().save(&entry)?;
+        }
+        Problem::LegacyEntry => {
+            let (dir, _) = entry_location(&issue.path)?;
+            let journal = dir.join("journal");
+            let target = journal.join(issue.path.file_name().unwrap_or_default());
+            if target.exists() {
+                return Err(StorageError::InvalidWorkspace(format!(
+                    "{:?} already exists",
+                    target
+                )));
+            }
+            fs::create_dir_all(&journal)?;
+            fs::rename(&issue.path, &target)?;
+        }
+        Problem::OrphanedTeamDir(0) => fs::remove_dir(&issue.path)?,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Load the profile at `path`, change it, and save it
+fn update_profile(
+    repo: &WorkspaceRepository,
+    path: &Path,
+    change: impl FnOnce(&mut ReportProfile),
+) -> StorageResult<()> {
+    let dir = path.parent().unwrap_or(repo.path()).to_path_buf();
+    let report_repo = repo.report_at(dir, None);
+    let mut report = report_repo.load()?;
+    change(&mut report.profile);
+    report_repo.save(&report)
+}
+
+/// Report directory and timestamp of an entry file at the root or in `journal/`
+fn entry_location(path: &Path) -> StorageResult<(PathBuf, chrono::NaiveDateTime)> {
+    let invalid = || StorageError::InvalidWorkspace(format!("{:?} is not an entry", path));
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(invalid)?;
+    let timestamp = parse_entry_timestamp(name).ok_or_else(invalid)?;
+    let mut dir = path.parent().ok_or_else(invalid)?;
+    if dir.file_name().is_some_and(|n| n == "journal") {
+        dir = dir.parent().ok_or_else(invalid)?;
+    }
+    Ok((dir.to_path_buf(), timestamp))
+}
+
+fn check_report_dir(
+    repo: &WorkspaceRepository,
+    dir: &Path,
+    in_team: bool,
+    issues: &mut Vec<Issue>,
+) -> StorageResult<()> {
+    let profile_path = dir.join("_profile.md");
+    let notes = entry_files(dir)?;
+    if !profile_path.exists() {
+        // Unrelated directories at the root are fine; in a team, or next to
+        // notes, a missing profile hides someone
+        if in_team || !notes.is_empty() {
+            issues.push(Issue {
+                path: dir.to_path_buf(),
+                problem: Problem::MissingProfile,
+            });
+        }
+        return Ok(());
+    }
+
+    let profile = check_profile(repo, &profile_path, issues);
+    check_notes(repo, dir, notes, issues);
+
+    let team_dir = dir.join("team");
+    if team_dir.is_dir() {
+        let members = subdirs(&team_dir)?;
+        if profile
+            .as_ref()
+            .is_some_and(|p| !p.report_type.is_manager())
+        {
+            issues.push(Issue {
+                path: team_dir,
+                problem: Problem::OrphanedTeamDir(members.len()),
+            });
+        }
+        for member in members {
+            check_report_dir(repo, &member, true, issues)?;
+        }
+    }
+    Ok(())
+}
+
+/// Check a profile's fields; returns it if it parses
+fn check_profile(
+    repo: &WorkspaceRepository,
+    path: &Path,
+    issues: &mut Vec<Issue>,
+) -> Option<ReportProfile> {
+    let mut report = |problem| {
+        issues.push(Issue {
+            path: path.to_path_buf(),
+            problem,
+        })
+    };
+    let parsed = repo
+        .read_text(path)
+        .and_then(|text| match parse_frontmatter(&text).0 {
+            Some(yaml) if !yaml.is_empty() => Ok(serde_yaml::from_str::<ReportProfile>(yaml)?),
+            _ => Err(StorageError::InvalidWorkspace(
+                "missing frontmatter".to_string(),
+            )),
+        });
+    let profile = match parsed {
+        Ok(profile) => profile,
+        Err(e) => {
+            report(Problem::UnreadableProfile(e.to_string()));
+            return None;
+        }
+    };
+
+    if let Some(level) = &profile.level {
+        match Level::parse(level) {
+            None => report(Problem::UnknownLevel(level.clone())),
+            Some(parsed) if parsed.as_str() != level => {
+                report(Problem::NonCanonicalLevel(level.clone()))
+            }
+            Some(_) => {}
+        }
+    }
+    // The app matches frequencies case-sensitively, so `Weekly` is unknown too
+    if !matches!(
+        MeetingFrequency::parse(&profile.meeting_frequency),
+        Some(freq) if freq.as_str() == profile.meeting_frequency
+    ) {
+        report(Problem::UnknownFrequency(profile.meeting_frequency.clone()));
+    }
+    Some(profile)
+}
+
+fn check_notes(
+    repo: &WorkspaceRepository,
+    dir: &Path,
+    notes: Vec<PathBuf>,
+    issues: &mut Vec<Issue>,
+) {
+    let mut seen: HashMap<chrono::NaiveDateTime, PathBuf> = HashMap::new();
+    let journal = entry_files(&dir.join("journal")).unwrap_or_default();
+    let legacy_count = notes.len();
+
+    for (i, path) in notes.into_iter().chain(journal).enumerate() {
+        let mut report = |problem| {
+            issues.push(Issue {
+                path: path.clone(),
+                problem,
+            })
+        };
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let Some(timestamp) = parse_entry_timestamp(&name) else {
+            report(Problem::UnrecognizedFile);
+            continue;
+        };
+        if let Some(other) = seen.get(&timestamp) {
+            report(Problem::DuplicateTimestamp(other.clone()));
+        } else {
+            seen.insert(timestamp, path.clone());
+        }
+        if i < legacy_count {
+            report(Problem::LegacyEntry);
+        }
+
+        match repo
+            .read_text(&path)
+            .and_then(|text| parse_entry(&text).map(|(frontmatter, _)| frontmatter.mood))
+        {
+            Err(e) => report(Problem::UnreadableEntry(e.to_string())),
+            Ok(Some(mood)) if !(1..=5).contains(&mood) => report(Problem::MoodOutOfRange(mood)),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// Markdown files in `dir` that could be notes (not profiles or dot-files)
+pub(crate) fn entry_files(dir: &Path) -> StorageResult<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_file() && name.ends_with(".md") && !name.starts_with(['_', '.']) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Non-hidden subdirectories of `dir`, sorted
+fn subdirs(dir: &Path) -> StorageResult<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().starts_with('.'));
+        if path.is_dir() && !hidden {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
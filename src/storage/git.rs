@@ -0,0 +1,398 @@
+//! Git integration
+//!
+//! A workspace is plain markdown, so git gives it history and sync for free.
+//! This wraps the `git` command-line tool (like [`crate::editor`] wraps
+//! `$EDITOR`) rather than linking a git library: whatever credentials,
+//! hooks and config the user's git has, we get too.
+//!
+//! Commands never read from the terminal (`GIT_TERMINAL_PROMPT=0`), so a
+//! remote that wants a password fails instead of hanging the TUI.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::{Arc, Mutex};
+
+use crate::storage::{StorageError, StorageResult};
+
+/// Handle to the git repository containing a workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRepo {
+    root: PathBuf,
+}
+
+/// One commit that touched a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileRevision {
+    /// Full commit hash
+    pub commit: String,
+    /// Commit date, `YYYY-MM-DD HH:MM`
+    pub date: String,
+    /// First line of the commit message
+    pub summary: String,
+    /// File path at that commit, relative to the repository root
+    /// (differs from today's path across renames)
+    pub path: String,
+}
+
+impl FileRevision {
+    pub fn short_commit(&self) -> &str {
+        &self.commit[..self.commit.len().min(7)]
+    }
+}
+
+/// Result of [`GitRepo::sync`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncOutcome {
+    /// Pulled and pushed; `committed` is whether local changes were committed first
+    Synced { committed: bool },
+    /// The pull conflicted and was rolled back; nothing was overwritten.
+    /// Lists the conflicting files relative to the repository root.
+    Conflicts(Vec<String>),
+}
+
+/// Commits saves to the workspace's repository (`git.auto_commit`)
+///
+/// A failed commit (no git identity, a rejecting hook, `index.lock` held by
+/// a sync) must not fail a save whose file is already written, so it is
+/// kept for [`take_failure`](Self::take_failure) to report instead. Clones
+/// share the record.
+#[derive(Debug, Clone)]
+pub struct AutoCommit {
+    git: GitRepo,
+    failure: Arc<Mutex<Option<String>>>,
+}
+
+impl AutoCommit {
+    pub fn new(git: GitRepo) -> Self {
+        Self {
+            git,
+            failure: Arc::default(),
+        }
+    }
+
+    /// Commit `path`, remembering the error if that fails
+    pub fn commit_file(&self, path: &Path, message: &str) {
+        if let Err(e) = self.git.commit_file(path, message) {
+            *self.failure.lock().unwrap_or_else(|e| e.into_inner()) = Some(e.to_string());
+        }
+    }
+
+    /// The latest commit failure since the last call, if any
+    pub fn take_failure(&self) -> Option<String> {
+        self.failure
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+    }
+}
+
+impl GitRepo {
+    /// Find the repository containing `path`, if any (and if git is installed)
+    pub fn discover(path: &Path) -> Option<Self> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(path)
+            .args(["rev-parse", "--show-toplevel"])
+            .stdin(Stdio::null())
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Some(Self {
+            root: PathBuf::from(root),
+        })
+    }
+
+    /// Repository root directory
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Stage and commit a single file (including its deletion)
+    ///
+    /// Only `path` is committed; anything else the user has staged is left
+    /// alone. Returns `false` if the file had no changes to commit.
+    pub fn commit_file(&self, path: &Path, message: &str) -> StorageResult<bool> {
+        let relative = self.relative(path);
+        self.run(&["add", "-A", "--", &relative])?;
+        if self.is_clean_index(&relative)? {
+            return Ok(false);
+        }
+        self.run(&["commit", "-q", "-m", message, "--", &relative])?;
+        Ok(true)
+    }
+
+    /// Commits that touched `path`, newest first (follows renames)
+    pub fn file_history(&self, path: &Path) -> StorageResult<Vec<FileRevision>> {
+        let relative = self.relative(path);
+        let output = self.run(&[
+            "log",
+            "--follow",
+            "--name-only",
+            "--date=format:%Y-%m-%d %H:%M",
+            "--format=%x1e%H%x1f%ad%x1f%s",
+            "--",
+            &relative,
+        ])?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let revisions = stdout
+            .split('\x1e')
+            .filter_map(|record| {
+                let mut lines = record.lines();
+                let mut fields = lines.next()?.split('\x1f');
+                let commit = fields.next()?.to_string();
+                let date = fields.next()?.to_string();
+                let summary = fields.next().unwrap_or("").to_string();
+                let path = lines
+                    .find(|l| !l.trim().is_empty())
+                    .unwrap_or(&relative)
+                    .to_string();
+                Some(FileRevision {
+                    commit,
+                    date,
+                    summary,
+                    path,
+                })
+            })
+            .collect();
+        Ok(revisions)
+    }
+
+    /// Raw file contents as of a revision (still encrypted in encrypted workspaces)
+    pub fn show(&self, revision: &FileRevision) -> StorageResult<Vec<u8>> {
+        let spec = format!("{}:{}", revision.commit, revision.path);
+        Ok(self.run(&["show", &spec])?.stdout)
+    }
+
+    /// Files with unresolved merge conflicts
+    pub fn conflicted_files(&self) -> StorageResult<Vec<String>> {
+        let output = self.run(&["diff", "--name-only", "--diff-filter=U"])?;
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Commit pending workspace changes, pull, then push
+    ///
+    /// A conflicting pull is aborted so the working tree is exactly as it
+    /// was; the conflicting files are returned for the user to resolve with
+    /// git. `branch` defaults to the current branch's upstream.
+    pub fn sync(
+        &self,
+        workspace: &Path,
+        remote: &str,
+        branch: Option<&str>,
+    ) -> StorageResult<SyncOutcome> {
+        let unresolved = self.conflicted_files()?;
+        if !unresolved.is_empty() {
+            return Ok(SyncOutcome::Conflicts(unresolved));
+        }
+
+        let relative = self.relative(workspace);
+        self.run(&["add", "-A", "--", &relative])?;
+        let committed = !self.is_clean_index(&relative)?;
+        if committed {
+            self.run(&["commit", "-q", "-m", "Sync workspace", "--", &relative])?;
+        }
+
+        let mut pull = vec!["pull", "--no-rebase", "--no-edit", remote];
+        pull.extend(branch);
+        if let Err(err) = self.run(&pull) {
+            let conflicts = self.conflicted_files()?;
+            if conflicts.is_empty() {
+                return Err(err);
+            }
+            self.run(&["merge", "--abort"])?;
+            return Ok(SyncOutcome::Conflicts(conflicts));
+        }
+
+        let refspec = branch.map(|b| format!("HEAD:{}", b));
+        self.run(&["push", remote, refspec.as_deref().unwrap_or("HEAD")])?;
+        Ok(SyncOutcome::Synced { committed })
+    }
+
+    /// Path relative to the repository root, as git expects in pathspecs
+    fn relative(&self, path: &Path) -> String {
+        let path = path.canonicalize().unwrap_or_else(|_| {
+            // Deleted files can't be canonicalized; resolve their directory
+            match (path.parent(), path.file_name()) {
+                (Some(parent), Some(name)) => parent
+                    .canonicalize()
+                    .unwrap_or_else(|_| parent.to_path_buf())
+                    .join(name),
+                _ => path.to_path_buf(),
+            }
+        });
+        let root = self
+            .root
+            .canonicalize()
+            .unwrap_or_else(|_| self.root.clone());
+        let relative = path.strip_prefix(&root).unwrap_or(&path);
+        match relative.to_string_lossy().into_owned() {
+            empty if empty.is_empty() => ".".to_string(),
+            relative => relative,
+        }
+    }
+
+    /// Whether nothing under `relative` is staged
+    fn is_clean_index(&self, relative: &str) -> StorageResult<bool> {
+        let output = self.command(&["diff", "--cached", "--quiet", "--", relative])?;
+        Ok(output.status.success())
+    }
+
+    /// Run git and fail with its stderr on a non-zero exit
+    fn run(&self, args: &[&str]) -> StorageResult<Output> {
+        let output = self.command(args)?;
+        if output.status.success() {
+            Ok(output)
+        } else {
+            Err(StorageError::Git(format!(
+                "git {} failed: {}",
+                args[0],
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+
+    fn command(&self, args: &[&str]) -> StorageResult<Output> {
+        Ok(Command::new("git")
+            .arg("-C")
+            .arg(&self.root)
+            .args(args)
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(Stdio::null())
+            .output()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(status.status.success(), "git {:?}: {:?}", args, status);
+    }
+
+    /// Give a repository an identity so commits work on any machine
+    fn configure(dir: &Path) -> GitRepo {
+        git(dir, &["config", "user.name", "Test"]);
+        git(dir, &["config", "user.email", "test@example.com"]);
+        git(dir, &["config", "commit.gpgsign", "false"]);
+        GitRepo::discover(dir).unwrap()
+    }
+
+    fn init_repo(dir: &Path) -> GitRepo {
+        git(dir, &["init", "-q", "-b", "main"]);
+        configure(dir)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_auto_commit_keeps_the_failure() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        let auto_commit = AutoCommit::new(init_repo(temp.path()));
+        let hook = temp.path().join(".git/hooks/pre-commit");
+        fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+        let note = temp.path().join("notes.md");
+        fs::write(&note, "hello").unwrap();
+
+        auto_commit.commit_file(&note, "Save note");
+        assert!(auto_commit.clone().take_failure().is_some());
+        assert_eq!(auto_commit.take_failure(), None);
+    }
+
+    #[test]
+    fn test_discover_outside_repo() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(GitRepo::discover(temp.path()), None);
+    }
+
+    #[test]
+    fn test_commit_file_and_history() {
+        let temp = TempDir::new().unwrap();
+        let repo = init_repo(temp.path());
+        let note = temp.path().join("alex-chen/journal/2026-01-05.md");
+        fs::create_dir_all(note.parent().unwrap()).unwrap();
+
+        fs::write(&note, "first").unwrap();
+        assert!(repo.commit_file(&note, "Save note").unwrap());
+        // Nothing changed: no empty commit
+        assert!(!repo.commit_file(&note, "Save note").unwrap());
+        fs::write(&note, "second").unwrap();
+        // Unrelated staged work is not swept into the commit
+        fs::write(temp.path().join("other.md"), "wip").unwrap();
+        git(temp.path(), &["add", "other.md"]);
+        assert!(repo.commit_file(&note, "Edit note").unwrap());
+
+        let history = repo.file_history(&note).unwrap();
+        let summaries: Vec<_> = history.iter().map(|r| r.summary.as_str()).collect();
+        assert_eq!(summaries, vec!["Edit note", "Save note"]);
+        assert_eq!(history[0].path, "alex-chen/journal/2026-01-05.md");
+        assert_eq!(repo.show(&history[1]).unwrap(), b"first");
+        assert_eq!(history[0].short_commit().len(), 7);
+
+        fs::remove_file(&note).unwrap();
+        assert!(repo.commit_file(&note, "Delete note").unwrap());
+        assert_eq!(repo.file_history(&note).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_sync_pushes_and_reports_conflicts() {
+        let temp = TempDir::new().unwrap();
+        let remote = temp.path().join("remote.git");
+        git(
+            temp.path(),
+            &["init", "-q", "--bare", "-b", "main", "remote.git"],
+        );
+
+        // Two clones of the same workspace
+        let mine = temp.path().join("mine");
+        fs::create_dir_all(&mine).unwrap();
+        let mine_repo = init_repo(&mine);
+        git(
+            &mine,
+            &["remote", "add", "origin", remote.to_str().unwrap()],
+        );
+        fs::write(mine.join("notes.md"), "base\n").unwrap();
+        mine_repo
+            .commit_file(&mine.join("notes.md"), "Base")
+            .unwrap();
+        git(&mine, &["push", "-q", "origin", "HEAD:main"]);
+
+        git(
+            temp.path(),
+            &["clone", "-q", remote.to_str().unwrap(), "theirs"],
+        );
+        let theirs = temp.path().join("theirs");
+        let theirs_repo = configure(&theirs);
+        fs::write(theirs.join("notes.md"), "theirs\n").unwrap();
+        assert_eq!(
+            theirs_repo.sync(&theirs, "origin", Some("main")).unwrap(),
+            SyncOutcome::Synced { committed: true }
+        );
+
+        // Conflicting local edit: sync stops and leaves our file untouched
+        fs::write(mine.join("notes.md"), "mine\n").unwrap();
+        assert_eq!(
+            mine_repo.sync(&mine, "origin", Some("main")).unwrap(),
+            SyncOutcome::Conflicts(vec!["notes.md".to_string()])
+        );
+        assert_eq!(fs::read_to_string(mine.join("notes.md")).unwrap(), "mine\n");
+        assert!(mine_repo.conflicted_files().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,357 @@
+//! Workspace format migrations
+//!
+//! `version` in `.vibe-manager` records which on-disk layout a workspace
+//! uses. Each migration upgrades one version to the next; [`migrate`] runs
+//! the pending ones in order, bumping `version` after each, so an
+//! interrupted run resumes where it stopped. [`pending`] plans them without
+//! touching anything (`vibe-manager migrate --dry-run`).
+//!
+//! Migrations only move and rewrite files the app already reads either way,
+//! so a workspace is usable at every step.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+
+use crate::model::{format_entry_filename, parse_entry_timestamp};
+use crate::storage::doctor::entry_files;
+use crate::storage::{
+    parse_frontmatter, write_atomic, StorageError, StorageResult, WorkspaceRepository,
+};
+
+/// Suffix of the directory beside the workspace that backups go to.
+/// Older backups may sit in a directory of this name inside the workspace
+pub const BACKUP_DIR: &str = ".vibe-backups";
+
+const WORKSPACE_FILE: &str = ".vibe-manager";
+
+/// One upgrade step
+struct Migration {
+    /// Version the workspace is at afterwards
+    to: u32,
+    description: &'static str,
+    plan: fn(&WorkspaceRepository, &mut MigrationStep) -> StorageResult<()>,
+}
+
+/// Upgrades, in order; `to` counts up from 2 without gaps
+const MIGRATIONS: &[Migration] = &[Migration {
+    to: 2,
+    description: "move notes into journal/ with full timestamps; rename `cadence`",
+    plan: plan_journal_layout,
+}];
+
+/// A file operation of a migration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// Move or rename a note
+    MoveEntry { from: PathBuf, to: PathBuf },
+    /// Rename a profile's `cadence` key to `meeting_frequency`
+    RenameCadence(PathBuf),
+    /// Rename `default_cadence` in `.vibe-manager` to `default_meeting_frequency`
+    RenameDefaultCadence,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::MoveEntry { from, to } => {
+                write!(f, "move {} → {}", from.display(), to.display())
+            }
+            Change::RenameCadence(profile) => {
+                write!(
+                    f,
+                    "rename cadence → meeting_frequency in {}",
+                    profile.display()
+                )
+            }
+            Change::RenameDefaultCadence => write!(
+                f,
+                "rename default_cadence → default_meeting_frequency in {}",
+                WORKSPACE_FILE
+            ),
+        }
+    }
+}
+
+/// What one migration will do (or did)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStep {
+    /// Version the workspace is at afterwards
+    pub to: u32,
+    pub description: &'static str,
+    pub changes: Vec<Change>,
+    /// Reasons the step can't run, e.g. two notes that would share a name
+    pub conflicts: Vec<String>,
+}
+
+/// Outcome of [`migrate`]
+#[derive(Debug, Clone)]
+pub struct MigrationReport {
+    /// Copy of the workspace taken before the first change
+    pub backup: Option<PathBuf>,
+    pub steps: Vec<MigrationStep>,
+}
+
+/// Plan every migration the workspace still needs, without changing anything
+///
+/// Each step is planned against the files as they are now; a later step
+/// may find more to do once earlier ones have run.
+pub fn pending(repo: &WorkspaceRepository) -> StorageResult<Vec<MigrationStep>> {
+    let version = repo.load()?.config.version;
+    MIGRATIONS
+        .iter()
+        .filter(|migration| migration.to > version)
+        .map(|migration| plan_step(repo, migration))
+        .collect()
+}
+
+/// Run the pending migrations, backing the workspace up first if asked to
+///
+/// Stops before the first step with conflicts, leaving the workspace at the
+/// last completed version.
+pub fn migrate(repo: &WorkspaceRepository, backup: bool) -> StorageResult<MigrationReport> {
+    let version = repo.load()?.config.version;
+    let migrations: Vec<_> = MIGRATIONS.iter().filter(|m| m.to > version).collect();
+    let mut report = MigrationReport {
+        backup: None,
+        steps: Vec::new(),
+    };
+    if migrations.is_empty() {
+        return Ok(report);
+    }
+    if backup {
+        report.backup = Some(back_up(repo, version)?);
+    }
+
+    for migration in migrations {
+        let step = plan_step(repo, migration)?;
+        if !step.conflicts.is_empty() {
+            return Err(StorageError::InvalidWorkspace(format!(
+                "Can't migrate to version {}: {}",
+                step.to,
+                step.conflicts.join("; ")
+            )));
+        }
+        for change in &step.changes {
+            apply(repo, change)?;
+        }
+        set_version(repo.path(), step.to)?;
+        report.steps.push(step);
+    }
+    Ok(report)
+}
+
+fn plan_step(repo: &WorkspaceRepository, migration: &Migration) -> StorageResult<MigrationStep> {
+    let mut step = MigrationStep {
+        to: migration.to,
+        description: migration.description,
+        changes: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    (migration.plan)(repo, &mut step)?;
+    Ok(step)
+}
+
+/// Version 1 → 2: every note in `journal/` named by its full timestamp,
+/// and `cadence` spelled `meeting_frequency`
+fn plan_journal_layout(repo: &WorkspaceRepository, step: &mut MigrationStep) -> StorageResult<()> {
+    let config = fs::read_to_string(repo.path().join(WORKSPACE_FILE))?;
+    if top_level_key(&config, "default_cadence", 2).is_some() {
+        step.changes.push(Change::RenameDefaultCadence);
+    }
+
+    for report in repo.list_all_reports()? {
+        let dir = report.path();
+        let profile = dir.join("_profile.md");
+        let text = repo.read_text(&profile)?;
+        if let Some(yaml) = parse_frontmatter(&text).0 {
+            if top_level_key(yaml, "cadence", 0).is_some() {
+                if top_level_key(yaml, "meeting_frequency", 0).is_some() {
+                    step.conflicts.push(format!(
+                        "{} has both cadence and meeting_frequency",
+                        profile.display()
+                    ));
+                } else {
+                    step.changes.push(Change::RenameCadence(profile));
+                }
+            }
+        }
+
+        let journal = dir.join("journal");
+        let mut targets = HashSet::new();
+        for from in entry_files(dir)?.into_iter().chain(entry_files(&journal)?) {
+            let name = from.file_name().unwrap_or_default().to_string_lossy();
+            let Some(timestamp) = parse_entry_timestamp(&name) else {
+                continue; // not a note; `doctor` reports it
+            };
+            let to = journal.join(format_entry_filename(timestamp));
+            if !targets.insert(to.clone()) || (to != from && to.exists()) {
+                step.conflicts.push(format!(
+                    "{} and another note both have timestamp {}",
+                    from.display(),
+                    timestamp
+                ));
+            } else if to != from {
+                step.changes.push(Change::MoveEntry { from, to });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply(repo: &WorkspaceRepository, change: &Change) -> StorageResult<()> {
+    match change {
+        Change::MoveEntry { from, to } => {
+            if let Some(dir) = to.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::rename(from, to)?;
+        }
+        Change::RenameCadence(profile) => {
+            let text = repo.read_text(profile)?;
+            let renamed = rename_key(&text, "cadence", "meeting_frequency", 0);
+            repo.write_text(profile, &renamed)?;
+        }
+        Change::RenameDefaultCadence => {
+            let path = repo.path().join(WORKSPACE_FILE);
+            let text = fs::read_to_string(&path)?;
+            let renamed = rename_key(&text, "default_cadence", "default_meeting_frequency", 2);
+            write_atomic(&path, renamed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Record `version` in `.vibe-manager`, keeping the rest of the file as written
+fn set_version(root: &Path, version: u32) -> StorageResult<()> {
+    let path = root.join(WORKSPACE_FILE);
+    let text = fs::read_to_string(&path)?;
+    let line = format!("version: {}", version);
+    let updated = match top_level_key(&text, "version", 0) {
+        Some(idx) => {
+            text.lines()
+                .enumerate()
+                .map(|(i, l)| if i == idx { line.as_str() } else { l })
+                .collect::<Vec<_>>()
+                .join("\n")
+                + "\n"
+        }
+        None => format!("{}\n{}", line, text),
+    };
+    write_atomic(&path, updated)?;
+    Ok(())
+}
+
+/// Where backups of the workspace at `root` go: `{name}.vibe-backups/`
+/// next to it, so they are never synced with the workspace
+pub fn backup_dir(root: &Path) -> StorageResult<PathBuf> {
+    let root = fs::canonicalize(root)?;
+    match root.file_name() {
+        Some(name) => {
+            let mut name = name.to_os_string();
+            name.push(BACKUP_DIR);
+            Ok(root.with_file_name(name))
+        }
+        None => Err(StorageError::InvalidWorkspace(format!(
+            "can't place a backup beside {}",
+            root.display()
+        ))),
+    }
+}
+
+/// Backup directories holding plaintext copies of the workspace at `root`,
+/// including one left inside it by an older release
+pub fn existing_backups(root: &Path) -> Vec<PathBuf> {
+    [Some(root.join(BACKUP_DIR)), backup_dir(root).ok()]
+        .into_iter()
+        .flatten()
+        .filter(|dir| dir.is_dir())
+        .collect()
+}
+
+/// Copy the whole workspace (but not `.git` or earlier backups) into
+/// [`backup_dir`]`/v{version}-{timestamp}/`; encrypted files stay encrypted
+pub fn back_up(repo: &WorkspaceRepository, version: u32) -> StorageResult<PathBuf> {
+    let root = repo.path();
+    let target = backup_dir(root)?.join(format!(
+        "v{}-{}",
+        version,
+        Local::now().format("%Y%m%dT%H%M%S")
+    ));
+    for entry in walkdir::WalkDir::new(root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git" && e.file_name() != BACKUP_DIR)
+    {
+        let entry = entry.map_err(|e| StorageError::Io(e.into()))?;
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        let dest = target.join(relative);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&dest)?;
+        } else if entry.file_type().is_file() {
+            if let Some(dir) = dest.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::copy(entry.path(), &dest)?;
+        }
+    }
+    Ok(target)
+}
+
+/// Line index of `key:` indented by exactly `indent` spaces
+fn top_level_key(yaml: &str, key: &str, indent: usize) -> Option<usize> {
+    yaml.lines().position(|line| {
+        let trimmed = line.trim_start_matches(' ');
+        line.len() - trimmed.len() == indent
+            && trimmed
+                .strip_prefix(key)
+                .is_some_and(|rest| rest.starts_with(':'))
+    })
+}
+
+/// Rename the first `key:` at `indent`, leaving its value and every other line untouched
+fn rename_key(text: &str, key: &str, new_key: &str, indent: usize) -> String {
+    let Some(idx) = top_level_key(text, key, indent) else {
+        return text.to_string();
+    };
+    let mut out: Vec<String> = text.lines().map(str::to_string).collect();
+    out[idx] = format!(
+        "{}{}{}",
+        " ".repeat(indent),
+        new_key,
+        &out[idx][indent + key.len()..]
+    );
+    let mut joined = out.join("\n");
+    if text.ends_with('\n') {
+        joined.push('\n');
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::CURRENT_VERSION;
+
+    #[test]
+    fn test_rename_key_keeps_other_lines() {
+        let yaml = "name: Alex\ncadence: weekly # agreed in Q1\nskills:\n  cadence: x\n";
+        assert_eq!(
+            rename_key(yaml, "cadence", "meeting_frequency", 0),
+            "name: Alex\nmeeting_frequency: weekly # agreed in Q1\nskills:\n  cadence: x\n"
+        );
+        assert_eq!(top_level_key(yaml, "cadence", 2), Some(3));
+        assert_eq!(top_level_key("cadences: 1", "cadence", 0), None);
+    }
+
+    #[test]
+    fn test_migrations_are_in_order() {
+        for (i, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.to, i as u32 + 2);
+        }
+        assert_eq!(MIGRATIONS.last().map(|m| m.to), Some(CURRENT_VERSION));
+    }
+}
@@ -3,12 +3,23 @@
 //! Provides repository-based access to workspace data stored as markdown files
 //! with YAML frontmatter.
 
+pub mod atomic;
+pub mod crypto;
+pub mod doctor;
+pub mod git;
+pub mod migrate;
 pub mod repo;
 
+pub use atomic::write_atomic;
+pub use crypto::{Cipher, Encryption, EncryptionHeader};
+pub use doctor::{Issue, Problem, Severity};
+pub use git::{AutoCommit, FileRevision, GitRepo, SyncOutcome};
 pub use repo::{EntryRepository, ReportRepository, WorkspaceRepository};
 
 use thiserror::Error;
 
+use crate::model::JournalEntryFrontmatter;
+
 #[derive(Error, Debug)]
 pub enum StorageError {
     #[error("IO error: {0}")]
@@ -22,6 +33,18 @@ pub enum StorageError {
 
     #[error("Profile not found: {0}")]
     ProfileNotFound(String),
+
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Git error: {0}")]
+    Git(String),
+
+    #[error("Workspace is encrypted and locked: {0}")]
+    Locked(String),
+
+    #[error("Encryption error: {0}")]
+    Crypto(String),
 }
 
 pub type StorageResult<T> = Result<T, StorageError>;
@@ -45,6 +68,18 @@ pub fn parse_frontmatter(content: &str) -> (Option<&str>, &str) {
     }
 }
 
+/// Parse a journal entry file into its frontmatter and body
+///
+/// A missing or empty frontmatter block yields the default frontmatter.
+pub fn parse_entry(content: &str) -> StorageResult<(JournalEntryFrontmatter, &str)> {
+    let (frontmatter, body) = parse_frontmatter(content);
+    let fm = match frontmatter {
+        Some(yaml) if !yaml.is_empty() => serde_yaml::from_str(yaml)?,
+        _ => JournalEntryFrontmatter::default(),
+    };
+    Ok((fm, body))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +109,16 @@ Some notes here.
         assert_eq!(body, content);
     }
 
+    #[test]
+    fn test_parse_entry() {
+        let (fm, body) = parse_entry("---\nmood: 4\n---\n\nNotes").unwrap();
+        assert_eq!(fm.mood, Some(4));
+        assert_eq!(body, "Notes");
+
+        assert!(parse_entry("---\nmood: [oops\n---\n").is_err());
+        assert_eq!(parse_entry("No frontmatter").unwrap().0.mood, None);
+    }
+
     #[test]
     fn test_parse_frontmatter_empty() {
         let content = "---\n---\n\nBody";
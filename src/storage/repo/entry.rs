@@ -3,25 +3,44 @@
 //! High-level interface for journal entry operations.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use chrono::{Local, NaiveDate, NaiveDateTime};
 
 use crate::model::{
     format_entry_filename, parse_entry_timestamp, Context, JournalEntry, JournalEntryFrontmatter,
 };
-use crate::storage::{parse_frontmatter, StorageError, StorageResult};
+use crate::storage::{parse_entry, AutoCommit, Encryption, StorageError, StorageResult};
 
 /// Repository for journal entry operations
 #[derive(Debug, Clone)]
 pub struct EntryRepository {
     report_path: PathBuf,
+    /// Repository to commit saves to, when auto-commit is on
+    auto_commit: Option<AutoCommit>,
+    encryption: Encryption,
 }
 
 impl EntryRepository {
     /// Create a new entry repository for a report
     pub(crate) fn new(report_path: PathBuf) -> Self {
-        Self { report_path }
+        Self {
+            report_path,
+            auto_commit: None,
+            encryption: Encryption::Off,
+        }
+    }
+
+    /// Commit every save and delete to `git`
+    pub(crate) fn with_auto_commit(mut self, git: Option<AutoCommit>) -> Self {
+        self.auto_commit = git;
+        self
+    }
+
+    /// Read and write entry files with `encryption`
+    pub(crate) fn with_encryption(mut self, encryption: Encryption) -> Self {
+        self.encryption = encryption;
+        self
     }
 
     /// List all journal entries for the report
@@ -79,14 +98,9 @@ impl EntryRepository {
     }
 
     /// Load a single entry
-    fn load_entry(&self, path: &PathBuf, timestamp: NaiveDateTime) -> StorageResult<JournalEntry> {
-        let content = fs::read_to_string(path)?;
-        let (frontmatter, body) = parse_frontmatter(&content);
-
-        let fm: JournalEntryFrontmatter = match frontmatter {
-            Some(yaml) if !yaml.is_empty() => serde_yaml::from_str(yaml)?,
-            _ => JournalEntryFrontmatter::default(),
-        };
+    fn load_entry(&self, path: &Path, timestamp: NaiveDateTime) -> StorageResult<JournalEntry> {
+        let content = self.encryption.read(path)?;
+        let (fm, body) = parse_entry(&content)?;
 
         Ok(JournalEntry::new(
             timestamp,
@@ -153,6 +167,33 @@ impl EntryRepository {
         Ok(entry)
     }
 
+    /// Record a past meeting at an exact timestamp (e.g. from a calendar import)
+    pub fn create_meeting_at(
+        &self,
+        timestamp: NaiveDateTime,
+        content: String,
+    ) -> StorageResult<JournalEntry> {
+        let journal_dir = self.report_path.join("journal");
+        fs::create_dir_all(&journal_dir)?;
+        let path = journal_dir.join(format_entry_filename(timestamp));
+
+        if path.exists() {
+            return Err(StorageError::InvalidWorkspace(format!(
+                "Meeting already exists for {}",
+                timestamp
+            )));
+        }
+
+        let frontmatter = JournalEntryFrontmatter {
+            mood: None,
+            context: Some(Context::Meeting),
+        };
+        let entry = JournalEntry::new(timestamp, path, frontmatter, content);
+
+        self.save(&entry)?;
+        Ok(entry)
+    }
+
     /// Create a new mood observation entry
     pub fn create_observation(
         &self,
@@ -186,7 +227,8 @@ impl EntryRepository {
     pub fn save(&self, entry: &JournalEntry) -> StorageResult<()> {
         let yaml = serde_yaml::to_string(&entry.frontmatter)?;
         let content = format!("---\n{}---\n\n{}", yaml, entry.content);
-        fs::write(&entry.path, content)?;
+        self.encryption.write(&entry.path, &content)?;
+        self.commit(&entry.path, "Save");
         Ok(())
     }
 
@@ -194,9 +236,30 @@ impl EntryRepository {
     pub fn delete(&self, entry: &JournalEntry) -> StorageResult<()> {
         if entry.path.exists() {
             fs::remove_file(&entry.path)?;
+            self.commit(&entry.path, "Delete");
         }
         Ok(())
     }
+
+    /// Auto-commit a changed entry file, e.g. "Save alex-chen/2026-01-20T143000.md"
+    ///
+    /// The file is already written, so a failed commit doesn't fail the save;
+    /// it is reported through [`AutoCommit::take_failure`].
+    fn commit(&self, path: &Path, verb: &str) {
+        let Some(git) = &self.auto_commit else {
+            return;
+        };
+        let slug = self
+            .report_path
+            .file_name()
+            .map(|n| n.to_string_lossy())
+            .unwrap_or_default();
+        let file = path
+            .file_name()
+            .map(|n| n.to_string_lossy())
+            .unwrap_or_default();
+        git.commit_file(path, &format!("{} {}/{}", verb, slug, file));
+    }
 }
 
 #[cfg(test)]
@@ -7,7 +7,7 @@ use std::path::{Path, PathBuf};
 
 use super::EntryRepository;
 use crate::model::{Report, ReportProfile};
-use crate::storage::{parse_frontmatter, StorageError, StorageResult};
+use crate::storage::{parse_frontmatter, AutoCommit, Encryption, StorageError, StorageResult};
 
 const PROFILE_FILE: &str = "_profile.md";
 
@@ -16,12 +16,32 @@ const PROFILE_FILE: &str = "_profile.md";
 pub struct ReportRepository {
     path: PathBuf,
     manager_slug: Option<String>,
+    /// Repository to commit saves to, when auto-commit is on
+    auto_commit: Option<AutoCommit>,
+    encryption: Encryption,
 }
 
 impl ReportRepository {
     /// Create a new report repository
     pub fn new(path: PathBuf, manager_slug: Option<String>) -> Self {
-        Self { path, manager_slug }
+        Self {
+            path,
+            manager_slug,
+            auto_commit: None,
+            encryption: Encryption::Off,
+        }
+    }
+
+    /// Commit every save and delete under this report to `git`
+    pub fn with_auto_commit(mut self, git: Option<AutoCommit>) -> Self {
+        self.auto_commit = git;
+        self
+    }
+
+    /// Read and write this report's files (and its team's) with `encryption`
+    pub fn with_encryption(mut self, encryption: Encryption) -> Self {
+        self.encryption = encryption;
+        self
     }
 
     /// Get the report directory path
@@ -45,7 +65,7 @@ impl ReportRepository {
             return Err(StorageError::ProfileNotFound(format!("{:?}", profile_path)));
         }
 
-        let content = fs::read_to_string(&profile_path)?;
+        let content = self.encryption.read(&profile_path)?;
         let (frontmatter, body) = parse_frontmatter(&content);
 
         let profile: ReportProfile = match frontmatter {
@@ -85,7 +105,14 @@ impl ReportRepository {
         let yaml = serde_yaml::to_string(&report.profile)?;
         let content = format!("---\n{}---\n\n{}", yaml, report.notes_content);
 
-        fs::write(&profile_path, content)?;
+        self.encryption.write(&profile_path, &content)?;
+
+        // The profile is already written; a failed commit is reported
+        // through `AutoCommit::take_failure` rather than failing the save
+        if let Some(git) = &self.auto_commit {
+            let message = format!("Update profile: {}", report.profile.name);
+            git.commit_file(&profile_path, &message);
+        }
         Ok(())
     }
 
@@ -114,7 +141,11 @@ impl ReportRepository {
                 // Skip hidden directories
                 if let Some(name) = name {
                     if !name.starts_with('.') && path.join(PROFILE_FILE).exists() {
-                        repos.push(ReportRepository::new(path, Some(slug.clone())));
+                        repos.push(
+                            ReportRepository::new(path, Some(slug.clone()))
+                                .with_auto_commit(self.auto_commit.clone())
+                                .with_encryption(self.encryption.clone()),
+                        );
                     }
                 }
             }
@@ -127,6 +158,8 @@ impl ReportRepository {
     /// Get the entry repository for this report
     pub fn entries(&self) -> EntryRepository {
         EntryRepository::new(self.path.clone())
+            .with_auto_commit(self.auto_commit.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -144,6 +177,9 @@ mod tests {
             level: Some("P3".to_string()),
             meeting_frequency: "weekly".to_string(),
             active: true,
+            tags: vec![],
+            email: None,
+            next_meeting: None,
             report_type: ReportType::Individual,
             manager_info: None,
             birthday: None,
@@ -6,17 +6,30 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::ReportRepository;
-use crate::model::{Report, ReportProfile, Workspace, WorkspaceConfig};
-use crate::storage::{StorageError, StorageResult};
+use crate::model::{
+    GitSettings, Report, ReportProfile, RosterView, Workspace, WorkspaceConfig, CURRENT_VERSION,
+};
+use crate::storage::crypto::{self, ENCRYPTION_FILE};
+use crate::storage::{
+    atomic, write_atomic, AutoCommit, Cipher, Encryption, EncryptionHeader, GitRepo, StorageError,
+    StorageResult, SyncOutcome,
+};
 use crate::utils::name_to_slug;
 
 /// Name of the workspace configuration file
 const WORKSPACE_FILE: &str = ".vibe-manager";
 
+/// Dashboard filter and sort, remembered between sessions
+const VIEW_FILE: &str = ".vibe-view";
+
 /// Repository for workspace operations
 #[derive(Debug, Clone)]
 pub struct WorkspaceRepository {
     path: PathBuf,
+    /// Repository saves are committed to (`git.auto_commit` inside a git repo)
+    auto_commit: Option<AutoCommit>,
+    /// Locked until [`Self::unlock`] when the workspace is encrypted
+    encryption: Encryption,
 }
 
 impl WorkspaceRepository {
@@ -29,7 +42,16 @@ impl WorkspaceRepository {
                 WORKSPACE_FILE, path
             )));
         }
-        Ok(Self { path })
+        let auto_commit = Self::auto_commit_repo(&path);
+        let encryption = match EncryptionHeader::read(&path)? {
+            Some(_) => Encryption::Locked,
+            None => Encryption::Off,
+        };
+        Ok(Self {
+            path,
+            auto_commit,
+            encryption,
+        })
     }
 
     /// Initialize a new workspace
@@ -57,9 +79,135 @@ impl WorkspaceRepository {
             config.settings.overdue_threshold_days
         );
 
-        fs::write(&config_path, content)?;
+        write_atomic(&config_path, content)?;
+
+        Ok(Self {
+            path,
+            auto_commit: None,
+            encryption: Encryption::Off,
+        })
+    }
+
+    /// Git repository to auto-commit to, if the workspace is in one and
+    /// `git.auto_commit` is on
+    ///
+    /// Reads the config leniently: an unreadable config disables
+    /// auto-commit here and is reported properly by [`Self::load`].
+    fn auto_commit_repo(path: &Path) -> Option<AutoCommit> {
+        let content = fs::read_to_string(path.join(WORKSPACE_FILE)).ok()?;
+        let config: WorkspaceConfig = if content.trim().is_empty() {
+            WorkspaceConfig::default()
+        } else {
+            serde_yaml::from_str(&content).ok()?
+        };
+        if !config.settings.git.auto_commit {
+            return None;
+        }
+        GitRepo::discover(path).map(AutoCommit::new)
+    }
+
+    /// The latest auto-commit failure since the last call, if any (the save
+    /// itself went through)
+    pub fn take_auto_commit_failure(&self) -> Option<String> {
+        self.auto_commit.as_ref()?.take_failure()
+    }
+
+    /// Commit, pull and push the workspace's git repository
+    ///
+    /// Fails if the workspace isn't inside a git repository. Conflicts are
+    /// not an error: see [`SyncOutcome::Conflicts`].
+    pub fn sync(&self, settings: &GitSettings) -> StorageResult<SyncOutcome> {
+        let git = GitRepo::discover(&self.path).ok_or_else(|| {
+            StorageError::Git(format!("{:?} is not in a git repository", self.path))
+        })?;
+        git.sync(&self.path, &settings.remote, settings.branch.as_deref())
+    }
+
+    /// Whether profiles and entries are stored encrypted
+    pub fn is_encrypted(&self) -> bool {
+        !matches!(self.encryption, Encryption::Off)
+    }
 
-        Ok(Self { path })
+    /// Whether the workspace is encrypted and still waiting for its passphrase
+    pub fn is_locked(&self) -> bool {
+        matches!(self.encryption, Encryption::Locked)
+    }
+
+    /// Derive the key of an encrypted workspace from its passphrase
+    pub fn unlock(&mut self, passphrase: &str) -> StorageResult<()> {
+        let header = EncryptionHeader::read(&self.path)?
+            .ok_or_else(|| StorageError::Crypto("Workspace is not encrypted".to_string()))?;
+        self.encryption = Encryption::Unlocked(Cipher::unlock(passphrase, &header)?);
+        Ok(())
+    }
+
+    /// Read a profile or entry file, decrypting it if needed
+    pub fn read_text(&self, path: &Path) -> StorageResult<String> {
+        self.encryption.read(path)
+    }
+
+    /// Write a profile or entry file, encrypting it if the workspace is
+    pub fn write_text(&self, path: &Path, content: &str) -> StorageResult<()> {
+        self.encryption.write(path, content)
+    }
+
+    /// Decode file contents read elsewhere (e.g. from git history)
+    pub fn decode_text(&self, data: Vec<u8>, path: &Path) -> StorageResult<String> {
+        self.encryption.decode(data, path)
+    }
+
+    /// Encrypt every profile and entry; returns how many files were converted
+    ///
+    /// `passphrase` sets the key of a plaintext workspace. An unlocked
+    /// encrypted workspace keeps its key, which resumes an interrupted run.
+    /// The header is written first, so a crash part-way leaves a workspace
+    /// that reads fine (plaintext files stay readable) and can be re-run.
+    pub fn encrypt(&mut self, passphrase: &str) -> StorageResult<usize> {
+        match &self.encryption {
+            Encryption::Off => {
+                let (cipher, header) = Cipher::create(passphrase)?;
+                header.write(&self.path)?;
+                self.encryption = Encryption::Unlocked(cipher);
+            }
+            Encryption::Locked => {
+                return Err(StorageError::Locked(self.path.display().to_string()))
+            }
+            Encryption::Unlocked(_) => {}
+        }
+        let target = self.encryption.clone();
+        self.convert_files(&target, |data| !crypto::is_encrypted(data))
+    }
+
+    /// Decrypt every profile and entry and turn encryption off
+    ///
+    /// The workspace must be unlocked. Returns how many files were converted.
+    pub fn decrypt(&mut self) -> StorageResult<usize> {
+        if !matches!(self.encryption, Encryption::Unlocked(_)) {
+            return Err(StorageError::Locked(self.path.display().to_string()));
+        }
+        // Until the header is removed, half-converted workspaces still read fine
+        let converted = self.convert_files(&Encryption::Off, crypto::is_encrypted)?;
+        fs::remove_file(self.path.join(ENCRYPTION_FILE))?;
+        self.encryption = Encryption::Off;
+        Ok(converted)
+    }
+
+    /// Rewrite the files whose raw contents match `needs_conversion` as `target`
+    fn convert_files(
+        &self,
+        target: &Encryption,
+        needs_conversion: impl Fn(&[u8]) -> bool,
+    ) -> StorageResult<usize> {
+        let mut converted = 0;
+        for path in crypto::workspace_files(&self.path)? {
+            let data = fs::read(&path)?;
+            if needs_conversion(&data) {
+                let text = self.encryption.decode(data, &path)?;
+                target.write(&path, &text)?;
+                converted += 1;
+            }
+        }
+        Ok(converted)
     }
 
     /// Check if a path is a valid workspace
@@ -85,7 +233,82 @@ impl WorkspaceRepository {
             serde_yaml::from_str(&content)?
         };
 
-        Ok(Workspace::new(self.path.clone(), config))
+        // An older app would misread a newer layout; older layouts still
+        // load and are upgraded by `vibe-manager migrate`
+        if config.version > CURRENT_VERSION {
+            return Err(StorageError::InvalidConfig(format!(
+                "workspace format version {} is newer than this vibe-manager supports ({})",
+                config.version, CURRENT_VERSION
+            )));
+        }
+
+        // Reject nonsensical weights up front rather than rendering a
+        // dashboard where every urgency score is zero or saturated
+        config
+            .settings
+            .scoring
+            .validate()
+            .map_err(StorageError::InvalidConfig)?;
+        config
+            .settings
+            .theme
+            .validate()
+            .map_err(StorageError::InvalidConfig)?;
+        if let Some(caldav) = &config.settings.calendar.caldav {
+            caldav.validate().map_err(StorageError::InvalidConfig)?;
+        }
+
+        let mut workspace = Workspace::new(self.path.clone(), config);
+        workspace.interrupted_writes = atomic::interrupted_writes(&self.path)?;
+        Ok(workspace)
+    }
+
+    /// Finish a save that crashed before replacing its file
+    ///
+    /// `temp` is one of [`Workspace::interrupted_writes`]. A note that can't
+    /// be read back (not decryptable, or with broken frontmatter) is refused
+    /// rather than moved over the last good version.
+    pub fn restore_interrupted_write(&self, temp: &Path) -> StorageResult<PathBuf> {
+        let target = atomic::target_path(temp).ok_or_else(|| {
+            StorageError::InvalidWorkspace(format!("{:?} is not an interrupted write", temp))
+        })?;
+        if target.extension().is_some_and(|ext| ext == "md") {
+            let text = self.read_text(temp)?;
+            if let Some(yaml) = crate::storage::parse_frontmatter(&text).0 {
+                serde_yaml::from_str::<serde_yaml::Value>(yaml)?;
+            }
+        }
+        Ok(atomic::restore(temp)?)
+    }
+
+    /// Delete a leftover temp file, keeping the file it would have replaced
+    pub fn discard_interrupted_write(&self, temp: &Path) -> StorageResult<()> {
+        if atomic::target_path(temp).is_none() {
+            return Err(StorageError::InvalidWorkspace(format!(
+                "{:?} is not an interrupted write",
+                temp
+            )));
+        }
+        fs::remove_file(temp)?;
+        Ok(())
+    }
+
+    /// The dashboard filter and sort last used in this workspace
+    ///
+    /// Without a `.vibe-view` file this is the default: everyone, most
+    /// urgent first.
+    pub fn load_roster_view(&self) -> StorageResult<RosterView> {
+        let path = self.path.join(VIEW_FILE);
+        if !path.exists() {
+            return Ok(RosterView::default());
+        }
+        Ok(serde_yaml::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Remember the dashboard filter and sort for the next session
+    pub fn save_roster_view(&self, view: &RosterView) -> StorageResult<()> {
+        write_atomic(&self.path.join(VIEW_FILE), serde_yaml::to_string(view)?)?;
+        Ok(())
     }
 
     /// Get the workspace path
@@ -95,8 +318,14 @@ impl WorkspaceRepository {
 
     /// Get a repository for a specific report by slug
     pub fn report(&self, slug: &str) -> ReportRepository {
-        let path = self.path.join(slug);
-        ReportRepository::new(path, None)
+        self.report_at(self.path.join(slug), None)
+    }
+
+    /// Get a repository for a report directory at any depth
+    pub fn report_at(&self, path: PathBuf, manager_slug: Option<String>) -> ReportRepository {
+        ReportRepository::new(path, manager_slug)
+            .with_auto_commit(self.auto_commit.clone())
+            .with_encryption(self.encryption.clone())
     }
 
     /// List all direct report repositories in the workspace
@@ -112,7 +341,7 @@ impl WorkspaceRepository {
                 // Skip hidden directories and special folders
                 if let Some(name) = name {
                     if !name.starts_with('.') && path.join("_profile.md").exists() {
-                        repos.push(ReportRepository::new(path, None));
+                        repos.push(self.report_at(path, None));
                     }
                 }
             }
@@ -122,6 +351,22 @@ impl WorkspaceRepository {
         Ok(repos)
     }
 
+    /// List every report repository at any depth: direct reports first,
+    /// then each manager's team members (recursively), in walk order
+    pub fn list_all_reports(&self) -> StorageResult<Vec<ReportRepository>> {
+        let mut all = Vec::new();
+        let mut pending = self.list_reports()?;
+        while !pending.is_empty() {
+            let mut next = Vec::new();
+            for repo in pending {
+                next.extend(repo.list_team_members()?);
+                all.push(repo);
+            }
+            pending = next;
+        }
+        Ok(all)
+    }
+
     /// Create a new report in the workspace
     pub fn create_report(
         &self,
@@ -151,7 +396,7 @@ impl WorkspaceRepository {
         );
         let report = Report::new(slug, dir.clone(), profile, notes_content);
 
-        let repo = ReportRepository::new(dir, None);
+        let repo = self.report_at(dir, None);
         repo.save(&report)?;
         Ok(repo)
     }
@@ -171,7 +416,7 @@ mod tests {
         assert!(WorkspaceRepository::is_valid(path));
 
         let workspace = repo.load().unwrap();
-        assert_eq!(workspace.config.version, 1);
+        assert_eq!(workspace.config.version, CURRENT_VERSION);
     }
 
     #[test]
@@ -183,4 +428,66 @@ mod tests {
         let result = WorkspaceRepository::init(path);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_roster_view_round_trips() {
+        use crate::model::{RosterFilter, RosterLayout, RosterSort};
+
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        assert_eq!(repo.load_roster_view().unwrap(), RosterView::default());
+
+        let view = RosterView {
+            filter: RosterFilter::Tag("payments".to_string()),
+            sort: RosterSort::Mood,
+            layout: RosterLayout::Table,
+        };
+        repo.save_roster_view(&view).unwrap();
+        assert_eq!(repo.load_roster_view().unwrap(), view);
+    }
+
+    #[test]
+    fn test_load_custom_scoring_weights() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        fs::write(
+            path.join(WORKSPACE_FILE),
+            "version: 1\nsettings:\n  scoring:\n    urgency:\n      low_mood: 50\n",
+        )
+        .unwrap();
+
+        let workspace = WorkspaceRepository::open(path).unwrap().load().unwrap();
+        let urgency = &workspace.config.settings.scoring.urgency;
+        assert_eq!(urgency.low_mood, 50);
+        // Unspecified weights keep their defaults
+        assert_eq!(urgency.never_met, 100);
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_scoring_weights() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        fs::write(
+            path.join(WORKSPACE_FILE),
+            "version: 1\nsettings:\n  scoring:\n    health:\n      overdue: 250\n",
+        )
+        .unwrap();
+
+        let result = WorkspaceRepository::open(path).unwrap().load();
+        assert!(matches!(result, Err(StorageError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_theme_colour() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        fs::write(
+            path.join(WORKSPACE_FILE),
+            "version: 2\nsettings:\n  theme:\n    preset: light\n    colors:\n      primary: teal-ish\n",
+        )
+        .unwrap();
+
+        let result = WorkspaceRepository::open(path).unwrap().load();
+        assert!(matches!(result, Err(StorageError::InvalidConfig(_))));
+    }
 }
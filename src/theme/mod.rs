@@ -1,4 +1,6 @@
+mod palette;
 mod rpg;
 pub mod sprites;
 
+pub use palette::*;
 pub use rpg::*;
@@ -0,0 +1,320 @@
+//! Runtime colour palette
+//!
+//! The `style_*` helpers and colour functions in this module read the
+//! active [`Theme`] rather than fixed constants, so components keep calling
+//! `style_header()` or `mood_color(m)` and pick up whichever palette the
+//! workspace, the user or `NO_COLOR` asked for.
+//!
+//! The active theme is per thread: the TUI renders on one thread, and tests
+//! rendering side by side can't see each other's theme.
+
+use std::cell::Cell;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use ratatui::style::Color;
+
+use crate::model::{ThemePreset, ThemeSettings};
+use crate::utils::user_config_path;
+
+/// Colours for each role in the UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Borders, headers, the highlight bar
+    pub primary: Color,
+    /// Titles, focused borders, the selected option
+    pub secondary: Color,
+    pub accent: Color,
+    /// Status: good
+    pub success: Color,
+    /// Status: needs attention
+    pub warning: Color,
+    /// Status: bad
+    pub danger: Color,
+    pub muted: Color,
+    pub text: Color,
+    pub text_dim: Color,
+    /// Text on a `primary` background
+    pub on_primary: Color,
+    /// Background of the status bar's mode badge
+    pub badge: Color,
+    /// Mood 1 to 5, dim to bright
+    pub mood: [Color; 5],
+    /// Mood trend arrows
+    pub rising: Color,
+    pub falling: Color,
+    /// Whether per-report colours are shown; without colour, highlights
+    /// fall back to reverse video
+    pub colored: bool,
+}
+
+impl Theme {
+    /// Accessible 8-bit RPG palette: blue/orange instead of green/red,
+    /// magenta for danger
+    pub const RPG: Theme = Theme {
+        primary: Color::Cyan,
+        secondary: Color::Yellow,
+        accent: Color::Magenta,
+        success: Color::Cyan,
+        warning: Color::Yellow,
+        danger: Color::Magenta,
+        muted: Color::Gray,
+        text: Color::White,
+        text_dim: Color::Gray,
+        on_primary: Color::Black,
+        badge: Color::DarkGray,
+        mood: [
+            Color::Magenta,
+            Color::LightMagenta,
+            Color::Yellow,
+            Color::LightCyan,
+            Color::Cyan,
+        ],
+        rising: Color::Green,
+        falling: Color::Rgb(255, 140, 0),
+        colored: true,
+    };
+
+    /// Bright colours only, no greys
+    pub const HIGH_CONTRAST: Theme = Theme {
+        primary: Color::LightCyan,
+        secondary: Color::LightYellow,
+        accent: Color::LightMagenta,
+        success: Color::LightCyan,
+        warning: Color::LightYellow,
+        danger: Color::LightMagenta,
+        muted: Color::White,
+        text: Color::White,
+        text_dim: Color::White,
+        on_primary: Color::Black,
+        badge: Color::Black,
+        mood: [
+            Color::LightMagenta,
+            Color::LightMagenta,
+            Color::LightYellow,
+            Color::LightCyan,
+            Color::LightCyan,
+        ],
+        rising: Color::LightCyan,
+        falling: Color::LightYellow,
+        colored: true,
+    };
+
+    /// The terminal's own colours throughout
+    pub const MONOCHROME: Theme = Theme {
+        primary: Color::Reset,
+        secondary: Color::Reset,
+        accent: Color::Reset,
+        success: Color::Reset,
+        warning: Color::Reset,
+        danger: Color::Reset,
+        muted: Color::Reset,
+        text: Color::Reset,
+        text_dim: Color::Reset,
+        on_primary: Color::Reset,
+        badge: Color::Reset,
+        mood: [Color::Reset; 5],
+        rising: Color::Reset,
+        falling: Color::Reset,
+        colored: false,
+    };
+
+    /// Darker 256-colour shades for light backgrounds, where yellow and
+    /// light cyan wash out
+    pub const LIGHT: Theme = Theme {
+        primary: Color::Blue,
+        secondary: Color::Indexed(130),
+        accent: Color::Magenta,
+        success: Color::Blue,
+        warning: Color::Indexed(130),
+        danger: Color::Magenta,
+        muted: Color::DarkGray,
+        text: Color::Black,
+        text_dim: Color::DarkGray,
+        on_primary: Color::White,
+        badge: Color::Indexed(252),
+        mood: [
+            Color::Magenta,
+            Color::Indexed(133),
+            Color::Indexed(130),
+            Color::Indexed(31),
+            Color::Blue,
+        ],
+        rising: Color::Indexed(28),
+        falling: Color::Indexed(166),
+        colored: true,
+    };
+
+    pub fn preset(preset: ThemePreset) -> Theme {
+        match preset {
+            ThemePreset::Rpg => Theme::RPG,
+            ThemePreset::HighContrast => Theme::HIGH_CONTRAST,
+            ThemePreset::Monochrome => Theme::MONOCHROME,
+            ThemePreset::Light => Theme::LIGHT,
+        }
+    }
+
+    /// Build the theme from settings layers, lowest priority first
+    ///
+    /// A layer's preset replaces everything below it; its colours then
+    /// override single roles. `no_color` (the `NO_COLOR` convention) wins
+    /// over all of them. Overrides are expected to be validated already
+    /// (see [`ThemeSettings::validate`]); invalid ones are skipped.
+    pub fn resolve(layers: &[&ThemeSettings], no_color: bool) -> Theme {
+        if no_color {
+            return Theme::MONOCHROME;
+        }
+        let mut theme = Theme::default();
+        for layer in layers {
+            if let Some(preset) = layer.preset {
+                theme = Theme::preset(preset);
+            }
+            for (role, value) in &layer.colors {
+                if let Ok(color) = value.parse() {
+                    theme.set(role, color);
+                }
+            }
+        }
+        theme
+    }
+
+    fn set(&mut self, role: &str, color: Color) {
+        let slot = match role {
+            "primary" => &mut self.primary,
+            "secondary" => &mut self.secondary,
+            "accent" => &mut self.accent,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "danger" => &mut self.danger,
+            "muted" => &mut self.muted,
+            "text" => &mut self.text,
+            "text_dim" => &mut self.text_dim,
+            "on_primary" => &mut self.on_primary,
+            "badge" => &mut self.badge,
+            "mood_1" => &mut self.mood[0],
+            "mood_2" => &mut self.mood[1],
+            "mood_3" => &mut self.mood[2],
+            "mood_4" => &mut self.mood[3],
+            "mood_5" => &mut self.mood[4],
+            "rising" => &mut self.rising,
+            "falling" => &mut self.falling,
+            _ => return,
+        };
+        *slot = color;
+    }
+
+    /// A report's own colour, or plain text when colours are off
+    pub fn report(&self, color: Color) -> Color {
+        if self.colored {
+            color
+        } else {
+            self.text
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::RPG
+    }
+}
+
+thread_local! {
+    static ACTIVE: Cell<Theme> = const { Cell::new(Theme::RPG) };
+}
+
+/// The theme this thread renders with
+pub fn theme() -> Theme {
+    ACTIVE.with(Cell::get)
+}
+
+/// Switch this thread's theme
+pub fn set_theme(theme: Theme) {
+    ACTIVE.with(|active| active.set(theme));
+}
+
+/// Whether `NO_COLOR` is set to a non-empty value (<https://no-color.org>)
+pub fn no_color_requested() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// `theme.yaml` in the user's config directory (see [`user_config_path`])
+pub fn user_theme_path() -> Option<PathBuf> {
+    user_config_path("theme.yaml")
+}
+
+/// The user's own theme settings, which take priority over the workspace's
+///
+/// Same fields as the workspace `theme:` section. A missing file is `None`.
+pub fn load_user_theme() -> Result<Option<ThemeSettings>> {
+    let Some(path) = user_theme_path().filter(|path| path.exists()) else {
+        return Ok(None);
+    };
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {:?}", path))?;
+    let settings: ThemeSettings =
+        serde_yaml::from_str(&content).with_context(|| format!("Invalid theme in {:?}", path))?;
+    settings
+        .validate()
+        .map_err(anyhow::Error::msg)
+        .with_context(|| format!("Invalid theme in {:?}", path))?;
+    Ok(Some(settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn settings(preset: Option<ThemePreset>, colors: &[(&str, &str)]) -> ThemeSettings {
+        ThemeSettings {
+            preset,
+            colors: colors
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_layers() {
+        let workspace = settings(Some(ThemePreset::Light), &[("danger", "red")]);
+        let theme = Theme::resolve(&[&workspace], false);
+        assert_eq!(theme.primary, Theme::LIGHT.primary);
+        assert_eq!(theme.danger, Color::Red);
+
+        // The user's colours stack on the workspace's...
+        let user = settings(None, &[("mood_1", "#ff0000"), ("primary", "208")]);
+        let theme = Theme::resolve(&[&workspace, &user], false);
+        assert_eq!(theme.danger, Color::Red);
+        assert_eq!(theme.mood[0], Color::Rgb(255, 0, 0));
+        assert_eq!(theme.primary, Color::Indexed(208));
+
+        // ...but the user's preset starts over
+        let user = settings(Some(ThemePreset::HighContrast), &[]);
+        assert_eq!(
+            Theme::resolve(&[&workspace, &user], false),
+            Theme::HIGH_CONTRAST
+        );
+
+        assert_eq!(Theme::resolve(&[&workspace], true), Theme::MONOCHROME);
+    }
+
+    #[test]
+    fn test_every_role_can_be_overridden() {
+        for role in crate::model::THEME_ROLES {
+            let mut theme = Theme::MONOCHROME;
+            theme.set(role, Color::Red);
+            assert_ne!(theme, Theme::MONOCHROME, "role {} not applied", role);
+        }
+    }
+
+    #[test]
+    fn test_monochrome_hides_report_colours() {
+        let color = Color::Rgb(100, 149, 237);
+        assert_eq!(Theme::RPG.report(color), color);
+        assert_eq!(Theme::MONOCHROME.report(color), Color::Reset);
+    }
+}
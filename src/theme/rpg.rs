@@ -6,7 +6,8 @@
 //! ## Accessibility Considerations
 //! - Colors are paired with icons/shapes, never color-alone
 //! - Avoids red-green distinctions (uses blue/orange instead for status)
-//! - High contrast mode available
+//! - High-contrast, monochrome and light-terminal presets (`theme:` setting);
+//!   `NO_COLOR` turns colour off
 //! - Numeric values shown alongside visual gauges
 
 use ratatui::{
@@ -14,44 +15,32 @@ use ratatui::{
     widgets::{Block, BorderType, Borders},
 };
 
+use super::palette::theme;
+
 // ═══════════════════════════════════════════════════════════════
-// Color Palette - Accessible Design
+// Styles
 // ═══════════════════════════════════════════════════════════════
 //
-// Design principles:
+// Colours come from the active theme (see `palette`). Design principles
+// every preset follows:
 // - Use BLUE for positive/success (instead of green)
 // - Use ORANGE/YELLOW for warnings
 // - Use MAGENTA for danger/critical (instead of red)
 // - Never rely on color alone - always pair with icons/text
 // - High contrast between foreground and background
 
-pub const COLOR_PRIMARY: Color = Color::Cyan;
-pub const COLOR_SECONDARY: Color = Color::Yellow;
-pub const COLOR_ACCENT: Color = Color::Magenta;
-
-// Status colors - colorblind-safe palette
-// Using Blue-Orange opposition instead of Red-Green
-pub const COLOR_SUCCESS: Color = Color::Cyan; // Blue-ish for "good"
-pub const COLOR_WARNING: Color = Color::Yellow; // Yellow for "attention"
-pub const COLOR_DANGER: Color = Color::Magenta; // Magenta for "bad" (not red)
-
-// Text colors with good contrast
-pub const COLOR_MUTED: Color = Color::Gray; // Lighter than DarkGray
-pub const COLOR_TEXT: Color = Color::White;
-pub const COLOR_TEXT_DIM: Color = Color::Gray;
-
-// ═══════════════════════════════════════════════════════════════
-// Styles
-// ═══════════════════════════════════════════════════════════════
-
 pub fn style_default() -> Style {
-    Style::default().fg(COLOR_TEXT)
+    Style::default().fg(theme().text)
 }
 
 pub fn style_highlight() -> Style {
+    let theme = theme();
+    if !theme.colored {
+        return Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD);
+    }
     Style::default()
-        .fg(Color::Black)
-        .bg(COLOR_PRIMARY)
+        .fg(theme.on_primary)
+        .bg(theme.primary)
         .add_modifier(Modifier::BOLD)
 }
 
@@ -61,30 +50,35 @@ pub fn style_selected() -> Style {
 
 pub fn style_title() -> Style {
     Style::default()
-        .fg(COLOR_SECONDARY)
+        .fg(theme().secondary)
         .add_modifier(Modifier::BOLD)
 }
 
 pub fn style_header() -> Style {
     Style::default()
-        .fg(COLOR_PRIMARY)
+        .fg(theme().primary)
         .add_modifier(Modifier::BOLD)
 }
 
 pub fn style_success() -> Style {
-    Style::default().fg(COLOR_SUCCESS)
+    Style::default().fg(theme().success)
 }
 
 pub fn style_warning() -> Style {
-    Style::default().fg(COLOR_WARNING)
+    Style::default().fg(theme().warning)
 }
 
 pub fn style_danger() -> Style {
-    Style::default().fg(COLOR_DANGER)
+    Style::default().fg(theme().danger)
 }
 
 pub fn style_muted() -> Style {
-    Style::default().fg(COLOR_MUTED)
+    Style::default().fg(theme().muted)
+}
+
+/// Foreground in a report's own colour (plain text when colours are off)
+pub fn style_report(color: Color) -> Style {
+    Style::default().fg(theme().report(color))
 }
 
 /// Style for selected/unselected options in selection lists
@@ -93,7 +87,7 @@ pub fn style_muted() -> Style {
 pub fn selection_style(is_selected: bool) -> Style {
     if is_selected {
         Style::default()
-            .fg(COLOR_SECONDARY)
+            .fg(theme().secondary)
             .add_modifier(Modifier::BOLD)
     } else {
         style_muted()
@@ -105,11 +99,11 @@ pub fn selection_style(is_selected: bool) -> Style {
 // ═══════════════════════════════════════════════════════════════
 
 /// Create an RPG-style block with double borders ╔═══╗
-pub fn rpg_block(title: &str) -> Block<'_> {
+pub fn rpg_block(title: &str) -> Block<'static> {
     Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Double)
-        .border_style(Style::default().fg(COLOR_PRIMARY))
+        .border_style(Style::default().fg(theme().primary))
         .title(format!("═ {} ═", title))
         .title_style(style_title())
 }
@@ -119,9 +113,9 @@ pub fn simple_block(title: &str) -> Block<'_> {
     Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(COLOR_MUTED))
+        .border_style(Style::default().fg(theme().muted))
         .title(title)
-        .title_style(Style::default().fg(COLOR_TEXT_DIM))
+        .title_style(Style::default().fg(theme().text_dim))
 }
 
 /// Create an active/focused block
@@ -129,7 +123,7 @@ pub fn focused_block(title: &str) -> Block<'_> {
     Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Double)
-        .border_style(Style::default().fg(COLOR_SECONDARY))
+        .border_style(Style::default().fg(theme().secondary))
         .title(format!("▶ {} ◀", title))
         .title_style(style_title())
 }
@@ -170,13 +164,10 @@ pub fn mood_gauge_with_value(value: u8) -> String {
 pub fn mood_color(value: u8) -> Color {
     // Using brightness/saturation instead of red-green hue shift
     // All users can perceive brightness differences
+    let theme = theme();
     match value {
-        1 => COLOR_DANGER,        // Magenta - distinct
-        2 => Color::LightMagenta, // Lighter magenta
-        3 => COLOR_WARNING,       // Yellow - neutral
-        4 => Color::LightCyan,    // Light cyan
-        5 => COLOR_SUCCESS,       // Cyan - positive
-        _ => COLOR_MUTED,
+        1..=5 => theme.mood[value as usize - 1],
+        _ => theme.muted,
     }
 }
 
@@ -210,9 +201,9 @@ pub fn overdue_icon(is_overdue: bool) -> &'static str {
 /// Uses blue/yellow distinction (colorblind-safe)
 pub fn overdue_color(is_overdue: bool) -> Color {
     if is_overdue {
-        COLOR_WARNING // Yellow
+        theme().warning
     } else {
-        COLOR_SUCCESS // Cyan
+        theme().success
     }
 }
 
@@ -0,0 +1,70 @@
+//! Fuzzy matching for pickers
+
+/// How well `query` matches `text`, higher is better; `None` if it doesn't
+///
+/// The query's letters must appear in order, ignoring case and spaces.
+/// Letters that start a word or follow the previous match score extra, so
+/// "ac" ranks "Alex Chen" above "Jack".
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let Some(&first) = query.first() else {
+        return Some(0);
+    };
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+
+    // Try each place the first letter occurs; matching the rest greedily
+    // from there is good enough for names and command labels
+    (0..text.len())
+        .filter(|&start| text[start] == first)
+        .filter_map(|start| score_from(&query, &text, start))
+        .max()
+}
+
+fn score_from(query: &[char], text: &[char], start: usize) -> Option<i32> {
+    let mut score = 0;
+    let mut at = start;
+    let mut previous: Option<usize> = None;
+    for &c in query {
+        let found = at + text[at..].iter().position(|&t| t == c)?;
+        score += 10;
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 6;
+        }
+        match previous {
+            Some(p) if found == p + 1 => score += 8,
+            Some(p) => score -= (found - p - 1).min(5) as i32,
+            None => score -= start.min(10) as i32,
+        }
+        previous = Some(found);
+        at = found + 1;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters_in_order() {
+        assert!(fuzzy_score("alx", "Alex Chen").is_some());
+        assert!(fuzzy_score("xla", "Alex Chen").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert!(fuzzy_score("alex chen", "Alex Chen").is_some());
+    }
+
+    #[test]
+    fn test_word_starts_and_runs_rank_higher() {
+        let initials = fuzzy_score("ac", "Alex Chen").unwrap();
+        let inside = fuzzy_score("ac", "Jack").unwrap();
+        assert!(initials > inside);
+
+        let prefix = fuzzy_score("sync", "Git sync").unwrap();
+        let scattered = fuzzy_score("sync", "Show your notes, cancel").unwrap();
+        assert!(prefix > scattered);
+    }
+}
@@ -1,9 +1,14 @@
+mod fuzzy;
 mod name;
 mod slug;
 
+pub use fuzzy::*;
 pub use name::*;
 pub use slug::*;
 
+use std::env;
+use std::path::PathBuf;
+
 use ratatui::style::Color;
 
 /// Color palette for report display colors - warm, friendly tones
@@ -47,3 +52,13 @@ pub fn report_color(color: Option<&str>, name: &str) -> Color {
         .and_then(parse_hex_color)
         .unwrap_or_else(|| color_from_name(name))
 }
+
+/// `file` in the user's config directory: `$XDG_CONFIG_HOME/vibe-manager/`,
+/// else `~/.config/vibe-manager/`
+pub fn user_config_path(file: &str) -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config.join("vibe-manager").join(file))
+}
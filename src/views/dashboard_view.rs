@@ -1,15 +1,21 @@
 //! Dashboard view - main layout for team overview
 
+use chrono::Local;
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Position, Rect},
     Frame,
 };
 
-use crate::app::{App, ViewMode};
+use super::screen_layout;
+use crate::app::keymap::{Action, Scope};
+use crate::app::{App, MouseTarget, ViewMode};
 use crate::components::{
-    render_empty_state, Dashboard, HallHeader, HelpModal, NewReportModal, StatusBar,
+    render_empty_state, Dashboard, HallHeader, HeatmapGrid, HelpModal, MigrateConfirmModal,
+    NewReportModal, StatusBar, WarningsModal,
+};
+use crate::model::{
+    compute_mood_heatmap, compute_team_metrics_with_settings, RosterFilter, RosterLayout,
 };
-use crate::model::compute_team_metrics;
 
 /// Build the hall header from the navigation stack (None at root)
 fn hall_header(app: &App) -> Option<HallHeader> {
@@ -24,7 +30,8 @@ fn hall_header(app: &App) -> Option<HallHeader> {
     }
     breadcrumb.push_str("'S SQUAD");
 
-    let metrics = compute_team_metrics(&app.summaries);
+    let metrics =
+        compute_team_metrics_with_settings(&app.summaries, &app.workspace.config.settings);
     let first_name = current
         .name
         .split_whitespace()
@@ -38,29 +45,73 @@ fn hall_header(app: &App) -> Option<HallHeader> {
     })
 }
 
+/// Per report on the roster: whether the table shows its squad line
+fn expanded_squads(app: &App) -> Vec<bool> {
+    app.reports
+        .iter()
+        .map(|report| app.expanded_squads.contains(&report.slug))
+        .collect()
+}
+
+fn dashboard<'a>(app: &'a App, expanded: &'a [bool]) -> Dashboard<'a> {
+    let mut dashboard = Dashboard::new(&app.summaries, &app.workspace_summary, app.selected_index);
+    if let Some(hall) = hall_header(app) {
+        dashboard = dashboard.with_hall(hall);
+    }
+    if let Some(indicator) = app.roster_view.indicator() {
+        let keys = [
+            (Action::CycleFilter, "for the next filter"),
+            (Action::ClearFilter, "to show everyone"),
+        ]
+        .iter()
+        .filter_map(|(action, what)| {
+            let key = app.keymap.hint(Scope::Dashboard, &[*action])?;
+            Some(format!("{} {}", key, what))
+        })
+        .collect::<Vec<_>>();
+        let mut no_match = format!("Nobody here matches: {}", app.roster_view.filter.label());
+        if !keys.is_empty() {
+            no_match = format!("{} ({})", no_match, keys.join(", "));
+        }
+        dashboard = dashboard.with_view(indicator, no_match);
+    }
+    if app.roster_view.layout == RosterLayout::Table {
+        dashboard = dashboard.with_table(
+            expanded,
+            app.roster_view.sort,
+            app.workspace.config.settings.mood_scoring,
+        );
+    }
+    dashboard
+}
+
 pub fn render_dashboard_view(app: &App, frame: &mut Frame) {
     let size = frame.area();
 
     // Main layout: content area + status bar
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(10), Constraint::Length(1)])
-        .split(size);
+    let chunks = screen_layout(size);
 
-    // Render main content
-    if app.reports.is_empty() {
+    // Render main content (a filter hiding everyone isn't an empty party)
+    if app.reports.is_empty() && app.roster_view.filter == RosterFilter::All {
         render_empty_state(frame, chunks[0]);
     } else {
-        let mut dashboard =
-            Dashboard::new(&app.summaries, &app.workspace_summary, app.selected_index);
-        if let Some(hall) = hall_header(app) {
-            dashboard = dashboard.with_hall(hall);
-        }
-        dashboard.render(frame, chunks[0]);
+        dashboard(app, &expanded_squads(app)).render(frame, chunks[0]);
     }
 
     // Render status bar
-    let context = if app.hall_stack.is_empty() {
+    let context = if !app.sync_conflicts.is_empty() {
+        // Persists until a clean sync, unlike the status message
+        let then_sync = app
+            .keymap
+            .hint(Scope::Dashboard, &[Action::Sync])
+            .map(|key| format!(", then {} to sync", key))
+            .unwrap_or_default();
+        format!(
+            "⚠ Sync conflicts: {} — resolve with git{}",
+            app.sync_conflicts.join(", "),
+            then_sync
+        )
+    } else if app.hall_stack.is_empty() {
         format!(
             "{} reports • {} overdue",
             app.workspace_summary.active_count, app.workspace_summary.overdue_count
@@ -71,19 +122,98 @@ pub fn render_dashboard_view(app: &App, frame: &mut Frame) {
             app.workspace_summary.active_count, app.workspace_summary.overdue_count
         )
     };
-    let status = StatusBar::new(app.view_mode, &context, app.status_text())
+    let context = if app.warnings.is_empty() || !app.sync_conflicts.is_empty() {
+        context
+    } else {
+        let to_view = app
+            .keymap
+            .hint(Scope::Dashboard, &[Action::Warnings])
+            .map(|key| format!(", {} to view", key))
+            .unwrap_or_default();
+        format!(
+            "{} • ⚠ {} workspace problem(s){}",
+            context,
+            app.warnings.len(),
+            to_view
+        )
+    };
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap)
         .in_hall(!app.hall_stack.is_empty());
     status.render(frame, chunks[1]);
 
     // Render modals on top
     match app.view_mode {
         ViewMode::Help => {
-            HelpModal::render(frame, size);
+            HelpModal::render(frame, size, &app.keymap);
         }
         ViewMode::NewReportModal => {
             let modal = NewReportModal::new(&app.new_report_state);
             modal.render(frame, size);
         }
+        ViewMode::Warnings => {
+            WarningsModal::new(&app.warnings, app.repo.path(), app.selected_index)
+                .render(frame, size);
+        }
+        ViewMode::MigrateConfirmModal => {
+            MigrateConfirmModal::new(&app.pending_migration, app.repo.path()).render(frame, size);
+        }
         _ => {}
     }
 }
+
+/// The card or table heading, or new report modal field, at `position`
+pub fn hit_test_dashboard_view(app: &App, area: Rect, position: Position) -> Option<MouseTarget> {
+    match app.view_mode {
+        ViewMode::Dashboard if !app.reports.is_empty() => {
+            let expanded = expanded_squads(app);
+            let dashboard = dashboard(app, &expanded);
+            let content = screen_layout(area)[0];
+            let column = dashboard
+                .column_areas(content)
+                .into_iter()
+                .find(|(_, heading)| heading.contains(position))
+                .map(|(sort, _)| MouseTarget::Column(sort));
+            column.or_else(|| {
+                dashboard
+                    .card_areas(content)
+                    .into_iter()
+                    .find(|(_, card)| card.contains(position))
+                    .map(|(idx, _)| MouseTarget::Report(idx))
+            })
+        }
+        ViewMode::NewReportModal => NewReportModal::new(&app.new_report_state)
+            .field_at(area, position)
+            .map(|(field, option)| MouseTarget::NewReportField(field, option)),
+        _ => None,
+    }
+}
+
+/// Mood heatmap for the current roster (the squad, when inside a hall)
+pub fn render_heatmap_view(app: &App, frame: &mut Frame) {
+    let size = frame.area();
+
+    let chunks = screen_layout(size);
+
+    let weeks = HeatmapGrid::weeks_for_width(chunks[0].width);
+    let heatmap = compute_mood_heatmap(
+        app.reports
+            .iter()
+            .zip(&app.entries_by_report)
+            .map(|(report, entries)| (report.profile.name.as_str(), entries.as_slice())),
+        weeks,
+        Local::now().date_naive(),
+    );
+
+    let title = match app.hall_stack.last() {
+        Some(hall) => {
+            let first_name = hall.name.split_whitespace().next().unwrap_or(&hall.name);
+            format!("{}'s Squad Mood · last {} weeks", first_name, weeks)
+        }
+        None => format!("Team Mood · last {} weeks", weeks),
+    };
+    HeatmapGrid::new(&heatmap, &title).render(frame, chunks[0]);
+
+    let context = format!("{} reports", app.reports.len());
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap);
+    status.render(frame, chunks[1]);
+}
@@ -1,14 +1,27 @@
 //! Report detail view layout
 
+use chrono::Local;
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Position, Rect},
     Frame,
 };
 
-use crate::app::{App, ViewMode};
+use super::screen_layout;
+use crate::app::{App, MouseTarget, ViewMode};
 use crate::components::{
-    DeleteConfirmModal, EntryInputModal, HelpModal, NoteViewer, ReportDetail, StatusBar,
+    DeleteConfirmModal, EntryInputModal, FileHistory, HelpModal, MeetingPrepPanel,
+    MoodAnalyticsPanel, NoteEditor, NoteViewer, ReportDetail, StatusBar,
 };
+use crate::model::{compute_meeting_prep, compute_mood_analytics};
+
+fn report_detail(app: &App, report_idx: usize) -> ReportDetail<'_> {
+    ReportDetail::new(
+        &app.reports[report_idx],
+        &app.summaries[report_idx],
+        &app.entries_by_report[report_idx],
+        app.selected_index,
+    )
+}
 
 pub fn render_detail_view(app: &App, frame: &mut Frame) {
     let size = frame.area();
@@ -20,28 +33,23 @@ pub fn render_detail_view(app: &App, frame: &mut Frame) {
     };
 
     let report = &app.reports[report_idx];
-    let summary = &app.summaries[report_idx];
     let entries = &app.entries_by_report[report_idx];
 
     // Main layout
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(10), Constraint::Length(1)])
-        .split(size);
+    let chunks = screen_layout(size);
 
     // Render report detail
-    let detail = ReportDetail::new(report, summary, entries, app.selected_index);
-    detail.render(frame, chunks[0]);
+    report_detail(app, report_idx).render(frame, chunks[0]);
 
     // Render status bar
     let meeting_count = entries.iter().filter(|e| e.is_meeting()).count();
     let context = format!("{} • {} meetings", report.profile.name, meeting_count);
-    let status = StatusBar::new(app.view_mode, &context, app.status_text());
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap);
     status.render(frame, chunks[1]);
 
     // Render help modal if active
     if app.view_mode == ViewMode::Help {
-        HelpModal::render(frame, size);
+        HelpModal::render(frame, size, &app.keymap);
     }
 
     // Render entry input modal if active
@@ -64,6 +72,89 @@ pub fn render_detail_view(app: &App, frame: &mut Frame) {
     }
 }
 
+/// The meeting row at `position`
+pub fn hit_test_detail_view(app: &App, area: Rect, position: Position) -> Option<MouseTarget> {
+    let report_idx = app.selected_report_index?;
+    report_detail(app, report_idx)
+        .meeting_at(screen_layout(area)[0], position)
+        .map(MouseTarget::Meeting)
+}
+
+pub fn render_mood_analytics_view(app: &App, frame: &mut Frame) {
+    let size = frame.area();
+
+    let report_idx = match app.selected_report_index {
+        Some(idx) => idx,
+        None => return,
+    };
+
+    let report = &app.reports[report_idx];
+    let entries = &app.entries_by_report[report_idx];
+    let analytics = compute_mood_analytics(entries, app.mood_window, Local::now().date_naive());
+
+    let chunks = screen_layout(size);
+
+    MoodAnalyticsPanel::new(&report.profile.name, &analytics).render(frame, chunks[0]);
+
+    let context = format!(
+        "{} • {} readings",
+        report.profile.name,
+        analytics.readings.len()
+    );
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap);
+    status.render(frame, chunks[1]);
+}
+
+pub fn render_meeting_prep_view(app: &App, frame: &mut Frame) {
+    let size = frame.area();
+
+    let report_idx = match app.selected_report_index {
+        Some(idx) => idx,
+        None => return,
+    };
+
+    let report = &app.reports[report_idx];
+    let prep = compute_meeting_prep(
+        report,
+        &app.entries_by_report[report_idx],
+        &app.summaries[report_idx],
+        Local::now().date_naive(),
+    );
+
+    let chunks = screen_layout(size);
+
+    MeetingPrepPanel::new(&prep).render(frame, chunks[0]);
+
+    let context = format!(
+        "{} • {} open action items",
+        report.profile.name,
+        prep.action_items.len()
+    );
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap);
+    status.render(frame, chunks[1]);
+}
+
+/// Past versions of a note or profile
+pub fn render_history_view(app: &App, frame: &mut Frame) {
+    let Some(history) = &app.history else {
+        return;
+    };
+    let size = frame.area();
+
+    let chunks = screen_layout(size);
+
+    FileHistory::new(history, app.selected_index).render(frame, chunks[0]);
+
+    let context = format!("{} • {} revisions", history.title, history.revisions.len());
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap);
+    status.render(frame, chunks[1]);
+}
+
+/// Where a note's text goes on a screen of size `area`
+pub fn note_page(area: Rect) -> Rect {
+    NoteViewer::text_area(screen_layout(area)[0])
+}
+
 pub fn render_viewer_view(app: &App, frame: &mut Frame) {
     let size = frame.area();
 
@@ -81,13 +172,12 @@ pub fn render_viewer_view(app: &App, frame: &mut Frame) {
     let entry = &app.entries_by_report[report_idx][entry_idx];
 
     // Main layout
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(10), Constraint::Length(1)])
-        .split(size);
+    let chunks = screen_layout(size);
 
     // Render viewer
-    let viewer = NoteViewer::new(entry, &app.editor_content, app.editor_mood);
+    let viewer = NoteViewer::new(entry, &app.editor_content, app.editor_mood)
+        .with_scroll(app.note_scroll)
+        .with_search(app.note_search.as_ref());
     viewer.render(frame, chunks[0]);
 
     // Render status bar
@@ -96,7 +186,7 @@ pub fn render_viewer_view(app: &App, frame: &mut Frame) {
         report.profile.name,
         entry.date().format("%Y-%m-%d")
     );
-    let status = StatusBar::new(app.view_mode, &context, app.status_text());
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap);
     status.render(frame, chunks[1]);
 
     // Render delete confirmation modal if active
@@ -105,3 +195,29 @@ pub fn render_viewer_view(app: &App, frame: &mut Frame) {
         DeleteConfirmModal::new(&date_str).render(frame, size);
     }
 }
+
+/// The inline editor, in place of the note viewer
+pub fn render_editor_view(app: &App, frame: &mut Frame) {
+    let Some(editor) = &app.note_editor else {
+        return;
+    };
+    // A note deleted outside the app is no longer in the roster
+    let entry = editor.detached.as_ref().or_else(|| {
+        let report_idx = app.selected_report_index?;
+        app.entries_by_report[report_idx].get(app.selected_entry_index?)
+    });
+    let Some(entry) = entry else {
+        return;
+    };
+
+    let chunks = screen_layout(frame.area());
+    NoteEditor::new(entry, editor, app.editor_mood).render(frame, chunks[0]);
+
+    let date = entry.date().format("%Y-%m-%d");
+    let context = match app.selected_report_index {
+        Some(report_idx) => format!("{} • {}", app.reports[report_idx].profile.name, date),
+        None => date.to_string(),
+    };
+    let status = StatusBar::new(app.view_mode, &context, app.status_text(), &app.keymap);
+    status.render(frame, chunks[1]);
+}
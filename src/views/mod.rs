@@ -3,3 +3,48 @@ pub mod detail_view;
 
 pub use dashboard_view::*;
 pub use detail_view::*;
+
+use std::rc::Rc;
+
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
+use ratatui::Frame;
+
+use crate::app::{App, MouseTarget, ViewMode};
+use crate::components::CommandPaletteModal;
+
+/// Split the screen into the view's content and the status bar below it
+fn screen_layout(area: Rect) -> Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(1)])
+        .split(area)
+}
+
+/// The view the command palette was opened over, or the current one
+pub fn underlying_view(app: &App) -> ViewMode {
+    match (&app.palette, app.view_mode) {
+        (Some(palette), ViewMode::CommandPalette) => palette.return_to,
+        (_, view_mode) => view_mode,
+    }
+}
+
+/// Draw the command palette over whatever view is already rendered
+pub fn render_command_palette(app: &App, frame: &mut Frame) {
+    if let Some(palette) = &app.palette {
+        CommandPaletteModal::new(palette).render(frame, frame.area());
+    }
+}
+
+/// What's at `position` on a screen of size `area`, for mouse events
+///
+/// Uses the layouts the views render with, so it agrees with the last frame
+/// drawn for the same state.
+pub fn hit_test(app: &App, area: Rect, position: Position) -> Option<MouseTarget> {
+    match app.view_mode {
+        ViewMode::Dashboard | ViewMode::NewReportModal => {
+            hit_test_dashboard_view(app, area, position)
+        }
+        ViewMode::ReportDetail => hit_test_detail_view(app, area, position),
+        _ => None,
+    }
+}
@@ -0,0 +1,112 @@
+//! Workspace file watching
+//!
+//! Notices notes and profiles changed outside the app (another editor, a
+//! `git pull`) so the TUI can reload them. Events are debounced: a pull that
+//! touches fifty files arrives as one batch.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+
+use crate::storage::atomic;
+
+/// How long the workspace must be quiet before a batch is delivered
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Root-level dot-files that still matter to the app
+const WATCHED_DOTFILES: &[&str] = &[".vibe-manager", ".vibe-encryption"];
+
+/// Watches a workspace until dropped
+pub struct WorkspaceWatcher {
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl WorkspaceWatcher {
+    /// Watch `root` recursively, calling `on_change` with each debounced
+    /// batch of relevant paths (see [`is_relevant`])
+    ///
+    /// `on_change` runs on the watcher's thread.
+    pub fn start(root: &Path, on_change: impl Fn(Vec<PathBuf>) + Send + 'static) -> Result<Self> {
+        let watched_root = root.to_path_buf();
+        let mut debouncer = new_debouncer(DEBOUNCE, move |result: DebounceEventResult| {
+            // Watch errors (e.g. inotify limits) just mean no live reload
+            let Ok(events) = result else {
+                return;
+            };
+            let mut paths: Vec<PathBuf> = events
+                .into_iter()
+                .map(|event| event.path)
+                .filter(|path| is_relevant(&watched_root, path))
+                .collect();
+            paths.sort();
+            paths.dedup();
+            if !paths.is_empty() {
+                on_change(paths);
+            }
+        })
+        .context("Failed to create file watcher")?;
+        debouncer
+            .watcher()
+            .watch(root, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {:?}", root))?;
+        Ok(Self {
+            _debouncer: debouncer,
+        })
+    }
+}
+
+/// Whether a change at `path` can affect what the app shows
+///
+/// Markdown files and directories (a report added or removed) count, as do
+/// the workspace config and encryption header. Git internals, in-flight
+/// atomic writes and editor swap or backup files don't.
+pub fn is_relevant(root: &Path, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+    let Some(name) = relative.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    if relative.parent() == Some(Path::new("")) && WATCHED_DOTFILES.contains(&name) {
+        return true;
+    }
+    let hidden = relative
+        .components()
+        .any(|c| c.as_os_str().to_string_lossy().starts_with('.'));
+    if hidden || atomic::target_path(path).is_some() {
+        return false;
+    }
+    match path.extension() {
+        Some(ext) => ext == "md",
+        // Directories (which may already be gone); extensionless editor
+        // artifacts like vim's `4913` probe are filtered by being digits
+        None => !name.chars().all(|c| c.is_ascii_digit()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_relevant() {
+        let root = Path::new("/w");
+        let relevant = |p: &str| is_relevant(root, Path::new(p));
+
+        assert!(relevant("/w/alex-chen/2026-01-20T143000.md"));
+        assert!(relevant("/w/alex-chen/_profile.md"));
+        assert!(relevant("/w/chris/team/taylor"));
+        assert!(relevant("/w/.vibe-manager"));
+
+        assert!(!relevant("/w/.git/index"));
+        assert!(!relevant("/w/alex-chen/._profile.md.tmp"));
+        assert!(!relevant("/w/alex-chen/.2026-01-20T143000.md.swp"));
+        assert!(!relevant("/w/alex-chen/2026-01-20T143000.md~"));
+        assert!(!relevant("/w/alex-chen/4913"));
+        assert!(!relevant("/w/alex-chen/.vibe-manager"));
+        assert!(!relevant("/elsewhere/notes.md"));
+    }
+}
@@ -105,7 +105,7 @@ mod tests {
             .enumerate()
             .filter(|(_, e)| e.is_meeting())
             .map(|(i, _)| i)
-            .last()
+            .next_back()
             .expect("No meetings found");
 
         app.delete_entry(report_idx, last_meeting_idx)
@@ -259,6 +259,496 @@ mod tests {
         assert!(app.pending_entry_notes.is_empty());
     }
 
+    #[test]
+    fn test_mood_analytics_window_cycle_and_back() {
+        use vibe_manager::app::{Msg, ViewMode};
+        use vibe_manager::model::MoodWindow;
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+
+        // Only reachable from a report's detail view
+        app.update(Msg::ShowMoodAnalytics).unwrap();
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+
+        app.selected_report_index = Some(0);
+        app.view_mode = ViewMode::ReportDetail;
+        app.update(Msg::ShowMoodAnalytics).unwrap();
+        assert_eq!(app.view_mode, ViewMode::MoodAnalytics);
+        assert_eq!(app.mood_window, MoodWindow::Month);
+
+        app.update(Msg::CycleMoodWindow).unwrap();
+        assert_eq!(app.mood_window, MoodWindow::Quarter);
+
+        // Back returns to the detail view; the window choice sticks
+        app.update(Msg::Back).unwrap();
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.selected_report_index, Some(0));
+        assert_eq!(app.mood_window, MoodWindow::Quarter);
+    }
+
+    #[test]
+    fn test_meeting_prep_opens_from_report_detail_and_back() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+
+        // Only reachable from a report's detail view
+        press(&mut app, &[KeyCode::Char('p')]);
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+
+        app.update(Msg::ViewReport).unwrap();
+        press(&mut app, &[KeyCode::Char('p')]);
+        assert_eq!(app.view_mode, ViewMode::MeetingPrep);
+
+        // The key that opened it closes it again
+        press(&mut app, &[KeyCode::Char('p')]);
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.selected_report_index, Some(0));
+    }
+
+    #[test]
+    fn test_mood_heatmap_opens_from_dashboard_and_back() {
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+        app.selected_index = 2;
+
+        app.update(Msg::ShowMoodHeatmap).unwrap();
+        assert_eq!(app.view_mode, ViewMode::MoodHeatmap);
+
+        // Back lands on the dashboard with the selection untouched
+        app.update(Msg::Back).unwrap();
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert_eq!(app.selected_index, 2);
+    }
+
+    #[test]
+    fn test_calendar_synced_reloads_and_reports() {
+        use vibe_manager::app::Msg;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let idx = app
+            .reports
+            .iter()
+            .position(|r| r.slug == "alex-chen")
+            .unwrap();
+        let before = app.entries_by_report[idx].len();
+
+        // A sync running in the background writes straight to disk
+        app.repo
+            .report("alex-chen")
+            .entries()
+            .create_meeting_at(
+                chrono::NaiveDate::from_ymd_opt(2025, 6, 2)
+                    .unwrap()
+                    .and_hms_opt(10, 0, 0)
+                    .unwrap(),
+                "# 1-on-1\n".to_string(),
+            )
+            .unwrap();
+
+        app.update(Msg::CalendarSynced("Calendar synced".to_string()))
+            .unwrap();
+        let idx = app
+            .reports
+            .iter()
+            .position(|r| r.slug == "alex-chen")
+            .unwrap();
+        assert_eq!(app.entries_by_report[idx].len(), before + 1);
+        assert_eq!(app.status_text(), Some("Calendar synced"));
+    }
+
+    #[test]
+    fn test_calendar_synced_keeps_the_open_report_and_note() {
+        use vibe_manager::app::{Msg, ViewMode};
+        use vibe_manager::model::ReportProfile;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        app.selected_index = app.reports.len() - 1;
+        app.update(Msg::ViewReport).unwrap();
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        let report_idx = app.selected_report_index.unwrap();
+        let slug = app.reports[report_idx].slug.clone();
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        let entry_idx = app.selected_entry_index.unwrap();
+        let note = app.entries_by_report[report_idx][entry_idx].path.clone();
+        app.update(Msg::Back).unwrap();
+        app.selected_index = 0;
+
+        // A never-met report written by the sync sorts to the top of the roster
+        let profile: ReportProfile = serde_yaml::from_str("name: Sam Rivera").unwrap();
+        app.repo.create_report("Sam Rivera", profile).unwrap();
+        app.update(Msg::CalendarSynced("Calendar synced".to_string()))
+            .unwrap();
+
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        let report_idx = app.selected_report_index.unwrap();
+        assert_eq!(app.reports[report_idx].slug, slug);
+
+        // The same meeting is still highlighted and opens the same note
+        app.update(Msg::ViewMeeting(app.selected_index)).unwrap();
+        let entry_idx = app.selected_entry_index.unwrap();
+        assert_eq!(app.entries_by_report[report_idx][entry_idx].path, note);
+    }
+
+    #[test]
+    fn test_theme_follows_workspace_then_user_settings() {
+        use vibe_manager::app::Msg;
+        use vibe_manager::model::{ThemePreset, ThemeSettings};
+        use vibe_manager::theme::{theme, Theme};
+
+        let temp = setup_temp_workspace();
+        let config = temp.path().join(".vibe-manager");
+        let original = std::fs::read_to_string(&config).unwrap();
+        std::fs::write(
+            &config,
+            format!("{}  theme:\n    preset: light\n", original),
+        )
+        .unwrap();
+
+        let app = vibe_manager::app::App::new(temp.path().to_path_buf()).unwrap();
+        assert_eq!(theme(), Theme::LIGHT);
+
+        let user = ThemeSettings {
+            preset: Some(ThemePreset::HighContrast),
+            ..Default::default()
+        };
+        let mut app = app.with_user_theme(Some(user));
+        assert_eq!(theme(), Theme::HIGH_CONTRAST);
+
+        // Editing the workspace config re-applies the layers
+        app.user_theme = None;
+        std::fs::write(&config, &original).unwrap();
+        app.update(Msg::FilesChanged(vec![config])).unwrap();
+        assert_eq!(theme(), Theme::RPG);
+    }
+
+    #[test]
+    fn test_files_changed_keeps_settings_when_the_config_breaks() {
+        use vibe_manager::app::Msg;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let threshold = app.workspace.config.settings.overdue_threshold_days;
+
+        // Saved from another terminal with a typo
+        let config = temp.path().join(".vibe-manager");
+        std::fs::write(&config, "settings: [oops\n").unwrap();
+        app.update(Msg::FilesChanged(vec![config])).unwrap();
+
+        assert_eq!(
+            app.workspace.config.settings.overdue_threshold_days,
+            threshold
+        );
+        assert!(app
+            .status_text()
+            .unwrap()
+            .starts_with("Kept the previous settings"));
+        assert!(!app.reports.is_empty());
+    }
+
+    #[test]
+    fn test_files_changed_leaves_a_removed_hall() {
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        app.selected_index = chris_wong_idx(&app);
+        app.update(Msg::EnterHall).unwrap();
+        app.update(Msg::ViewReport).unwrap();
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+
+        // A pull removes Chris, squad and all
+        let chris = temp.path().join("chris-wong");
+        let member = app.reports[0].path.clone();
+        std::fs::remove_dir_all(&chris).unwrap();
+        app.update(Msg::FilesChanged(vec![member])).unwrap();
+
+        assert!(app.hall_stack.is_empty());
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert!(!app.reports.iter().any(|r| r.slug == "chris-wong"));
+        assert_eq!(
+            app.status_text(),
+            Some("The hall you were in is gone; back to the top")
+        );
+    }
+
+    #[test]
+    fn test_files_changed_rechecks_only_what_changed() {
+        use vibe_manager::app::Msg;
+        use vibe_manager::storage::doctor;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let before = app.warnings.clone();
+
+        let profile = temp.path().join("alex-chen/_profile.md");
+        let original = std::fs::read_to_string(&profile).unwrap();
+        std::fs::write(&profile, "---\nname: [Alex\n---\n").unwrap();
+        app.update(Msg::FilesChanged(vec![profile.clone()]))
+            .unwrap();
+        assert!(app.warnings.iter().any(|issue| issue.path == profile));
+        // Same list, in the same order, as checking everything
+        assert_eq!(app.warnings, doctor::diagnose(&app.repo).unwrap());
+
+        std::fs::write(&profile, original).unwrap();
+        app.update(Msg::FilesChanged(vec![profile])).unwrap();
+        assert_eq!(app.warnings, before);
+    }
+
+    #[test]
+    fn test_files_changed_reloads_note_in_place() {
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let idx = app
+            .reports
+            .iter()
+            .position(|r| r.slug == "alex-chen")
+            .unwrap();
+        let order: Vec<String> = app.reports.iter().map(|r| r.slug.clone()).collect();
+
+        // Viewing the second-newest meeting of Alex
+        app.selected_index = idx;
+        app.update(Msg::ViewReport).unwrap();
+        app.update(Msg::ViewMeeting(1)).unwrap();
+        let entry_idx = app.selected_entry_index.unwrap();
+        let path = app.entries_by_report[idx][entry_idx].path.clone();
+
+        // Edited in another terminal, and a new note pulled in
+        std::fs::write(&path, "---\nmood: 1\n---\n\nRewritten elsewhere\n").unwrap();
+        let pulled = temp.path().join("alex-chen").join("2026-02-01T090000.md");
+        std::fs::write(&pulled, "---\nmood: 4\n---\n\n# 1-on-1\n").unwrap();
+        app.update(Msg::FilesChanged(vec![path.clone(), pulled]))
+            .unwrap();
+
+        // Same view, same note, fresh content; roster order untouched
+        assert_eq!(app.view_mode, ViewMode::NoteViewer);
+        assert_eq!(app.selected_report_index, Some(idx));
+        let entry_idx = app.selected_entry_index.unwrap();
+        assert_eq!(app.entries_by_report[idx][entry_idx].path, path);
+        assert_eq!(app.editor_content.trim(), "Rewritten elsewhere");
+        assert_eq!(app.editor_mood, Some(1));
+        let after: Vec<String> = app.reports.iter().map(|r| r.slug.clone()).collect();
+        assert_eq!(after, order);
+
+        // Deleted elsewhere: back to the list, still on Alex
+        std::fs::remove_file(&path).unwrap();
+        app.update(Msg::FilesChanged(vec![path])).unwrap();
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.selected_report_index, Some(idx));
+        assert_eq!(app.status_text(), Some("Note was deleted outside the app"));
+    }
+
+    #[test]
+    fn test_files_changed_keeps_dashboard_selection_when_roster_changes() {
+        use vibe_manager::app::{Msg, ViewMode};
+        use vibe_manager::model::ReportProfile;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        app.selected_index = app.reports.len() - 1;
+        let selected = app.reports[app.selected_index].slug.clone();
+        let before = app.reports.len();
+
+        // A report added by another process (e.g. a pull) lands at the top
+        let profile: ReportProfile = serde_yaml::from_str("name: Sam Rivera").unwrap();
+        let sam = app.repo.create_report("Sam Rivera", profile).unwrap();
+        app.update(Msg::FilesChanged(vec![sam.path().to_path_buf()]))
+            .unwrap();
+
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert_eq!(app.reports.len(), before + 1);
+        assert_eq!(app.reports[app.selected_index].slug, selected);
+    }
+
+    #[test]
+    fn test_warnings_modal_lists_hidden_files_and_restores_selection() {
+        use vibe_manager::app::{Msg, ViewMode};
+        use vibe_manager::storage::Problem;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        // The fixtures' team member without a profile is flagged, not lost
+        let no_profile = temp.path().join("chris-wong/team/no-profile");
+        assert!(app
+            .warnings
+            .iter()
+            .any(|i| i.path == no_profile && i.problem == Problem::MissingProfile));
+
+        app.selected_index = 2;
+        app.update(Msg::ShowWarnings).unwrap();
+        assert_eq!(app.view_mode, ViewMode::Warnings);
+        assert_eq!(app.selected_index, 0);
+        app.update(Msg::SelectNext).unwrap();
+        assert_eq!(app.selected_index, 1);
+
+        app.update(Msg::Back).unwrap();
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert_eq!(app.selected_index, 2);
+    }
+
+    #[test]
+    fn test_workspace_sync_conflicts_persist_until_clean_sync() {
+        use vibe_manager::app::{Effect, Msg};
+        use vibe_manager::storage::SyncOutcome;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+
+        assert_eq!(
+            app.update(Msg::SyncWorkspace).unwrap(),
+            Effect::SyncWorkspace
+        );
+        // A second press while the first sync runs doesn't start another
+        assert_eq!(app.update(Msg::SyncWorkspace).unwrap(), Effect::None);
+
+        let conflicts = vec!["alex-chen/_profile.md".to_string()];
+        app.update(Msg::WorkspaceSynced(Ok(SyncOutcome::Conflicts(
+            conflicts.clone(),
+        ))))
+        .unwrap();
+        assert!(!app.syncing);
+        assert_eq!(app.sync_conflicts, conflicts);
+
+        app.update(Msg::WorkspaceSynced(Err("remote unreachable".to_string())))
+            .unwrap();
+        assert_eq!(app.sync_conflicts, conflicts);
+        assert_eq!(app.status_text(), Some("Sync failed: remote unreachable"));
+
+        app.update(Msg::WorkspaceSynced(Ok(SyncOutcome::Synced {
+            committed: false,
+        })))
+        .unwrap();
+        assert!(app.sync_conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_workspace_synced_keeps_the_open_report() {
+        use vibe_manager::app::{Msg, ViewMode};
+        use vibe_manager::model::ReportProfile;
+        use vibe_manager::storage::SyncOutcome;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        app.selected_index = app.reports.len() - 1;
+        app.update(Msg::ViewReport).unwrap();
+        let slug = app.reports[app.selected_report_index.unwrap()].slug.clone();
+
+        // A report pulled from the remote sorts to the top of the roster
+        let profile: ReportProfile = serde_yaml::from_str("name: Sam Rivera").unwrap();
+        app.repo.create_report("Sam Rivera", profile).unwrap();
+        app.update(Msg::WorkspaceSynced(Ok(SyncOutcome::Synced {
+            committed: false,
+        })))
+        .unwrap();
+
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.reports[app.selected_report_index.unwrap()].slug, slug);
+    }
+
+    fn git(dir: &std::path::Path, args: &[&str]) {
+        let output = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?}: {:?}", args, output);
+    }
+
+    #[test]
+    fn test_history_view_shows_past_versions_and_back() {
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        git(temp.path(), &["init", "-q"]);
+        git(temp.path(), &["config", "user.name", "Test"]);
+        git(temp.path(), &["config", "user.email", "test@example.com"]);
+        git(temp.path(), &["config", "commit.gpgsign", "false"]);
+        git(temp.path(), &["add", "-A"]);
+        git(temp.path(), &["commit", "-q", "-m", "Import workspace"]);
+
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let idx = app
+            .reports
+            .iter()
+            .position(|r| r.slug == "alex-chen")
+            .unwrap();
+
+        // Saving through the repository auto-commits
+        let alex = app.repo.report("alex-chen");
+        let mut report = alex.load().unwrap();
+        report.profile.title = Some("Staff Engineer".to_string());
+        alex.save(&report).unwrap();
+
+        app.selected_index = idx;
+        app.update(Msg::ViewReport).unwrap();
+        app.selected_index = 1;
+        app.update(Msg::ShowHistory).unwrap();
+        assert_eq!(app.view_mode, ViewMode::History);
+        let history = app.history.as_ref().unwrap();
+        let summaries: Vec<_> = history
+            .revisions
+            .iter()
+            .map(|r| r.summary.as_str())
+            .collect();
+        assert_eq!(
+            summaries,
+            vec!["Update profile: Alex Chen", "Import workspace"]
+        );
+        assert!(history.content.contains("Staff Engineer"));
+
+        // Selecting an older revision loads its contents
+        app.update(Msg::SelectNext).unwrap();
+        assert!(!app
+            .history
+            .as_ref()
+            .unwrap()
+            .content
+            .contains("Staff Engineer"));
+
+        app.update(Msg::Back).unwrap();
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.selected_index, 1);
+        assert!(app.history.is_none());
+    }
+
+    #[test]
+    fn test_history_outside_git_shows_status() {
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        app.update(Msg::ViewReport).unwrap();
+        app.update(Msg::ShowHistory).unwrap();
+
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(
+            app.status_text(),
+            Some("History needs the workspace to be in a git repository")
+        );
+    }
+
     #[test]
     fn test_meeting_display_to_entry_index() {
         let path = fixtures_path();
@@ -355,7 +845,7 @@ mod tests {
 
     #[test]
     fn test_manager_urgency_includes_squad_bonus() {
-        use vibe_manager::model::{compute_report_summary, manager_urgency_bonus};
+        use vibe_manager::model::{compute_report_summary, manager_urgency_bonus, UrgencyFactor};
 
         let path = fixtures_path();
         let app = vibe_manager::app::App::new(path).expect("Failed to load app");
@@ -372,12 +862,26 @@ mod tests {
         let own_summary = compute_report_summary(
             &app.reports[idx],
             &app.entries_by_report[idx],
-            app.workspace.config.settings.overdue_threshold_days,
+            &app.workspace.config.settings,
         );
         assert_eq!(
             app.summaries[idx].urgency_score,
             own_summary.urgency_score + bonus
         );
+
+        // The bonus shows up in the breakdown, attributed to the worst outlier
+        let (factor, points) = app.summaries[idx]
+            .urgency_breakdown
+            .contributions
+            .last()
+            .expect("breakdown should include the squad bonus");
+        assert_eq!(
+            factor,
+            &UrgencyFactor::SquadOutlier {
+                name: metrics.outliers[0].name.clone()
+            }
+        );
+        assert_eq!(*points, bonus);
     }
 
     #[test]
@@ -688,4 +1192,721 @@ mod tests {
         assert_eq!(metrics.team_size, 0);
         assert!(metrics.outliers.is_empty());
     }
+
+    const SCREEN: ratatui::layout::Rect = ratatui::layout::Rect::new(0, 0, 120, 60);
+
+    /// The first screen cell whose hit test gives `target`
+    fn position_of(
+        app: &vibe_manager::app::App,
+        target: vibe_manager::app::MouseTarget,
+    ) -> ratatui::layout::Position {
+        SCREEN
+            .positions()
+            .find(|p| vibe_manager::views::hit_test(app, SCREEN, *p) == Some(target))
+            .unwrap_or_else(|| panic!("{:?} not on screen", target))
+    }
+
+    /// Send what a mouse event at `position` maps to, the way the runtime does
+    fn mouse(
+        app: &mut vibe_manager::app::App,
+        kind: crossterm::event::MouseEventKind,
+        position: ratatui::layout::Position,
+    ) {
+        let event = crossterm::event::MouseEvent {
+            kind,
+            column: position.x,
+            row: position.y,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        let target = vibe_manager::views::hit_test(app, SCREEN, position);
+        if let Some(msg) = vibe_manager::app::handle_mouse_event(app, event, target) {
+            app.update(msg).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_mouse_selects_and_opens_cards_and_meetings() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use vibe_manager::app::{MouseTarget, ViewMode};
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+        let left = MouseEventKind::Down(MouseButton::Left);
+
+        let alex = app
+            .reports
+            .iter()
+            .position(|e| e.profile.name == "Alex Chen")
+            .expect("Alex Chen not found");
+        app.selected_index = (alex + 1) % app.reports.len();
+
+        // First click selects the card, the second opens it
+        let card = position_of(&app, MouseTarget::Report(alex));
+        mouse(&mut app, left, card);
+        assert_eq!(app.selected_index, alex);
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        mouse(&mut app, left, card);
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.selected_report_index, Some(alex));
+
+        // The wheel walks the meeting list without wrapping
+        assert!(app.selected_meeting_count() >= 2);
+        let anywhere = ratatui::layout::Position::ORIGIN;
+        mouse(&mut app, MouseEventKind::ScrollUp, anywhere);
+        assert_eq!(app.selected_index, 0);
+        mouse(&mut app, MouseEventKind::ScrollDown, anywhere);
+        assert_eq!(app.selected_index, 1);
+
+        let row = position_of(&app, MouseTarget::Meeting(0));
+        mouse(&mut app, left, row);
+        assert_eq!(app.selected_index, 0);
+        mouse(&mut app, left, row);
+        assert_eq!(app.view_mode, ViewMode::NoteViewer);
+    }
+
+    #[test]
+    fn test_mouse_wheel_scrolls_note() {
+        use crossterm::event::MouseEventKind;
+        use vibe_manager::app::Msg;
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+        app.update(Msg::ViewReport).unwrap();
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        app.editor_content = "line\n".repeat(5);
+        let anywhere = ratatui::layout::Position::ORIGIN;
+
+        mouse(&mut app, MouseEventKind::ScrollDown, anywhere);
+        assert_eq!(app.note_scroll, 3);
+        // Stops with the last line at the top
+        mouse(&mut app, MouseEventKind::ScrollDown, anywhere);
+        assert_eq!(app.note_scroll, 4);
+        mouse(&mut app, MouseEventKind::ScrollUp, anywhere);
+        mouse(&mut app, MouseEventKind::ScrollUp, anywhere);
+        assert_eq!(app.note_scroll, 0);
+
+        // A newly opened note starts at the top
+        mouse(&mut app, MouseEventKind::ScrollDown, anywhere);
+        app.update(Msg::Back).unwrap();
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        assert_eq!(app.note_scroll, 0);
+    }
+
+    #[test]
+    fn test_note_keys_scroll_and_search() {
+        use crossterm::event::KeyCode;
+        use ratatui::layout::Size;
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+        // 80x24 leaves 16 lines of note on screen
+        app.update(Msg::Resize(Size::new(80, 24))).unwrap();
+        app.update(Msg::ViewReport).unwrap();
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        app.editor_content = (1..=40)
+            .map(|n| match n {
+                5 | 30 => format!("line {} mentions the Roadmap\n", n),
+                _ => format!("line {}\n", n),
+            })
+            .collect();
+
+        press(&mut app, &[KeyCode::Char('j'), KeyCode::Char('j')]);
+        assert_eq!(app.note_scroll, 2);
+        press(&mut app, &[KeyCode::Char('G')]);
+        assert_eq!(app.note_scroll, 24, "the last page fills the screen");
+        press(&mut app, &[KeyCode::PageUp, KeyCode::Char('k')]);
+        assert_eq!(app.note_scroll, 7);
+        press(&mut app, &[KeyCode::Char('g')]);
+        assert_eq!(app.note_scroll, 0);
+
+        // Typing a search doesn't trigger the keys it spells
+        press(&mut app, &[KeyCode::Char('/')]);
+        press(&mut app, &typed("roadmap"));
+        assert_eq!(app.note_scroll, 0);
+        assert_eq!(app.note_search.as_ref().unwrap().query, "roadmap");
+
+        // Enter jumps to the first match, already on screen
+        press(&mut app, &[KeyCode::Enter]);
+        assert_eq!(app.note_search.as_ref().unwrap().current, Some(4));
+        assert_eq!(app.note_scroll, 0);
+        // The next is off screen, so it's scrolled to with some context
+        press(&mut app, &[KeyCode::Char('n')]);
+        assert_eq!(app.note_search.as_ref().unwrap().current, Some(29));
+        assert_eq!(app.note_scroll, 27);
+        press(&mut app, &[KeyCode::Char('n')]);
+        assert_eq!(app.note_search.as_ref().unwrap().current, Some(4));
+        assert_eq!(app.status_text(), Some("Search wrapped to the top"));
+
+        // Esc clears the search, then leaves the note
+        press(&mut app, &[KeyCode::Esc]);
+        assert!(app.note_search.is_none());
+        assert_eq!(app.view_mode, ViewMode::NoteViewer);
+        press(&mut app, &[KeyCode::Char('/')]);
+        press(&mut app, &typed("nowhere"));
+        press(&mut app, &[KeyCode::Enter]);
+        assert!(app.note_search.is_none());
+        assert_eq!(app.status_text(), Some("Not found: nowhere"));
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+    }
+
+    #[test]
+    fn test_mouse_picks_new_report_options() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use vibe_manager::app::{MouseTarget, Msg};
+        use vibe_manager::components::NewReportField;
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+        app.update(Msg::ShowNewReport).unwrap();
+
+        for target in [
+            MouseTarget::NewReportField(NewReportField::ReportType, Some(1)),
+            MouseTarget::NewReportField(NewReportField::Frequency, Some(0)),
+            MouseTarget::NewReportField(NewReportField::Title, None),
+        ] {
+            let at = position_of(&app, target);
+            mouse(&mut app, MouseEventKind::Down(MouseButton::Left), at);
+        }
+
+        let state = &app.new_report_state;
+        assert!(state.report_type.is_manager());
+        assert_eq!(state.frequency_str(), "weekly");
+        assert_eq!(state.current_field, NewReportField::Title);
+    }
+
+    /// Press each key in turn, as the event loop would
+    fn press(app: &mut vibe_manager::app::App, keys: &[crossterm::event::KeyCode]) {
+        use crossterm::event::{KeyEvent, KeyModifiers};
+        use vibe_manager::app::handle_key_event;
+
+        for &code in keys {
+            if let Some(msg) = handle_key_event(app, KeyEvent::new(code, KeyModifiers::NONE)) {
+                app.update(msg).unwrap();
+            }
+        }
+    }
+
+    fn typed(text: &str) -> Vec<crossterm::event::KeyCode> {
+        text.chars().map(crossterm::event::KeyCode::Char).collect()
+    }
+
+    #[test]
+    fn test_command_palette_runs_actions_and_closes() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::ViewMode;
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+
+        press(&mut app, &[KeyCode::Char(':')]);
+        assert_eq!(app.view_mode, ViewMode::CommandPalette);
+        let palette = app.palette.as_ref().unwrap();
+        assert!(palette
+            .entries
+            .iter()
+            .any(|e| e.label == "Check workspace for problems"));
+
+        // Esc puts the dashboard back untouched
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert!(app.palette.is_none());
+
+        press(&mut app, &[KeyCode::Char(':')]);
+        press(&mut app, &typed("heatmap"));
+        press(&mut app, &[KeyCode::Enter]);
+        assert_eq!(app.view_mode, ViewMode::MoodHeatmap);
+        assert!(app.palette.is_none());
+
+        // Workspace commands are only offered on the dashboard
+        press(&mut app, &[KeyCode::Char(':')]);
+        let palette = app.palette.as_ref().unwrap();
+        assert!(!palette.entries.iter().any(|e| e.label.contains("Migrate")));
+    }
+
+    #[test]
+    fn test_palette_migrate_asks_before_changing_anything() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::ViewMode;
+
+        let temp = setup_temp_workspace();
+        let legacy = temp.path().join("alex-chen/2026-01-08.md");
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+
+        press(&mut app, &[KeyCode::Char(':')]);
+        press(&mut app, &typed("migrate"));
+        press(&mut app, &[KeyCode::Enter]);
+        assert_eq!(app.view_mode, ViewMode::MigrateConfirmModal);
+        assert!(!app.pending_migration.is_empty());
+        assert!(legacy.exists());
+
+        // Backing out leaves the workspace as it was
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert!(app.pending_migration.is_empty());
+        assert!(legacy.exists());
+        assert_eq!(app.workspace.config.version, 1);
+
+        press(&mut app, &[KeyCode::Char(':')]);
+        press(&mut app, &typed("migrate"));
+        press(&mut app, &[KeyCode::Enter, KeyCode::Char('y')]);
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert!(!legacy.exists());
+        assert_eq!(
+            app.workspace.config.version,
+            vibe_manager::model::CURRENT_VERSION
+        );
+        let backups = vibe_manager::storage::migrate::backup_dir(temp.path()).unwrap();
+        std::fs::remove_dir_all(backups).unwrap();
+    }
+
+    #[test]
+    fn test_palette_export_writes_into_the_workspace() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::EXPORT_FILE;
+
+        let temp = setup_temp_workspace();
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+
+        press(&mut app, &[KeyCode::Char(':')]);
+        press(&mut app, &typed("export schedule"));
+        press(&mut app, &[KeyCode::Enter]);
+        let ics = std::fs::read_to_string(temp.path().join(EXPORT_FILE)).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR"));
+        assert!(app.status_text().unwrap().contains("event(s)"));
+    }
+
+    #[test]
+    fn test_command_palette_jumps_into_nested_halls() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+
+        press(&mut app, &[KeyCode::Char(':')]);
+        press(&mut app, &typed("go priya"));
+        let palette = app.palette.as_ref().unwrap();
+        let first = palette.matches()[0];
+        assert_eq!(first.label, "Go to Priya Anand");
+        assert_eq!(first.detail, "Chris Wong ▸ Taylor Brooks");
+        press(&mut app, &[KeyCode::Enter]);
+
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        let idx = app.selected_report_index.unwrap();
+        assert_eq!(app.reports[idx].slug, "priya-anand");
+        let halls: Vec<&str> = app.hall_stack.iter().map(|h| h.slug.as_str()).collect();
+        assert_eq!(halls, ["chris-wong", "taylor-brooks"]);
+
+        // Backing out walks the halls as if they'd been entered by hand
+        app.update(Msg::Back).unwrap();
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        app.update(Msg::ExitHall).unwrap();
+        assert_eq!(app.reports[app.selected_index].slug, "taylor-brooks");
+        app.update(Msg::ExitHall).unwrap();
+        assert!(app.hall_stack.is_empty());
+        assert_eq!(app.reports[app.selected_index].slug, "chris-wong");
+    }
+
+    #[test]
+    fn test_person_finder_matches_title_and_team() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::{PaletteKind, ViewMode};
+
+        let path = fixtures_path();
+        let mut app = vibe_manager::app::App::new(path).expect("Failed to load app");
+
+        press(&mut app, &[KeyCode::Char('/')]);
+        assert_eq!(app.view_mode, ViewMode::CommandPalette);
+        let palette = app.palette.as_ref().unwrap();
+        assert_eq!(palette.kind, PaletteKind::People);
+        assert!(palette
+            .entries
+            .iter()
+            .all(|e| matches!(e.command, vibe_manager::app::Command::JumpTo(_))));
+
+        // Taylor leads the Tooling Pod, so Priya is in it
+        press(&mut app, &typed("tooling"));
+        let labels: Vec<&str> = app
+            .palette
+            .as_ref()
+            .unwrap()
+            .matches()
+            .iter()
+            .map(|e| e.label.as_str())
+            .collect();
+        assert_eq!(labels, ["Priya Anand", "Taylor Brooks"]);
+
+        press(&mut app, &[KeyCode::Enter]);
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        let idx = app.selected_report_index.unwrap();
+        assert_eq!(app.reports[idx].slug, "priya-anand");
+        assert_eq!(app.hall_stack.len(), 2);
+
+        // It opens from the detail view too, and Esc leaves it as it was
+        press(&mut app, &[KeyCode::Char('/'), KeyCode::Esc]);
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.selected_report_index, Some(idx));
+    }
+
+    /// Press `f` until the dashboard shows `filter`
+    fn cycle_filter_to(
+        app: &mut vibe_manager::app::App,
+        filter: vibe_manager::model::RosterFilter,
+    ) {
+        use vibe_manager::app::Msg;
+
+        for _ in 0..app.filter_choices.len() {
+            if app.roster_view.filter == filter {
+                return;
+            }
+            app.update(Msg::CycleFilter).unwrap();
+        }
+        assert_eq!(
+            app.roster_view.filter, filter,
+            "not among the filter choices"
+        );
+    }
+
+    fn roster_names(app: &vibe_manager::app::App) -> Vec<&str> {
+        app.reports
+            .iter()
+            .map(|r| r.profile.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_dashboard_filters_and_sorts_persist() {
+        use vibe_manager::app::{App, Msg};
+        use vibe_manager::model::{RosterFilter, RosterSort};
+
+        let temp = setup_temp_workspace();
+        let mut app = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let everyone = app.reports.len();
+        assert!(app
+            .filter_choices
+            .contains(&RosterFilter::Level("M2".to_string())));
+
+        app.update(Msg::CycleFilter).unwrap();
+        assert_eq!(app.roster_view.filter, RosterFilter::Overdue);
+        assert!(app.summaries.iter().all(|s| s.is_overdue));
+
+        cycle_filter_to(&mut app, RosterFilter::Tag("payments".to_string()));
+        let mut tagged = roster_names(&app);
+        tagged.sort();
+        assert_eq!(tagged, ["Alex Chen", "Jordan Lee"]);
+
+        app.update(Msg::CycleSort).unwrap();
+        assert_eq!(app.roster_view.sort, RosterSort::Name);
+        assert_eq!(roster_names(&app), ["Alex Chen", "Jordan Lee"]);
+
+        // The next session opens the dashboard the way it was left
+        let reopened = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let saved = reopened.repo.load_roster_view().unwrap();
+        let reopened = reopened.with_roster_view(saved).unwrap();
+        assert_eq!(reopened.roster_view, app.roster_view);
+        assert_eq!(roster_names(&reopened), ["Alex Chen", "Jordan Lee"]);
+
+        app.update(Msg::ClearFilter).unwrap();
+        assert_eq!(app.reports.len(), everyone);
+        let names = roster_names(&app);
+        let mut sorted = names.clone();
+        sorted.sort_by_key(|name| name.to_lowercase());
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn test_saved_view_only_applies_when_asked_for() {
+        use vibe_manager::app::App;
+        use vibe_manager::model::{RosterFilter, RosterView};
+
+        let temp = setup_temp_workspace();
+        let app = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let view = RosterView {
+            filter: RosterFilter::Managers,
+            ..RosterView::default()
+        };
+        app.repo.save_roster_view(&view).unwrap();
+
+        // CLI lookups (`explain`, `prep`) see everyone whatever the dashboard shows
+        let app = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        assert!(app.reports.iter().any(|r| r.slug == "alex-chen"));
+
+        let app = app.with_roster_view(view).unwrap();
+        assert!(!app.reports.iter().any(|r| r.slug == "alex-chen"));
+    }
+
+    #[test]
+    fn test_counts_cover_the_roster_a_filter_hides_after_reloads() {
+        use vibe_manager::app::{App, Msg};
+        use vibe_manager::model::RosterFilter;
+
+        let temp = setup_temp_workspace();
+        let mut app = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let everyone = app.reports.len();
+        let choices = app.filter_choices.clone();
+        cycle_filter_to(&mut app, RosterFilter::Managers);
+        assert!(app.reports.len() < everyone);
+        assert_eq!(app.workspace_summary.team_size, everyone);
+
+        // A note changing on disk reloads just that report
+        let note = app.entries_by_report[0][0].path.clone();
+        app.update(Msg::FilesChanged(vec![note])).unwrap();
+        assert_eq!(app.workspace_summary.team_size, everyone);
+        assert_eq!(app.filter_choices, choices);
+
+        // So does deleting one in the app
+        app.delete_entry(0, 0).unwrap();
+        assert_eq!(app.workspace_summary.team_size, everyone);
+        assert_eq!(app.filter_choices, choices);
+    }
+
+    #[test]
+    fn test_jump_clears_a_filter_hiding_the_target() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::{App, ViewMode};
+        use vibe_manager::model::RosterFilter;
+
+        let temp = setup_temp_workspace();
+        let mut app = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        cycle_filter_to(&mut app, RosterFilter::Level("P3".to_string()));
+        assert!(!roster_names(&app).contains(&"Chris Wong"));
+
+        press(&mut app, &[KeyCode::Char('/')]);
+        press(&mut app, &typed("priya"));
+        press(&mut app, &[KeyCode::Enter]);
+
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        let idx = app.selected_report_index.unwrap();
+        assert_eq!(app.reports[idx].slug, "priya-anand");
+        assert_eq!(app.hall_stack.len(), 2);
+        assert_eq!(app.roster_view.filter, RosterFilter::All);
+        assert_eq!(
+            app.status_text(),
+            Some("Filter cleared to show Priya Anand")
+        );
+    }
+
+    #[test]
+    fn test_table_layout_toggles_squads_and_sorts_by_column() {
+        use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+        use vibe_manager::app::{App, MouseTarget};
+        use vibe_manager::model::{RosterLayout, RosterSort};
+
+        let temp = setup_temp_workspace();
+        let mut app = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let chris = app
+            .reports
+            .iter()
+            .position(|r| r.slug == "chris-wong")
+            .expect("Chris Wong not found");
+        app.selected_index = chris;
+
+        // Squad lines only exist in the table
+        press(&mut app, &[KeyCode::Char('x')]);
+        assert!(app.expanded_squads.is_empty());
+
+        press(&mut app, &[KeyCode::Char('t')]);
+        assert_eq!(app.roster_view.layout, RosterLayout::Table);
+        press(&mut app, &[KeyCode::Char('x')]);
+        assert!(app.expanded_squads.contains("chris-wong"));
+
+        // The squad line under a manager clicks through to the manager
+        let rows: Vec<_> = (SCREEN.top()..SCREEN.bottom())
+            .map(|y| ratatui::layout::Position::new(SCREEN.width / 2, y))
+            .filter_map(|p| match vibe_manager::views::hit_test(&app, SCREEN, p) {
+                Some(MouseTarget::Report(idx)) => Some(idx),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(rows.len(), app.reports.len() + 1);
+        assert_eq!(rows.iter().filter(|&&idx| idx == chris).count(), 2);
+
+        // Clicking a heading sorts by it, clicking it again goes back to urgency
+        let left = MouseEventKind::Down(MouseButton::Left);
+        let name = position_of(&app, MouseTarget::Column(RosterSort::Name));
+        mouse(&mut app, left, name);
+        assert_eq!(app.roster_view.sort, RosterSort::Name);
+        let names = roster_names(&app);
+        let mut sorted = names.clone();
+        sorted.sort_by_key(|name| name.to_lowercase());
+        assert_eq!(names, sorted);
+        mouse(&mut app, left, name);
+        assert_eq!(app.roster_view.sort, RosterSort::Urgency);
+
+        // The layout is remembered with the rest of the view
+        let reopened = App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        let saved = reopened.repo.load_roster_view().unwrap();
+        let reopened = reopened.with_roster_view(saved).unwrap();
+        assert_eq!(reopened.roster_view.layout, RosterLayout::Table);
+        press(&mut app, &[KeyCode::Char('t')]);
+        assert_eq!(app.roster_view.layout, RosterLayout::Grid);
+    }
+
+    /// Press a key with Ctrl held
+    fn press_ctrl(app: &mut vibe_manager::app::App, c: char) {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        use vibe_manager::app::handle_key_event;
+
+        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        if let Some(msg) = handle_key_event(app, key) {
+            app.update(msg).unwrap();
+        }
+    }
+
+    fn inline_editing_app(temp: &TempDir) -> vibe_manager::app::App {
+        use vibe_manager::model::EditorKind;
+
+        let mut app =
+            vibe_manager::app::App::new(temp.path().to_path_buf()).expect("Failed to load app");
+        app.workspace.config.settings.editor = EditorKind::Inline;
+        let alex = app
+            .reports
+            .iter()
+            .position(|r| r.slug == "alex-chen")
+            .expect("Alex Chen not found");
+        app.selected_index = alex;
+        app.update(vibe_manager::app::Msg::ViewReport).unwrap();
+        app
+    }
+
+    #[test]
+    fn test_inline_editor_saves_through_the_repository() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app = inline_editing_app(&temp);
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        let before = app.editor_content.clone();
+
+        // Typing doesn't trigger commands: `q` and `:` are just text
+        press(&mut app, &[KeyCode::Char('e')]);
+        assert_eq!(app.view_mode, ViewMode::NoteEditor);
+        press(&mut app, &[KeyCode::Enter]);
+        press(&mut app, &typed("q: ship it"));
+        app.update(Msg::Paste("\n- [ ] follow up".to_string()))
+            .unwrap();
+        assert!(!app.should_quit);
+
+        press_ctrl(&mut app, 's');
+        assert_eq!(app.view_mode, ViewMode::NoteViewer);
+        assert_eq!(app.status_text(), Some("Note updated"));
+        assert_eq!(
+            app.editor_content,
+            format!("{}\nq: ship it\n- [ ] follow up\n", before.trim_end())
+        );
+
+        let report = app.selected_report_index.unwrap();
+        let entry = &app.entries_by_report[report][app.selected_entry_index.unwrap()];
+        let on_disk = std::fs::read_to_string(&entry.path).unwrap();
+        assert!(on_disk.starts_with("---\n"), "frontmatter kept");
+        assert!(on_disk.ends_with("q: ship it\n- [ ] follow up\n"));
+    }
+
+    #[test]
+    fn test_inline_editor_asks_before_discarding() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app = inline_editing_app(&temp);
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        let before = app.editor_content.clone();
+
+        press(&mut app, &[KeyCode::Char('e')]);
+        press(&mut app, &typed("scratch"));
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.view_mode, ViewMode::NoteEditor, "first Esc only warns");
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.view_mode, ViewMode::NoteViewer);
+        assert_eq!(app.status_text(), Some("Changes discarded"));
+        assert_eq!(app.editor_content, before);
+    }
+
+    #[test]
+    fn test_inline_editor_drops_a_new_note_left_empty() {
+        use vibe_manager::app::{Effect, Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app = inline_editing_app(&temp);
+        let report = app.selected_report_index.unwrap();
+        let count = app.entries_by_report[report].len();
+
+        // No `$EDITOR` to spawn: the editor opens in the TUI
+        assert!(matches!(app.update(Msg::NewMeeting).unwrap(), Effect::None));
+        assert_eq!(app.view_mode, ViewMode::NoteEditor);
+        assert_eq!(app.entries_by_report[report].len(), count + 1);
+
+        press_ctrl(&mut app, 's');
+        assert_eq!(app.view_mode, ViewMode::ReportDetail);
+        assert_eq!(app.status_text(), Some("Entry creation canceled"));
+        assert_eq!(app.entries_by_report[report].len(), count);
+    }
+
+    #[test]
+    fn test_inline_editor_keeps_a_note_deleted_outside_the_app() {
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app = inline_editing_app(&temp);
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        let report = app.selected_report_index.unwrap();
+        let path = app.entries_by_report[report][app.selected_entry_index.unwrap()]
+            .path
+            .clone();
+
+        press(&mut app, &[crossterm::event::KeyCode::Char('e')]);
+        press(&mut app, &typed("keep me"));
+        std::fs::remove_file(&path).unwrap();
+        app.update(Msg::FilesChanged(vec![path.clone()])).unwrap();
+        assert_eq!(app.view_mode, ViewMode::NoteEditor);
+        assert_eq!(
+            app.status_text(),
+            Some("Note was deleted outside the app; Ctrl+S writes it back")
+        );
+
+        press_ctrl(&mut app, 's');
+        assert_eq!(app.view_mode, ViewMode::NoteViewer);
+        assert_eq!(app.status_text(), Some("Note written back"));
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .ends_with("keep me\n"));
+        let entry = &app.entries_by_report[report][app.selected_entry_index.unwrap()];
+        assert_eq!(entry.path, path);
+    }
+
+    #[test]
+    fn test_inline_editor_keeps_the_text_when_the_report_is_removed() {
+        use crossterm::event::KeyCode;
+        use vibe_manager::app::{Msg, ViewMode};
+
+        let temp = setup_temp_workspace();
+        let mut app = inline_editing_app(&temp);
+        app.update(Msg::ViewMeeting(0)).unwrap();
+        press(&mut app, &[KeyCode::Char('e')]);
+        press(&mut app, &typed("keep me"));
+
+        let dir = temp.path().join("alex-chen");
+        std::fs::remove_dir_all(&dir).unwrap();
+        app.update(Msg::FilesChanged(vec![dir])).unwrap();
+        assert_eq!(app.view_mode, ViewMode::NoteEditor);
+        assert!(app.note_editor.as_ref().unwrap().text().contains("keep me"));
+
+        // Nowhere to save it, so the editor stays open
+        press_ctrl(&mut app, 's');
+        assert_eq!(app.view_mode, ViewMode::NoteEditor);
+        assert_eq!(
+            app.status_text(),
+            Some("Report was removed outside the app; this note can't be saved")
+        );
+        assert!(!temp.path().join("alex-chen").exists());
+
+        press(&mut app, &[KeyCode::Esc, KeyCode::Esc]);
+        assert_eq!(app.view_mode, ViewMode::Dashboard);
+        assert!(app.note_editor.is_none());
+    }
 }
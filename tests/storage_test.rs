@@ -39,6 +39,19 @@ mod workspace_tests {
         assert_eq!(workspace.config.settings.overdue_threshold_days, 3);
     }
 
+    #[test]
+    fn test_list_all_reports_walks_nested_teams() {
+        let repo = fixtures_repo();
+        let all = repo.list_all_reports().unwrap();
+
+        // 5 direct reports + 7 in chris-wong's team + 1 under taylor-brooks
+        assert_eq!(all.len(), 13);
+        let slugs: Vec<_> = all.iter().map(|r| r.slug()).collect();
+        assert!(slugs.contains(&"devon-okafor"));
+        assert!(slugs.contains(&"priya-anand"));
+        assert!(!slugs.contains(&".hidden"));
+    }
+
     #[test]
     fn test_list_report_dirs() {
         let repo = fixtures_repo();
@@ -433,3 +446,461 @@ mod manager_tests {
         assert!(team_slugs.contains(&"taylor-brooks"));
     }
 }
+
+#[cfg(test)]
+mod git_tests {
+    use std::path::Path;
+    use std::process::Command;
+
+    use tempfile::TempDir;
+    use vibe_manager::model::ReportProfile;
+    use vibe_manager::storage::WorkspaceRepository;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?}: {:?}", args, output);
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    }
+
+    fn git_workspace() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        git(temp.path(), &["init", "-q"]);
+        git(temp.path(), &["config", "user.name", "Test"]);
+        git(temp.path(), &["config", "user.email", "test@example.com"]);
+        git(temp.path(), &["config", "commit.gpgsign", "false"]);
+        WorkspaceRepository::init(temp.path()).unwrap();
+        temp
+    }
+
+    fn profile(name: &str) -> ReportProfile {
+        serde_yaml::from_str(&format!("name: {}", name)).unwrap()
+    }
+
+    #[test]
+    fn test_saves_auto_commit_inside_git_repo() {
+        let temp = git_workspace();
+        let repo = WorkspaceRepository::open(temp.path()).unwrap();
+
+        let alex = repo
+            .create_report("Alex Chen", profile("Alex Chen"))
+            .unwrap();
+        let entry = alex.entries().create_meeting(None).unwrap();
+        alex.entries().delete(&entry).unwrap();
+
+        let log = git(temp.path(), &["log", "--format=%s"]);
+        let file = entry.path.file_name().unwrap().to_string_lossy();
+        assert_eq!(
+            log.lines().collect::<Vec<_>>(),
+            vec![
+                format!("Delete alex-chen/{}", file),
+                format!("Save alex-chen/{}", file),
+                "Update profile: Alex Chen".to_string(),
+            ]
+        );
+        // Only saved files are committed; the workspace config stays untracked
+        assert!(git(temp.path(), &["status", "--porcelain"]).contains(".vibe-manager"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_failed_auto_commit_is_reported_not_fatal() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = git_workspace();
+        // A hook that rejects every commit
+        let hook = temp.path().join(".git/hooks/pre-commit");
+        std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+        std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let repo = WorkspaceRepository::open(temp.path()).unwrap();
+        assert_eq!(repo.take_auto_commit_failure(), None);
+
+        let alex = repo
+            .create_report("Alex Chen", profile("Alex Chen"))
+            .unwrap();
+        assert!(alex.path().join("_profile.md").exists());
+        assert!(repo.take_auto_commit_failure().is_some());
+        assert_eq!(repo.take_auto_commit_failure(), None);
+    }
+
+    #[test]
+    fn test_auto_commit_can_be_turned_off() {
+        let temp = git_workspace();
+        std::fs::write(
+            temp.path().join(".vibe-manager"),
+            "settings:\n  git:\n    auto_commit: false\n",
+        )
+        .unwrap();
+        let repo = WorkspaceRepository::open(temp.path()).unwrap();
+        repo.create_report("Alex Chen", profile("Alex Chen"))
+            .unwrap();
+
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(temp.path())
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "expected no commits");
+    }
+
+    #[test]
+    fn test_sync_outside_git_repo_fails() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        assert!(repo.sync(&Default::default()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod encryption_tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+    use vibe_manager::model::ReportProfile;
+    use vibe_manager::storage::{StorageError, WorkspaceRepository};
+
+    #[test]
+    fn test_encrypt_unlock_and_decrypt_workspace() {
+        let temp = TempDir::new().unwrap();
+        let mut repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let profile: ReportProfile = serde_yaml::from_str("name: Alex Chen").unwrap();
+        let alex = repo.create_report("Alex Chen", profile).unwrap();
+        let entry = alex
+            .entries()
+            .create_observation(Some(2), None, "Worried about the reorg".to_string())
+            .unwrap();
+
+        assert_eq!(repo.encrypt("hunter2").unwrap(), 2);
+        assert!(!fs::read_to_string(&entry.path)
+            .unwrap_or_default()
+            .contains("reorg"));
+        assert!(
+            !String::from_utf8_lossy(&fs::read(alex.path().join("_profile.md")).unwrap())
+                .contains("Alex Chen")
+        );
+
+        // Reopened: locked until the passphrase is given
+        let mut reopened = WorkspaceRepository::open(temp.path()).unwrap();
+        assert!(reopened.is_locked());
+        assert!(matches!(
+            reopened.report("alex-chen").load(),
+            Err(StorageError::Locked(_))
+        ));
+        assert!(reopened.unlock("wrong").is_err());
+        reopened.unlock("hunter2").unwrap();
+        let alex = reopened.report("alex-chen");
+        assert_eq!(alex.load().unwrap().profile.name, "Alex Chen");
+        let entries = alex.entries().list().unwrap();
+        assert_eq!(entries[0].content, "Worried about the reorg");
+
+        // New saves are encrypted too
+        let note = alex
+            .entries()
+            .create_meeting(chrono::NaiveDate::from_ymd_opt(2026, 1, 5))
+            .unwrap();
+        assert!(!fs::read_to_string(&note.path)
+            .unwrap_or_default()
+            .contains("1-on-1"));
+
+        assert_eq!(reopened.decrypt().unwrap(), 3);
+        assert!(!reopened.is_encrypted());
+        assert!(fs::read_to_string(&entry.path).unwrap().contains("reorg"));
+        assert!(!WorkspaceRepository::open(temp.path())
+            .unwrap()
+            .is_encrypted());
+    }
+}
+
+mod recovery_tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+    use vibe_manager::model::ReportProfile;
+    use vibe_manager::storage::{atomic, WorkspaceRepository};
+
+    #[test]
+    fn test_interrupted_profile_save_is_detected_and_restored() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let profile: ReportProfile = serde_yaml::from_str("name: Alex Chen").unwrap();
+        let alex = repo.create_report("Alex Chen", profile).unwrap();
+        let path = alex.path().join("_profile.md");
+        assert!(repo.load().unwrap().interrupted_writes.is_empty());
+
+        // A save that crashed after writing its temp file
+        let newer = fs::read_to_string(&path)
+            .unwrap()
+            .replace("Alex Chen", "Alex Chen-Park");
+        fs::write(atomic::temp_path(&path), newer).unwrap();
+
+        let workspace = repo.load().unwrap();
+        assert_eq!(workspace.interrupted_writes, vec![atomic::temp_path(&path)]);
+        repo.restore_interrupted_write(&workspace.interrupted_writes[0])
+            .unwrap();
+        assert_eq!(alex.load().unwrap().profile.name, "Alex Chen-Park");
+        assert!(repo.load().unwrap().interrupted_writes.is_empty());
+    }
+
+    #[test]
+    fn test_broken_interrupted_save_is_refused() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let profile: ReportProfile = serde_yaml::from_str("name: Alex Chen").unwrap();
+        let alex = repo.create_report("Alex Chen", profile).unwrap();
+        let path = alex.path().join("_profile.md");
+        let temp_file = atomic::temp_path(&path);
+        fs::write(&temp_file, "---\nname: [Alex\n---\n").unwrap();
+
+        assert!(repo.restore_interrupted_write(&temp_file).is_err());
+        assert_eq!(alex.load().unwrap().profile.name, "Alex Chen");
+
+        repo.discard_interrupted_write(&temp_file).unwrap();
+        assert!(!temp_file.exists());
+        assert!(repo.load().unwrap().interrupted_writes.is_empty());
+    }
+}
+
+mod doctor_tests {
+    use std::fs;
+    use std::path::Path;
+
+    use tempfile::TempDir;
+    use vibe_manager::model::ReportProfile;
+    use vibe_manager::storage::doctor::{diagnose, repair};
+    use vibe_manager::storage::{Problem, Severity, WorkspaceRepository};
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    /// Workspace with one of each kind of problem
+    fn broken_workspace() -> (TempDir, WorkspaceRepository) {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let profile: ReportProfile =
+            serde_yaml::from_str("name: Alex Chen\nlevel: p3\nmeeting_frequency: fortnightly")
+                .unwrap();
+        let alex = repo.create_report("Alex Chen", profile).unwrap();
+        let root = alex.path();
+        write(&root.join("2026-01-05.md"), "---\nmood: 9\n---\n\nLegacy\n");
+        write(&root.join("journal/2026-01-05.md"), "---\nmood: 3\n---\n");
+        write(
+            &root.join("journal/2026-01-06T100000.md"),
+            "---\nmood: [\n---\n",
+        );
+        write(&root.join("journal/notes.md"), "scratch");
+        write(&root.join("team/sam/2026-01-07.md"), "---\nmood: 4\n---\n");
+
+        write(
+            &temp.path().join("broken/_profile.md"),
+            "---\nname: [Jo\n---\n",
+        );
+        write(&temp.path().join("docs/readme.txt"), "not a report");
+        (temp, repo)
+    }
+
+    #[test]
+    fn test_diagnose_reports_every_problem() {
+        let (temp, repo) = broken_workspace();
+        let issues = diagnose(&repo).unwrap();
+        let at = |rel: &str| {
+            issues
+                .iter()
+                .filter(|i| i.path == temp.path().join(rel))
+                .map(|i| i.problem.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let profile = at("alex-chen/_profile.md");
+        assert!(profile.contains(&Problem::NonCanonicalLevel("p3".to_string())));
+        assert!(profile.contains(&Problem::UnknownFrequency("fortnightly".to_string())));
+        assert_eq!(
+            at("alex-chen/2026-01-05.md"),
+            vec![Problem::LegacyEntry, Problem::MoodOutOfRange(9)]
+        );
+        assert!(matches!(
+            at("alex-chen/journal/2026-01-05.md")[..],
+            [Problem::DuplicateTimestamp(_)]
+        ));
+        assert!(matches!(
+            at("alex-chen/journal/2026-01-06T100000.md")[..],
+            [Problem::UnreadableEntry(_)]
+        ));
+        assert_eq!(
+            at("alex-chen/journal/notes.md"),
+            vec![Problem::UnrecognizedFile]
+        );
+        assert_eq!(at("alex-chen/team"), vec![Problem::OrphanedTeamDir(1)]);
+        assert_eq!(at("alex-chen/team/sam"), vec![Problem::MissingProfile]);
+        assert!(matches!(
+            at("broken/_profile.md")[..],
+            [Problem::UnreadableProfile(_)]
+        ));
+        assert!(at("docs").is_empty());
+        assert_eq!(at("broken/_profile.md")[0].severity(), Severity::Error);
+        assert_eq!(Problem::LegacyEntry.severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn test_repair_fixes_what_it_can() {
+        let (temp, repo) = broken_workspace();
+        let mut fixable: Vec<_> = diagnose(&repo)
+            .unwrap()
+            .into_iter()
+            .filter(|i| i.problem.is_fixable())
+            .collect();
+        fixable.sort_by_key(|i| i.problem == Problem::LegacyEntry);
+        for issue in &fixable {
+            // The legacy note collides with one already in journal/
+            let result = repair(&repo, issue);
+            assert_eq!(result.is_ok(), issue.problem != Problem::LegacyEntry);
+        }
+
+        let report = repo.report("alex-chen").load().unwrap();
+        assert_eq!(report.profile.level.as_deref(), Some("P3"));
+        assert_eq!(report.profile.meeting_frequency, "biweekly");
+        // Whether Alex manages the team/ found beside them is a guess; left alone
+        assert!(!report.is_manager());
+        assert!(temp.path().join("alex-chen/team/sam").is_dir());
+        let legacy = fs::read_to_string(temp.path().join("alex-chen/2026-01-05.md")).unwrap();
+        assert!(!legacy.contains("mood: 9"));
+
+        let remaining: Vec<_> = diagnose(&repo)
+            .unwrap()
+            .into_iter()
+            .filter(|i| i.problem.is_fixable())
+            .map(|i| i.problem)
+            .collect();
+        assert_eq!(remaining, vec![Problem::LegacyEntry]);
+    }
+
+    #[test]
+    fn test_repair_only_removes_empty_orphaned_team_dirs() {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        let profile: ReportProfile = serde_yaml::from_str("name: Alex Chen").unwrap();
+        let alex = repo.create_report("Alex Chen", profile).unwrap();
+        fs::create_dir_all(alex.path().join("team")).unwrap();
+
+        let issues = diagnose(&repo).unwrap();
+        assert_eq!(issues[0].problem, Problem::OrphanedTeamDir(0));
+        assert!(issues[0].problem.is_fixable());
+        assert!(!Problem::OrphanedTeamDir(2).is_fixable());
+        assert!(repair(&repo, &issues[0]).unwrap());
+        assert!(!alex.path().join("team").exists());
+        assert!(!repo.report("alex-chen").load().unwrap().is_manager());
+    }
+}
+
+mod migration_tests {
+    use std::fs;
+    use std::path::Path;
+
+    use tempfile::TempDir;
+    use vibe_manager::model::{ReportProfile, CURRENT_VERSION};
+    use vibe_manager::storage::migrate::{self, Change};
+    use vibe_manager::storage::WorkspaceRepository;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    /// Version 1 workspace: notes beside the profile, date-only names, `cadence`
+    fn legacy_workspace() -> (TempDir, WorkspaceRepository) {
+        let temp = TempDir::new().unwrap();
+        let repo = WorkspaceRepository::init(temp.path()).unwrap();
+        write(
+            &temp.path().join(".vibe-manager"),
+            "version: 1\nsettings:\n  default_cadence: weekly\n",
+        );
+        let profile: ReportProfile = serde_yaml::from_str("name: Alex Chen").unwrap();
+        let alex = repo.create_report("Alex Chen", profile).unwrap();
+        let root = alex.path();
+        write(
+            &root.join("_profile.md"),
+            "---\nname: Alex Chen\ncadence: monthly\n---\n",
+        );
+        write(&root.join("2026-01-05.md"), "---\nmood: 4\n---\n\nLegacy\n");
+        write(&root.join("journal/2026-01-06.md"), "---\nmood: 3\n---\n");
+        write(&root.join("journal/2026-01-07T100000.md"), "---\n---\n");
+        (temp, repo)
+    }
+
+    #[test]
+    fn test_dry_run_changes_nothing() {
+        let (temp, repo) = legacy_workspace();
+        let steps = migrate::pending(&repo).unwrap();
+
+        assert_eq!(steps.len(), 1);
+        assert!(steps[0].conflicts.is_empty());
+        assert!(steps[0].changes.contains(&Change::RenameDefaultCadence));
+        assert_eq!(steps[0].changes.len(), 4);
+        assert!(temp.path().join("alex-chen/2026-01-05.md").exists());
+        assert_eq!(repo.load().unwrap().config.version, 1);
+    }
+
+    #[test]
+    fn test_migrate_moves_notes_and_renames_keys() {
+        let (temp, repo) = legacy_workspace();
+        let report = migrate::migrate(&repo, true).unwrap();
+
+        let journal = temp.path().join("alex-chen/journal");
+        assert!(journal.join("2026-01-05T000000.md").exists());
+        assert!(journal.join("2026-01-06T000000.md").exists());
+        assert!(journal.join("2026-01-07T100000.md").exists());
+        assert!(!temp.path().join("alex-chen/2026-01-05.md").exists());
+
+        let profile = fs::read_to_string(temp.path().join("alex-chen/_profile.md")).unwrap();
+        assert!(profile.contains("meeting_frequency: monthly"));
+        let workspace = repo.load().unwrap();
+        assert_eq!(workspace.config.version, CURRENT_VERSION);
+        assert_eq!(
+            workspace.config.settings.default_meeting_frequency,
+            "weekly"
+        );
+
+        // The backup sits beside the workspace, out of reach of `sync`
+        let backup = report.backup.unwrap();
+        let backups = migrate::backup_dir(temp.path()).unwrap();
+        assert!(backup.starts_with(&backups));
+        assert!(!backups.starts_with(temp.path()));
+        assert!(backup.join("alex-chen/2026-01-05.md").exists());
+        assert_eq!(
+            migrate::existing_backups(temp.path()),
+            vec![backups.clone()]
+        );
+        assert!(migrate::pending(&repo).unwrap().is_empty());
+        assert!(migrate::migrate(&repo, true).unwrap().backup.is_none());
+        fs::remove_dir_all(backups).unwrap();
+    }
+
+    #[test]
+    fn test_existing_backups_include_ones_inside_the_workspace() {
+        let (temp, _repo) = legacy_workspace();
+        assert!(migrate::existing_backups(temp.path()).is_empty());
+
+        let legacy = temp.path().join(migrate::BACKUP_DIR);
+        fs::create_dir_all(legacy.join("v1-20260101T000000")).unwrap();
+        assert_eq!(migrate::existing_backups(temp.path()), [legacy]);
+    }
+
+    #[test]
+    fn test_conflicting_notes_stop_the_migration() {
+        let (temp, repo) = legacy_workspace();
+        write(
+            &temp.path().join("alex-chen/2026-01-07T100000.md"),
+            "---\n---\n",
+        );
+
+        assert!(migrate::migrate(&repo, false).is_err());
+        assert_eq!(repo.load().unwrap().config.version, 1);
+        assert!(temp.path().join("alex-chen/2026-01-05.md").exists());
+    }
+}
@@ -4,14 +4,16 @@
 //! snapshots. All summaries are constructed literals with fixed values —
 //! never loaded from fixtures, whose dates drift against `Local::now()`.
 
+use ratatui::layout::Rect;
 use ratatui::style::Color;
 use ratatui::{backend::TestBackend, Terminal};
 
 use vibe_manager::components::{
-    AvatarGrid, Dashboard, DoorwayCard, HallHeader, DOORWAY_CARD_HEIGHT,
+    AvatarGrid, Dashboard, DoorwayCard, HallHeader, RosterTable, DOORWAY_CARD_HEIGHT,
 };
 use vibe_manager::model::{
-    MoodTrend, OutlierInfo, ReportSummary, ReportType, TeamMetrics, WorkspaceSummary,
+    MoodScoring, MoodTrend, OutlierInfo, ReportSummary, ReportType, RosterSort, TeamMetrics,
+    UrgencyBreakdown, WorkspaceSummary,
 };
 
 /// A manager summary with a troubled squad (named worst outlier + overflow)
@@ -21,13 +23,17 @@ fn manager_with_outliers() -> ReportSummary {
         title: Some("Eng Manager".to_string()),
         level: "M2".to_string(),
         meeting_frequency: "weekly".to_string(),
+        frequency_days: 7,
         active: true,
         days_since_meeting: Some(3),
         is_overdue: false,
         mood_trend: Some(MoodTrend::Stable),
         recent_mood: Some(4),
+        mood_baseline: None,
+        mood_deviation: None,
         color: Color::White,
         urgency_score: 25,
+        urgency_breakdown: UrgencyBreakdown::default(),
         report_type: ReportType::Manager,
         team_metrics: Some(TeamMetrics {
             team_size: 4,
@@ -41,6 +47,7 @@ fn manager_with_outliers() -> ReportSummary {
                     urgency_score: 50,
                     mood_trend: Some(MoodTrend::Falling),
                     recent_mood: Some(2),
+                    low_mood: true,
                     is_overdue: true,
                     days_since_meeting: Some(42),
                 },
@@ -49,6 +56,7 @@ fn manager_with_outliers() -> ReportSummary {
                     urgency_score: 30,
                     mood_trend: None,
                     recent_mood: Some(2),
+                    low_mood: true,
                     is_overdue: false,
                     days_since_meeting: Some(10),
                 },
@@ -57,6 +65,7 @@ fn manager_with_outliers() -> ReportSummary {
                     urgency_score: 20,
                     mood_trend: None,
                     recent_mood: None,
+                    low_mood: false,
                     is_overdue: true,
                     days_since_meeting: Some(35),
                 },
@@ -165,13 +174,17 @@ fn ic_summary(name: &str, level: &str) -> ReportSummary {
         title: None,
         level: level.to_string(),
         meeting_frequency: "biweekly".to_string(),
+        frequency_days: 14,
         active: true,
         days_since_meeting: Some(5),
         is_overdue: false,
         mood_trend: Some(MoodTrend::Stable),
         recent_mood: Some(4),
+        mood_baseline: None,
+        mood_deviation: None,
         color: Color::White,
         urgency_score: 0,
+        urgency_breakdown: UrgencyBreakdown::default(),
         report_type: ReportType::Individual,
         team_metrics: None,
     }
@@ -199,6 +212,43 @@ fn dashboard_grid_mixes_doorway_and_ic_rows() {
     insta::assert_snapshot!(terminal.backend());
 }
 
+/// Table layout: one line per report, an open squad line under a manager,
+/// and an arrow on the sorted column
+#[test]
+fn dashboard_table_with_squad_line() {
+    let mut late = ic_summary("Alex Chen", "P3");
+    late.days_since_meeting = Some(20);
+    late.is_overdue = true;
+    let mut never = ic_summary("Jonas", "P1");
+    never.days_since_meeting = None;
+    never.recent_mood = None;
+    never.mood_trend = None;
+    let summaries = vec![
+        never,
+        manager_with_outliers(),
+        late,
+        ic_summary("Sam Reyes", "P2"),
+        manager_no_team(),
+    ];
+    let expanded = [false, true, false, false, true];
+
+    let backend = TestBackend::new(80, 8);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            RosterTable::new(
+                &summaries,
+                2,
+                &expanded,
+                RosterSort::NextDue,
+                MoodScoring::default(),
+            )
+            .render(frame, frame.area());
+        })
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
 /// HALL-02: hall header shows breadcrumb, member count, and team health bar
 #[test]
 fn dashboard_hall_header_breadcrumb() {
@@ -232,6 +282,32 @@ fn dashboard_hall_header_breadcrumb() {
     insta::assert_snapshot!(terminal.backend());
 }
 
+/// A filter hiding everyone keeps the dashboard, says so and names the view
+#[test]
+fn dashboard_filter_matches_nobody() {
+    let workspace_summary = WorkspaceSummary {
+        team_size: 3,
+        active_count: 3,
+        overdue_count: 0,
+        average_mood: Some(4.0),
+        total_report_count: 3,
+    };
+
+    let backend = TestBackend::new(70, 22);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            Dashboard::new(&[], &workspace_summary, 0)
+                .with_view(
+                    "Filter: overdue • Sort: name".to_string(),
+                    "Nobody here matches: overdue (F to show everyone)".to_string(),
+                )
+                .render(frame, frame.area());
+        })
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
 /// DOOR-03: selected and unselected renders are both 5 rows and differ only
 /// in the door-hint line (content row index 3)
 #[test]
@@ -291,3 +367,469 @@ fn doorway_card_height_invariant() {
         }
     }
 }
+
+/// Mood analytics over a fixed window: time-axis chart, stats, contexts,
+/// and weekly/monthly averages
+#[test]
+fn mood_analytics_panel_quarter() {
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+    use vibe_manager::components::MoodAnalyticsPanel;
+    use vibe_manager::model::{
+        compute_mood_analytics, Context, JournalEntry, JournalEntryFrontmatter, MoodWindow,
+    };
+
+    let entry = |m: u32, d: u32, mood: u8, context: Context| {
+        JournalEntry::new(
+            NaiveDate::from_ymd_opt(2026, m, d)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap(),
+            PathBuf::new(),
+            JournalEntryFrontmatter {
+                mood: Some(mood),
+                context: Some(context),
+            },
+            String::new(),
+        )
+    };
+    let entries = vec![
+        entry(1, 12, 4, Context::Meeting),
+        entry(1, 26, 4, Context::Meeting),
+        entry(2, 3, 3, Context::Standup),
+        entry(2, 16, 2, Context::Meeting),
+        entry(3, 2, 3, Context::Slack),
+        entry(3, 25, 5, Context::Meeting),
+    ];
+    let today = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+    let analytics = compute_mood_analytics(&entries, MoodWindow::Quarter, today);
+
+    let backend = TestBackend::new(100, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            MoodAnalyticsPanel::new("Alex Chen", &analytics).render(frame, frame.area());
+        })
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
+/// Meeting prep for a manager: the last two notes beside open action items,
+/// mood, what was logged since, dates coming up, goals and the squad
+#[test]
+fn meeting_prep_panel_for_a_manager() {
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+    use vibe_manager::components::MeetingPrepPanel;
+    use vibe_manager::model::{
+        compute_meeting_prep, compute_report_summary, Context, JournalEntry,
+        JournalEntryFrontmatter, Report, ReportProfile, WorkspaceSettings,
+    };
+
+    let entry = |d: u32, mood: u8, context: Option<Context>, content: &str| {
+        JournalEntry::new(
+            NaiveDate::from_ymd_opt(2026, 3, d)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap(),
+            PathBuf::new(),
+            JournalEntryFrontmatter {
+                mood: Some(mood),
+                context,
+            },
+            content.to_string(),
+        )
+    };
+    let entries = vec![
+        entry(
+            2,
+            4,
+            None,
+            "## Topics\n- Payments migration scope\n\n## Action Items\n- [ ] Share the RFC\n- [x] Book the offsite",
+        ),
+        entry(
+            16,
+            2,
+            None,
+            "Tired after the incident week.\n\n- [ ] Look at on-call load",
+        ),
+        entry(18, 3, Some(Context::Slack), "Asked about the rotation"),
+    ];
+    let profile: ReportProfile = serde_yaml::from_str(
+        "name: Chris Wong\ntitle: Engineering Manager\nreport_type: manager\nbirthday: 1990-03-28\nstart_date: 2022-04-10\n",
+    )
+    .unwrap();
+    let report = Report::new(
+        "chris-wong".to_string(),
+        PathBuf::new(),
+        profile,
+        "## Goals\n- [ ] Grow a tech lead\n- [x] Hire two engineers\n".to_string(),
+    );
+    let mut summary = compute_report_summary(&report, &entries, &WorkspaceSettings::default());
+    summary.team_metrics = manager_with_outliers().team_metrics;
+    let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+    let prep = compute_meeting_prep(&report, &entries, &summary, today);
+
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            MeetingPrepPanel::new(&prep).render(frame, frame.area());
+        })
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
+/// A note's markdown rendered: nested and task lists, a quote, code and a
+/// table, with a search's matches marked and its count in the title
+#[test]
+fn note_viewer_renders_markdown_with_search() {
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+    use vibe_manager::app::NoteSearch;
+    use vibe_manager::components::NoteViewer;
+    use vibe_manager::model::{Context, JournalEntry, JournalEntryFrontmatter};
+
+    let content = "\
+## Career
+- Wants to lead the **payments** migration once the roadmap settles down for
+  the quarter, and to pair with the platform team before then
+  - [x] intro to the staff engineer
+  - [ ] draft a growth plan
+
+> Said the on-call load is *wearing thin*
+
+```sh
+make deploy
+```
+
+| Goal | Status |
+|------|-------:|
+| Roadmap review | 80% |
+| Mentoring | done |
+";
+    let entry = JournalEntry::new(
+        NaiveDate::from_ymd_opt(2026, 3, 2)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        PathBuf::new(),
+        JournalEntryFrontmatter {
+            mood: Some(4),
+            context: Some(Context::Meeting),
+        },
+        content.to_string(),
+    );
+    let search = NoteSearch {
+        query: "roadmap".to_string(),
+        typing: false,
+        current: Some(2),
+    };
+
+    let backend = TestBackend::new(80, 26);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            NoteViewer::new(&entry, content, Some(4))
+                .with_search(Some(&search))
+                .render(frame, frame.area());
+        })
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
+/// Reports × weeks heatmap with a team row; a shared dip shows as a column
+#[test]
+fn mood_heatmap_grid_team_dip() {
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+    use vibe_manager::components::HeatmapGrid;
+    use vibe_manager::model::{compute_mood_heatmap, JournalEntry, JournalEntryFrontmatter};
+
+    let entry = |m: u32, d: u32, mood: u8| {
+        JournalEntry::new(
+            NaiveDate::from_ymd_opt(2026, m, d)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap(),
+            PathBuf::new(),
+            JournalEntryFrontmatter {
+                mood: Some(mood),
+                context: None,
+            },
+            String::new(),
+        )
+    };
+    let alex = vec![entry(2, 2, 4), entry(2, 16, 2), entry(3, 2, 4)];
+    let sam = vec![entry(2, 3, 5), entry(2, 17, 1), entry(2, 24, 3)];
+    let kim = vec![entry(2, 18, 2)];
+    let today = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+    let heatmap = compute_mood_heatmap(
+        [
+            ("Alex Chen", alex.as_slice()),
+            ("Sam Taylor", sam.as_slice()),
+            ("Kim Diaz", kim.as_slice()),
+        ],
+        6,
+        today,
+    );
+
+    let backend = TestBackend::new(60, 11);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            HeatmapGrid::new(&heatmap, "Team Mood · last 6 weeks").render(frame, frame.area());
+        })
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
+/// Help lists the active keymap, rebound keys included
+#[test]
+fn help_modal_rebound_keys() {
+    use vibe_manager::app::Keymap;
+    use vibe_manager::components::HelpModal;
+
+    let keymap = Keymap::from_yaml("dashboard:\n  sync: ctrl+s\n  warnings: []\n").unwrap();
+
+    let backend = TestBackend::new(100, 40);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| HelpModal::render(frame, frame.area(), &keymap))
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
+/// The palette lists matches best first, keys and halls on the right
+#[test]
+fn command_palette_filtered() {
+    use vibe_manager::app::keymap::Action;
+    use vibe_manager::app::{Command, CommandPaletteState, PaletteEntry, PaletteKind, ViewMode};
+    use vibe_manager::components::CommandPaletteModal;
+
+    let entry = |label: &str, detail: &str, command: Command| PaletteEntry {
+        label: label.to_string(),
+        detail: detail.to_string(),
+        keywords: String::new(),
+        command,
+    };
+    let state = CommandPaletteState {
+        kind: PaletteKind::Commands,
+        query: "sy".to_string(),
+        entries: vec![
+            entry("Git sync (pull & push)", "s", Command::Action(Action::Sync)),
+            entry(
+                "Team mood heatmap",
+                "m",
+                Command::Action(Action::MoodHeatmap),
+            ),
+            entry(
+                "Go to Sam Young",
+                "Chris Wong ▸ Taylor Brooks",
+                Command::Doctor,
+            ),
+        ],
+        selected: 1,
+        return_to: ViewMode::Dashboard,
+    };
+
+    let backend = TestBackend::new(80, 14);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| CommandPaletteModal::new(&state).render(frame, frame.area()))
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
+/// Migrate lists every change, paths relative to the workspace, before it runs
+#[test]
+fn migrate_confirm_modal() {
+    use std::path::Path;
+    use vibe_manager::components::MigrateConfirmModal;
+    use vibe_manager::storage::migrate::{Change, MigrationStep};
+
+    let root = Path::new("/w");
+    let steps = [MigrationStep {
+        to: 2,
+        description: "Move notes into journal/ and rename cadence",
+        changes: vec![
+            Change::MoveEntry {
+                from: root.join("alex-chen/2026-01-05.md"),
+                to: root.join("alex-chen/journal/2026-01-05T000000.md"),
+            },
+            Change::RenameCadence(root.join("alex-chen/_profile.md")),
+            Change::RenameDefaultCadence,
+        ],
+        conflicts: Vec::new(),
+    }];
+
+    let backend = TestBackend::new(80, 14);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| MigrateConfirmModal::new(&steps, root).render(frame, frame.area()))
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend());
+}
+
+/// Text of one row of the buffer, between two columns
+fn buffer_text(terminal: &Terminal<TestBackend>, y: u16, x: std::ops::Range<u16>) -> String {
+    let buffer = terminal.backend().buffer();
+    x.map(|x| buffer[(x, y)].symbol()).collect()
+}
+
+/// Position of the first occurrence of `text` on screen
+fn find_text(terminal: &Terminal<TestBackend>, text: &str) -> ratatui::layout::Position {
+    let area = *terminal.backend().buffer().area();
+    (area.top()..area.bottom())
+        .find_map(|y| {
+            let line = buffer_text(terminal, y, area.left()..area.right());
+            let column = line.find(text)?;
+            Some(ratatui::layout::Position::new(
+                line[..column].chars().count() as u16,
+                y,
+            ))
+        })
+        .unwrap_or_else(|| panic!("{:?} not on screen", text))
+}
+
+/// Mouse hit-testing uses the rects the cards are drawn into
+#[test]
+fn dashboard_card_areas_match_rendered_cards() {
+    let summaries = vec![
+        manager_all_well(),
+        ic_summary("Alex Chen", "P3"),
+        ic_summary("Sam Reyes", "P2"),
+        ic_summary("Jonas", "P1"),
+    ];
+    let workspace_summary = WorkspaceSummary {
+        team_size: 4,
+        active_count: 4,
+        overdue_count: 0,
+        average_mood: Some(4.0),
+        total_report_count: 4,
+    };
+    let dashboard = Dashboard::new(&summaries, &workspace_summary, 1);
+
+    let backend = TestBackend::new(60, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| dashboard.render(frame, frame.area()))
+        .unwrap();
+
+    let areas = dashboard.card_areas(Rect::new(0, 0, 60, 30));
+    assert_eq!(areas.len(), summaries.len());
+    for (idx, card) in areas {
+        let first_name = summaries[idx].name.split_whitespace().next().unwrap();
+        let text: String = (card.top()..card.bottom())
+            .map(|y| buffer_text(&terminal, y, card.left()..card.right()))
+            .collect();
+        assert!(
+            text.to_lowercase().contains(&first_name.to_lowercase()),
+            "card {} drawn elsewhere",
+            idx
+        );
+    }
+}
+
+/// Clicks on the table land on the line or heading drawn under them
+#[test]
+fn dashboard_table_areas_match_rendered_rows() {
+    let summaries: Vec<_> = (1..=20)
+        .map(|n| ic_summary(&format!("Person {:02}", n), "P2"))
+        .collect();
+    let expanded = vec![false; summaries.len()];
+    let workspace_summary = WorkspaceSummary {
+        team_size: 20,
+        active_count: 20,
+        overdue_count: 0,
+        average_mood: Some(4.0),
+        total_report_count: 20,
+    };
+    // Selected near the bottom, so the table has scrolled
+    let dashboard = Dashboard::new(&summaries, &workspace_summary, 18).with_table(
+        &expanded,
+        RosterSort::Name,
+        MoodScoring::default(),
+    );
+
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| dashboard.render(frame, frame.area()))
+        .unwrap();
+
+    let area = Rect::new(0, 0, 80, 24);
+    let rows = dashboard.card_areas(area);
+    assert!(
+        rows.len() < summaries.len(),
+        "20 people don't fit in 24 lines"
+    );
+    assert!(rows.iter().any(|(idx, _)| *idx == 18));
+    for (idx, row) in rows {
+        let text = buffer_text(&terminal, row.y, row.left()..row.right());
+        assert!(
+            text.contains(&summaries[idx].name),
+            "row {} drawn elsewhere",
+            idx
+        );
+    }
+
+    let columns = dashboard.column_areas(area);
+    let name = find_text(&terminal, "Name ▾");
+    let mood = find_text(&terminal, "Mood");
+    assert_eq!(
+        columns
+            .iter()
+            .find(|(_, heading)| heading.contains(name))
+            .map(|(sort, _)| *sort),
+        Some(RosterSort::Name)
+    );
+    assert_eq!(
+        columns
+            .iter()
+            .find(|(_, heading)| heading.contains(mood))
+            .map(|(sort, _)| *sort),
+        Some(RosterSort::Mood)
+    );
+}
+
+/// Clicks on the new report modal land on the option drawn under them
+#[test]
+fn new_report_modal_field_at_matches_options() {
+    use vibe_manager::components::{NewReportField, NewReportModal, NewReportState};
+
+    let state = NewReportState::default();
+    let modal = NewReportModal::new(&state);
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| modal.render(frame, frame.area()))
+        .unwrap();
+    let area = Rect::new(0, 0, 100, 30);
+
+    let manager = find_text(&terminal, "Manager");
+    assert_eq!(
+        modal.field_at(area, manager),
+        Some((NewReportField::ReportType, Some(1)))
+    );
+    let p4 = find_text(&terminal, "P4");
+    assert_eq!(
+        modal.field_at(area, p4),
+        Some((NewReportField::Level, Some(3)))
+    );
+    let mut monthly = find_text(&terminal, "Monthly");
+    monthly.x += 6;
+    assert_eq!(
+        modal.field_at(area, monthly),
+        Some((NewReportField::Frequency, Some(2)))
+    );
+    let name = find_text(&terminal, "Name");
+    assert_eq!(
+        modal.field_at(area, name),
+        Some((NewReportField::Name, None))
+    );
+    assert_eq!(
+        modal.field_at(area, ratatui::layout::Position::ORIGIN),
+        None
+    );
+}